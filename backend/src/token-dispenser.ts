@@ -21,20 +21,14 @@ export type TokenDispenser = {
           isSigner: false
         },
         {
-          name: 'mint'
-          isMut: false
+          name: 'claimStats'
+          isMut: true
           isSigner: false
-          docs: ['Mint of the treasury']
         },
         {
-          name: 'treasury'
+          name: 'mint'
           isMut: false
           isSigner: false
-          docs: [
-            'Treasury token account. This is an externally owned token account and',
-            'the owner of this account will approve the config as a delegate using the',
-            'solana CLI command `spl-token approve <treasury_account_address> <approve_amount> <config_address>`'
-          ]
         },
         {
           name: 'systemProgram'
@@ -45,33 +39,59 @@ export type TokenDispenser = {
           name: 'addressLookupTable'
           isMut: false
           isSigner: false
+        },
+        {
+          name: 'eventAuthority'
+          isMut: false
+          isSigner: false
+        },
+        {
+          name: 'program'
+          isMut: false
+          isSigner: false
         }
       ]
       args: [
         {
           name: 'merkleRoot'
           type: {
-            array: ['u8', 20]
+            defined: 'ClaimMerkleRoot'
           }
         },
         {
           name: 'dispenserGuard'
           type: 'publicKey'
         },
-        {
-          name: 'funder'
-          type: 'publicKey'
-        },
         {
           name: 'maxTransfer'
           type: 'u64'
+        },
+        {
+          name: 'leafFormat'
+          type: {
+            defined: 'LeafFormat'
+          }
+        },
+        {
+          name: 'genesisHash'
+          type: {
+            array: ['u8', 32]
+          }
+        },
+        {
+          name: 'minPayloadVersion'
+          type: 'u8'
+        },
+        {
+          name: 'authorizationTemplate'
+          type: 'string'
         }
       ]
     },
     {
       name: 'claim'
       docs: [
-        "* Claim a claimant's tokens. This instructions needs to enforce :\n     * - The dispenser guard has signed the transaction - DONE\n     * - The claimant is claiming no more than once per ecosystem - DONE\n     * - The claimant has provided a valid proof of identity (is the owner of the wallet\n     *   entitled to the tokens)\n     * - The claimant has provided a valid proof of inclusion (this confirm that the claimant --\n     *   DONE\n     * - The claimant has not already claimed tokens -- DONE"
+        "* Claim a claimant's tokens. This instructions needs to enforce :\n     * - The dispenser guard has signed the transaction - DONE\n     * - The claimant is claiming no more than once per ecosystem - DONE\n     * - The claimant has provided a valid proof of identity (is the owner of the wallet\n     *   entitled to the tokens)\n     * - The claimant has provided a valid proof of inclusion (this confirm that the claimant --\n     *   DONE\n     * - The claimant has not already claimed tokens -- DONE\n     *\n     * This can also be called via CPI, for example by a multisig whose wallet is a PDA that signs\n     * as the claimant with `invoke_signed`. Signature verification instructions always live at the\n     * top level of the transaction since precompiles can't be invoked via CPI."
       ]
       accounts: [
         {
@@ -83,6 +103,9 @@ export type TokenDispenser = {
           name: 'claimant'
           isMut: false
           isSigner: true
+          docs: [
+            'Either a wallet signing the transaction or a PDA signing via CPI'
+          ]
         },
         {
           name: 'claimantFund'
@@ -98,6 +121,14 @@ export type TokenDispenser = {
           isMut: false
           isSigner: false
         },
+        {
+          name: 'claimStats'
+          isMut: true
+          isSigner: false
+          docs: [
+            'Accounts created before the latest ecosystems were added are grown by the funder'
+          ]
+        },
         {
           name: 'mint'
           isMut: false
@@ -130,6 +161,51 @@ export type TokenDispenser = {
           name: 'associatedTokenProgram'
           isMut: false
           isSigner: false
+        },
+        {
+          name: 'eventAuthority'
+          isMut: false
+          isSigner: false
+        },
+        {
+          name: 'program'
+          isMut: false
+          isSigner: false
+        }
+      ]
+      args: [
+        {
+          name: 'claimCertificate'
+          type: {
+            defined: 'ClaimCertificate'
+          }
+        }
+      ]
+    },
+    {
+      name: 'verifyClaim'
+      docs: [
+        '* Runs the same checks as `claim` without creating the receipt or transferring any tokens.\n     * The outcome is returned as a borsh-serialized `ClaimVerification` through\n     * `set_return_data` so that clients can simulate this instruction to learn whether a claim\n     * would succeed and, if not, why. Like in `claim`, the claim receipt is expected as the first\n     * remaining account.'
+      ]
+      accounts: [
+        {
+          name: 'claimant'
+          isMut: false
+          isSigner: false
+          docs: ['compared against the authorization payloads']
+        },
+        {
+          name: 'config'
+          isMut: false
+          isSigner: false
+        },
+        {
+          name: 'sysvarInstruction'
+          isMut: false
+          isSigner: false
+          docs: [
+            'CHECK : Same as in `Claim`, the address is checked and the account is loaded using load_instruction_at_checked.'
+          ]
         }
       ]
       args: [
@@ -140,11 +216,69 @@ export type TokenDispenser = {
           }
         }
       ]
+    },
+    {
+      name: 'setCosmosChainIds'
+      docs: [
+        '* Replace the bech32 prefixes of the Cosmos chains whose users can claim. Only the admin,\n     * who initialized the program, can call this.'
+      ]
+      accounts: [
+        {
+          name: 'admin'
+          isMut: false
+          isSigner: true
+        },
+        {
+          name: 'config'
+          isMut: true
+          isSigner: false
+        },
+        {
+          name: 'eventAuthority'
+          isMut: false
+          isSigner: false
+        },
+        {
+          name: 'program'
+          isMut: false
+          isSigner: false
+        }
+      ]
+      args: [
+        {
+          name: 'cosmosChainIds'
+          type: {
+            vec: 'string'
+          }
+        }
+      ]
+    },
+    {
+      name: 'logEvent'
+      docs: [
+        "* Target of the self-CPI used to emit events, see `emit_cpi_event`. It does nothing\n     * besides checking that the event authority signed, so that events can't be forged by\n     * calling it directly."
+      ]
+      accounts: [
+        {
+          name: 'eventAuthority'
+          isMut: false
+          isSigner: true
+        }
+      ]
+      args: [
+        {
+          name: 'event'
+          type: 'bytes'
+        }
+      ]
     }
   ]
   accounts: [
     {
       name: 'Config'
+      docs: [
+        '* The layout of this account is not the one of the first release: the merkle root became a\n * `ClaimMerkleRoot` and the campaign parameters were added after `max_transfer`. There is no\n * instruction to migrate an existing account, so a dispenser initialized with the old layout has\n * to be replaced by a fresh deployment of the program, initialized again.'
+      ]
       type: {
         kind: 'struct'
         fields: [
@@ -155,7 +289,7 @@ export type TokenDispenser = {
           {
             name: 'merkleRoot'
             type: {
-              array: ['u8', 20]
+              defined: 'ClaimMerkleRoot'
             }
           },
           {
@@ -167,20 +301,76 @@ export type TokenDispenser = {
             type: 'publicKey'
           },
           {
-            name: 'treasury'
+            name: 'addressLookupTable'
             type: 'publicKey'
           },
           {
-            name: 'addressLookupTable'
-            type: 'publicKey'
+            name: 'maxTransfer'
+            type: 'u64'
           },
           {
-            name: 'funder'
+            name: 'leafFormat'
+            type: {
+              defined: 'LeafFormat'
+            }
+          },
+          {
+            name: 'genesisHash'
+            type: {
+              array: ['u8', 32]
+            }
+          },
+          {
+            name: 'minPayloadVersion'
+            type: 'u8'
+          },
+          {
+            name: 'authorizationTemplate'
+            type: 'string'
+          },
+          {
+            name: 'admin'
             type: 'publicKey'
           },
           {
-            name: 'maxTransfer'
+            name: 'cosmosChainIds'
+            type: {
+              vec: 'string'
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'ClaimStats'
+      docs: [
+        '* Running totals of the claims, updated on every claim so that they can be read in a single\n * account fetch instead of replaying every `ClaimEvent`.'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'bump'
+            type: 'u8'
+          },
+          {
+            name: 'totalClaimed'
             type: 'u64'
+          },
+          {
+            name: 'claimCount'
+            type: 'u64'
+          },
+          {
+            name: 'ecosystems'
+            type: {
+              array: [
+                {
+                  defined: 'EcosystemClaimStats'
+                },
+                14
+              ]
+            }
           }
         ]
       }
@@ -195,95 +385,237 @@ export type TokenDispenser = {
   ]
   types: [
     {
-      name: 'CosmosMessage'
+      name: 'AlgorandMultisigPubkey'
       docs: [
-        '* An ADR036 message used in Cosmos. ADR036 is a standard for signing arbitrary data.\n* Only the message payload is stored in this struct.\n* The message signed for Cosmos is a JSON serialized CosmosStdSignDoc containing the payload and ADR036 compliant parameters.\n* The message also contains the bech32 address of the signer. We check that the signer corresponds to the public key.'
+        '* An Algorand multisig account. The address is the SHA512/256 hash of `MultisigAddr` followed by\n * the version, the threshold and the subsig pubkeys, so it depends on the order of the subsigs.'
       ]
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'payload'
-            type: 'bytes'
+            name: 'version'
+            type: 'u8'
           },
           {
-            name: 'signer'
-            type: 'string'
+            name: 'threshold'
+            type: 'u8'
+          },
+          {
+            name: 'subsigs'
+            type: {
+              vec: {
+                array: ['u8', 32]
+              }
+            }
           }
         ]
       }
     },
     {
-      name: 'DiscordMessage'
+      name: 'AlgorandMultisigSigner'
       docs: [
-        "* This message (borsh-serialized) needs to be signed by the dispenser guard after\n * verifying the claimant's pubkey controls the discord account.\n * The dispenser guard key should not be used for anything else."
+        '* The subsig `member_index` of an Algorand multisig signed the Algorand message, the signature is\n * checked by the Ed25519 instruction at `verification_instruction_index`.'
       ]
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'username'
-            type: 'string'
+            name: 'memberIndex'
+            type: 'u8'
           },
           {
-            name: 'claimant'
-            type: 'publicKey'
+            name: 'verificationInstructionIndex'
+            type: 'u8'
           }
         ]
       }
     },
     {
-      name: 'Ed25519InstructionHeader'
+      name: 'AlgorandRekeyMessage'
+      docs: [
+        '* This message (borsh-serialized) needs to be signed by the dispenser guard after\n * verifying on-chain that `pubkey` is the authorized key of the account `address`, for accounts\n * that were rekeyed.'
+      ]
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'numSignatures'
-            type: 'u8'
-          },
-          {
-            name: 'padding'
-            type: 'u8'
-          },
-          {
-            name: 'signatureOffset'
-            type: 'u16'
-          },
-          {
-            name: 'signatureInstructionIndex'
-            type: 'u16'
+            name: 'address'
+            type: {
+              array: ['u8', 32]
+            }
           },
           {
-            name: 'publicKeyOffset'
-            type: 'u16'
-          },
+            name: 'pubkey'
+            type: {
+              array: ['u8', 32]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'AptosMultiEd25519Pubkey'
+      docs: [
+        '* A k-of-n MultiEd25519 Aptos account. The authentication key, and therefore the address if\n * the key was never rotated, is the SHA3-256 hash of the member pubkeys followed by the\n * threshold and the MultiEd25519 scheme id.'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
           {
-            name: 'publicKeyInstructionIndex'
-            type: 'u16'
+            name: 'pubkeys'
+            type: {
+              vec: {
+                array: ['u8', 32]
+              }
+            }
           },
           {
-            name: 'messageDataOffset'
-            type: 'u16'
-          },
+            name: 'threshold'
+            type: 'u8'
+          }
+        ]
+      }
+    },
+    {
+      name: 'AptosMultiEd25519Signer'
+      docs: [
+        '* The member `member_index` of a MultiEd25519 account signed the Aptos message, the\n * signature is checked by the Ed25519 instruction at `verification_instruction_index`.'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
           {
-            name: 'messageDataSize'
-            type: 'u16'
+            name: 'memberIndex'
+            type: 'u8'
           },
           {
-            name: 'messageInstructionIndex'
-            type: 'u16'
+            name: 'verificationInstructionIndex'
+            type: 'u8'
           }
         ]
       }
     },
     {
-      name: 'Secp256k1InstructionHeader'
+      name: 'AptosKeyRotationMessage'
+      docs: [
+        '* This message (borsh-serialized) needs to be signed by the dispenser guard after\n * verifying on-chain that `pubkey` is the current authentication key of the account `address`,\n * for accounts that rotated their key.'
+      ]
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'numSignatures'
-            type: 'u8'
+            name: 'address'
+            type: {
+              array: ['u8', 32]
+            }
+          },
+          {
+            name: 'pubkey'
+            type: {
+              array: ['u8', 32]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'CosmosMessage'
+      docs: [
+        '* An ADR036 message used in Cosmos. ADR036 is a standard for signing arbitrary data.\n* Only the message payload is stored in this struct.\n* The message signed for Cosmos is a JSON serialized CosmosStdSignDoc containing the payload and ADR036 compliant parameters.\n* The message also contains the bech32 address of the signer. We check that the signer corresponds to the public key.'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'payload'
+            type: 'bytes'
+          },
+          {
+            name: 'signer'
+            type: 'string'
+          }
+        ]
+      }
+    },
+    {
+      name: 'LegacyAminoPubKey'
+      docs: [
+        '* A Cosmos legacy threshold multisig, i.e. `cosmos.crypto.multisig.LegacyAminoPubKey`.\n * Its address is the truncated SHA256 hash of its amino encoding, so it depends on the order\n * of the members.'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'threshold'
+            type: 'u32'
+          },
+          {
+            name: 'pubkeys'
+            type: {
+              vec: {
+                array: ['u8', 33]
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'CosmosMultisigSignature'
+      docs: [
+        '* The signature of the ADR036 signed doc by the member `member_index` of a `LegacyAminoPubKey`.'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'memberIndex'
+            type: 'u8'
+          },
+          {
+            name: 'signature'
+            type: {
+              array: ['u8', 64]
+            }
+          },
+          {
+            name: 'recoveryId'
+            type: 'u8'
+          }
+        ]
+      }
+    },
+    {
+      name: 'DiscordMessage'
+      docs: [
+        "* This message (borsh-serialized) needs to be signed by the dispenser guard after\n * verifying the claimant's pubkey controls the discord account.\n * The dispenser guard key should not be used for anything else."
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'username'
+            type: 'string'
+          },
+          {
+            name: 'claimant'
+            type: 'publicKey'
+          }
+        ]
+      }
+    },
+    {
+      name: 'Ed25519InstructionHeader'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'numSignatures'
+            type: 'u8'
+          },
+          {
+            name: 'padding'
+            type: 'u8'
           },
           {
             name: 'signatureOffset'
@@ -291,15 +623,15 @@ export type TokenDispenser = {
           },
           {
             name: 'signatureInstructionIndex'
-            type: 'u8'
+            type: 'u16'
           },
           {
-            name: 'ethAddressOffset'
+            name: 'publicKeyOffset'
             type: 'u16'
           },
           {
-            name: 'ethAddressInstructionIndex'
-            type: 'u8'
+            name: 'publicKeyInstructionIndex'
+            type: 'u16'
           },
           {
             name: 'messageDataOffset'
@@ -311,1000 +643,3856 @@ export type TokenDispenser = {
           },
           {
             name: 'messageInstructionIndex'
-            type: 'u8'
+            type: 'u16'
           }
         ]
       }
     },
     {
-      name: 'ClaimInfo'
+      name: 'Eip712Message'
+      docs: [
+        '* The message an EVM wallet signs for `eth_signTypedData_v4`, that is\n * `\\x19\\x01 || domainSeparator || hashStruct(claim)`. The Secp256k1 program hashes it with\n * Keccak256 which yields the EIP-712 digest.'
+      ]
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'identity'
+            name: 'domainSeparator'
             type: {
-              defined: 'Identity'
+              array: ['u8', 32]
             }
           },
           {
-            name: 'amount'
-            type: 'u64'
+            name: 'structHash'
+            type: {
+              array: ['u8', 32]
+            }
           }
         ]
       }
     },
     {
-      name: 'ClaimCertificate'
+      name: 'NearMessage'
+      docs: [
+        '* The parameters of a NEP-413 `signMessage` request, other than the message itself which is the\n * authorization payload.\n * The wallet signs the SHA256 hash of the tag followed by the borsh-serialized `Nep413Payload`,\n * so like for Sui we check that the signed hash matches the hash of the expected payload.'
+      ]
       type: {
         kind: 'struct'
         fields: [
           {
-            name: 'amount'
-            type: 'u64'
-          },
-          {
-            name: 'proofOfIdentity'
+            name: 'nonce'
             type: {
-              defined: 'IdentityCertificate'
+              array: ['u8', 32]
             }
           },
           {
-            name: 'proofOfInclusion'
+            name: 'recipient'
+            type: 'string'
+          },
+          {
+            name: 'callbackUrl'
             type: {
-              vec: {
-                array: ['u8', 20]
-              }
+              option: 'string'
             }
           }
         ]
       }
     },
     {
-      name: 'Identity'
+      name: 'NearKeyLinkMessage'
       docs: [
-        "* This is the identity that the claimant will use to claim tokens.\n * A claimant can claim tokens for 1 identity on each ecosystem.\n * Typically for a blockchain it is a public key in the blockchain's address space."
+        '* This message (borsh-serialized) needs to be signed by the dispenser guard after\n * verifying on-chain that `pubkey` is a full access key of the named account `account_id`.'
       ]
       type: {
-        kind: 'enum'
-        variants: [
+        kind: 'struct'
+        fields: [
           {
-            name: 'Discord'
-            fields: [
-              {
-                name: 'username'
-                type: 'string'
-              }
-            ]
+            name: 'accountId'
+            type: 'string'
           },
           {
-            name: 'Solana'
-            fields: [
-              {
-                name: 'pubkey'
-                type: {
-                  array: ['u8', 32]
-                }
-              }
-            ]
+            name: 'pubkey'
+            type: {
+              array: ['u8', 32]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'Secp256k1InstructionHeader'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'numSignatures'
+            type: 'u8'
           },
           {
-            name: 'Evm'
-            fields: [
-              {
-                name: 'pubkey'
-                type: {
-                  array: ['u8', 20]
-                }
-              }
-            ]
+            name: 'signatureOffset'
+            type: 'u16'
           },
           {
-            name: 'Sui'
-            fields: [
-              {
-                name: 'address'
-                type: {
-                  array: ['u8', 32]
-                }
-              }
-            ]
+            name: 'signatureInstructionIndex'
+            type: 'u8'
           },
           {
-            name: 'Aptos'
-            fields: [
-              {
-                name: 'address'
-                type: {
-                  array: ['u8', 32]
-                }
-              }
-            ]
+            name: 'ethAddressOffset'
+            type: 'u16'
           },
           {
-            name: 'Cosmwasm'
-            fields: [
-              {
-                name: 'address'
-                type: 'string'
-              }
-            ]
+            name: 'ethAddressInstructionIndex'
+            type: 'u8'
           },
           {
-            name: 'Injective'
-            fields: [
-              {
-                name: 'address'
-                type: 'string'
-              }
-            ]
+            name: 'messageDataOffset'
+            type: 'u16'
           },
           {
-            name: 'Algorand'
-            fields: [
-              {
-                name: 'pubkey'
-                type: {
-                  array: ['u8', 32]
-                }
-              }
-            ]
+            name: 'messageDataSize'
+            type: 'u16'
+          },
+          {
+            name: 'messageInstructionIndex'
+            type: 'u8'
           }
         ]
       }
     },
     {
-      name: 'IdentityCertificate'
+      name: 'SuiMultisigMember'
       type: {
-        kind: 'enum'
-        variants: [
+        kind: 'struct'
+        fields: [
           {
-            name: 'Discord'
-            fields: [
-              {
-                name: 'username'
-                type: 'string'
-              },
-              {
-                name: 'verification_instruction_index'
-                type: 'u8'
-              }
-            ]
+            name: 'pubkey'
+            type: {
+              defined: 'SuiMultisigMemberPubkey'
+            }
           },
           {
-            name: 'Evm'
-            fields: [
-              {
-                name: 'pubkey'
-                type: {
-                  array: ['u8', 20]
-                }
-              },
-              {
-                name: 'verification_instruction_index'
-                type: 'u8'
+            name: 'weight'
+            type: 'u8'
+          }
+        ]
+      }
+    },
+    {
+      name: 'SuiMultisigPubkey'
+      docs: [
+        "* A Sui multisig account, the members that signed need to add up to a weight of at least\n * `threshold`. The address is the Blake2b hash of the multisig flag, the threshold and each\n * member's flag, pubkey and weight, so it depends on the order of the members."
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'members'
+            type: {
+              vec: {
+                defined: 'SuiMultisigMember'
               }
-            ]
+            }
           },
           {
-            name: 'Solana'
+            name: 'threshold'
+            type: 'u16'
+          }
+        ]
+      }
+    },
+    {
+      name: 'SuiMultisigSigner'
+      docs: [
+        '* The member `member_index` of a Sui multisig signed the Sui message, the signature is checked\n * by the Ed25519 instruction at `verification_instruction_index`.'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'memberIndex'
+            type: 'u8'
           },
           {
-            name: 'Sui'
+            name: 'verificationInstructionIndex'
+            type: 'u8'
+          }
+        ]
+      }
+    },
+    {
+      name: 'TonAddress'
+      docs: [
+        '* A raw TON address, i.e. the workchain and the hash of the wallet contract, without the flags\n * and the checksum of the user-friendly base64 encoding.'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'workchain'
+            type: 'i32'
+          },
+          {
+            name: 'hash'
+            type: {
+              array: ['u8', 32]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'TonProofMessage'
+      docs: [
+        '* The fields of a TON Connect `ton_proof` other than the address and the payload, which is the\n * authorization payload.\n * The wallet signs `sha256(0xffff || "ton-connect" || sha256(message))` where `message` is\n * `"ton-proof-item-v2/" || workchain || address hash || domain length || domain || timestamp ||\n * payload`. Like for Sui, we check that the signed hash matches the hash of the expected payload.'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'domain'
+            type: 'string'
+          },
+          {
+            name: 'timestamp'
+            type: 'u64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'TonKeyLinkMessage'
+      docs: [
+        "* This message (borsh-serialized) needs to be signed by the dispenser guard after\n * verifying on the TON blockchain that `pubkey` is the key of the wallet contract at `address`,\n * since the address depends on the wallet contract and its state can't be read on Solana."
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'address'
+            type: {
+              defined: 'TonAddress'
+            }
+          },
+          {
+            name: 'pubkey'
+            type: {
+              array: ['u8', 32]
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'ClaimInfo'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'identity'
+            type: {
+              defined: 'Identity'
+            }
+          },
+          {
+            name: 'amount'
+            type: 'u64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'ClaimCertificate'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'amount'
+            type: 'u64'
+          },
+          {
+            name: 'proofOfIdentity'
+            type: {
+              defined: 'IdentityCertificate'
+            }
+          },
+          {
+            name: 'proofOfInclusion'
+            type: {
+              defined: 'ClaimMerklePath'
+            }
+          },
+          {
+            name: 'authorizationPayload'
+            type: {
+              defined: 'AuthorizationPayload'
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'ClaimVerification'
+      docs: [
+        '* The outcome of `verify_claim`.\n * `claim_info` and `receipt` are only known once the proof of identity has been checked.\n * `failure_reason` is why `claim` would fail, if it would.'
+      ]
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'claimInfo'
+            type: {
+              option: {
+                defined: 'ClaimInfo'
+              }
+            }
+          },
+          {
+            name: 'receipt'
+            type: {
+              option: 'publicKey'
+            }
+          },
+          {
+            name: 'claimed'
+            type: 'bool'
+          },
+          {
+            name: 'failureReason'
+            type: {
+              option: {
+                defined: 'ClaimFailure'
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      name: 'EcosystemClaimStats'
+      type: {
+        kind: 'struct'
+        fields: [
+          {
+            name: 'totalClaimed'
+            type: 'u64'
+          },
+          {
+            name: 'claimCount'
+            type: 'u64'
+          }
+        ]
+      }
+    },
+    {
+      name: 'BitcoinChain'
+      docs: [
+        '* Chains that sign messages the same way as Bitcoin, with their own prefix.\n * Their addresses hash keys the same way, so the `BitcoinAddress` of a key is the same on all of\n * them.'
+      ]
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'Bitcoin'
+          },
+          {
+            name: 'Litecoin'
+          },
+          {
+            name: 'Dogecoin'
+          }
+        ]
+      }
+    },
+    {
+      name: 'BitcoinAddress'
+      docs: [
+        '* A Bitcoin address without the version byte, the checksum and the base58 or bech32 encoding.\n * P2PKH addresses hold the HASH160 of a public key, P2SH-P2WPKH addresses the HASH160 of the\n * P2WPKH redeem script and P2WPKH addresses the version 0 witness program.'
+      ]
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'P2pkh'
             fields: [
               {
-                name: 'pubkey'
+                name: 'pubkeyHash'
                 type: {
-                  array: ['u8', 32]
+                  array: ['u8', 20]
                 }
-              },
-              {
-                name: 'verification_instruction_index'
-                type: 'u8'
               }
             ]
           },
           {
-            name: 'Aptos'
+            name: 'P2shP2wpkh'
             fields: [
               {
-                name: 'pubkey'
+                name: 'scriptHash'
                 type: {
-                  array: ['u8', 32]
+                  array: ['u8', 20]
                 }
-              },
-              {
-                name: 'verification_instruction_index'
-                type: 'u8'
               }
             ]
           },
           {
-            name: 'Cosmwasm'
+            name: 'P2wpkh'
             fields: [
               {
-                name: 'chain_id'
-                type: 'string'
-              },
-              {
-                name: 'signature'
-                type: {
-                  array: ['u8', 64]
-                }
-              },
-              {
-                name: 'recovery_id'
-                type: 'u8'
-              },
-              {
-                name: 'pubkey'
+                name: 'witnessProgram'
                 type: {
-                  array: ['u8', 65]
+                  array: ['u8', 20]
                 }
-              },
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      name: 'SuiMultisigMemberPubkey'
+      docs: [
+        '* The public key of a member of a Sui multisig, with the signature scheme of the member.'
+      ]
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'Ed25519'
+            fields: [
               {
-                name: 'message'
-                type: 'bytes'
+                array: ['u8', 32]
               }
             ]
           },
           {
-            name: 'Injective'
+            name: 'Secp256k1'
             fields: [
               {
-                name: 'pubkey'
-                type: {
-                  array: ['u8', 20]
-                }
-              },
-              {
-                name: 'verification_instruction_index'
-                type: 'u8'
+                array: ['u8', 33]
               }
             ]
           },
           {
-            name: 'Algorand'
+            name: 'Secp256r1'
             fields: [
               {
-                name: 'pubkey'
-                type: {
-                  array: ['u8', 32]
-                }
-              },
-              {
-                name: 'verification_instruction_index'
-                type: 'u8'
+                array: ['u8', 33]
               }
             ]
           }
         ]
       }
-    }
-  ]
-  events: [
+    },
+    {
+      name: 'AuthorizationPayload'
+      docs: [
+        "* The version of the authorization payload that the identity signed, along with the parameters\n * of the payload that can't be derived on-chain."
+      ]
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'V1'
+          },
+          {
+            name: 'V2'
+            fields: [
+              {
+                name: 'expiry'
+                type: 'i64'
+              },
+              {
+                name: 'nonce'
+                type: 'u64'
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      name: 'LeafFormat'
+      docs: [
+        "* How a `ClaimInfo` is encoded into a leaf of the merkle tree.\n * - `V1` is the borsh serialization of the `ClaimInfo`.\n * - `V2` is `LeafFormat::V2_VERSION || program id || campaign_id (u64 LE) || mint || V1 leaf`,\n *   so that a tree and its proofs can't be replayed on another deployment or campaign.\n *\n * NOTE: Any changes to this must also be made to the leaf encoding in the typescript sdk and\n * the test vectors in `test_merkle_tree`"
+      ]
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'V1'
+          },
+          {
+            name: 'V2'
+            fields: [
+              {
+                name: 'campaignId'
+                type: 'u64'
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      name: 'Identity'
+      docs: [
+        "* This is the identity that the claimant will use to claim tokens.\n * A claimant can claim tokens for 1 identity on each ecosystem.\n * Typically for a blockchain it is a public key in the blockchain's address space."
+      ]
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'Discord'
+            fields: [
+              {
+                name: 'username'
+                type: 'string'
+              }
+            ]
+          },
+          {
+            name: 'Solana'
+            fields: [
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 32]
+                }
+              }
+            ]
+          },
+          {
+            name: 'Evm'
+            fields: [
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 20]
+                }
+              }
+            ]
+          },
+          {
+            name: 'Sui'
+            fields: [
+              {
+                name: 'address'
+                type: {
+                  array: ['u8', 32]
+                }
+              }
+            ]
+          },
+          {
+            name: 'Aptos'
+            fields: [
+              {
+                name: 'address'
+                type: {
+                  array: ['u8', 32]
+                }
+              }
+            ]
+          },
+          {
+            name: 'Cosmwasm'
+            fields: [
+              {
+                name: 'address'
+                type: 'string'
+              }
+            ]
+          },
+          {
+            name: 'Injective'
+            fields: [
+              {
+                name: 'address'
+                type: 'string'
+              }
+            ]
+          },
+          {
+            name: 'Algorand'
+            fields: [
+              {
+                name: 'address'
+                type: {
+                  array: ['u8', 32]
+                }
+              }
+            ]
+          },
+          {
+            name: 'Bitcoin'
+            fields: [
+              {
+                name: 'address'
+                type: {
+                  defined: 'BitcoinAddress'
+                }
+              }
+            ]
+          },
+          {
+            name: 'Near'
+            fields: [
+              {
+                name: 'accountId'
+                type: 'string'
+              }
+            ]
+          },
+          {
+            name: 'Tron'
+            fields: [
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 20]
+                }
+              }
+            ]
+          },
+          {
+            name: 'Ton'
+            fields: [
+              {
+                name: 'address'
+                type: {
+                  defined: 'TonAddress'
+                }
+              }
+            ]
+          },
+          {
+            name: 'Substrate'
+            fields: [
+              {
+                name: 'network'
+                type: 'u16'
+              },
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 32]
+                }
+              }
+            ]
+          },
+          {
+            name: 'Cardano'
+            fields: [
+              {
+                name: 'keyHash'
+                type: {
+                  array: ['u8', 28]
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      name: 'Ecosystem'
+      docs: [
+        '* The ecosystem of an `Identity`, i.e. its variant without the data.'
+      ]
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'Discord'
+          },
+          {
+            name: 'Solana'
+          },
+          {
+            name: 'Evm'
+          },
+          {
+            name: 'Sui'
+          },
+          {
+            name: 'Aptos'
+          },
+          {
+            name: 'Cosmwasm'
+          },
+          {
+            name: 'Injective'
+          },
+          {
+            name: 'Algorand'
+          },
+          {
+            name: 'Bitcoin'
+          },
+          {
+            name: 'Near'
+          },
+          {
+            name: 'Tron'
+          },
+          {
+            name: 'Ton'
+          },
+          {
+            name: 'Substrate'
+          },
+          {
+            name: 'Cardano'
+          }
+        ]
+      }
+    },
+    {
+      name: 'IdentityCertificate'
+      docs: [
+        '* `verification_instruction_index` is the index of the signature verification instruction\n * in the transaction, which the instructions sysvar exposes the same way whether `claim` is\n * called directly or via CPI.'
+      ]
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'Discord'
+            fields: [
+              {
+                name: 'username'
+                type: 'string'
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'Evm'
+            fields: [
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 20]
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'Solana'
+          },
+          {
+            name: 'Sui'
+            fields: [
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 32]
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'Aptos'
+            fields: [
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 32]
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'Cosmwasm'
+            fields: [
+              {
+                name: 'chainId'
+                type: 'string'
+              },
+              {
+                name: 'signature'
+                type: {
+                  array: ['u8', 64]
+                }
+              },
+              {
+                name: 'recoveryId'
+                type: 'u8'
+              },
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 33]
+                }
+              },
+              {
+                name: 'message'
+                type: 'bytes'
+              }
+            ]
+          },
+          {
+            name: 'Injective'
+            fields: [
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 20]
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'Algorand'
+            fields: [
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 32]
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'EvmTypedData'
+            fields: [
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 20]
+                }
+              },
+              {
+                name: 'chainId'
+                type: 'u64'
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'EvmSiwe'
+            fields: [
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 20]
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'Ethermint'
+            fields: [
+              {
+                name: 'chainId'
+                type: 'string'
+              },
+              {
+                name: 'signature'
+                type: {
+                  array: ['u8', 64]
+                }
+              },
+              {
+                name: 'recoveryId'
+                type: 'u8'
+              },
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 65]
+                }
+              },
+              {
+                name: 'message'
+                type: 'bytes'
+              }
+            ]
+          },
+          {
+            name: 'CosmwasmMultisig'
+            fields: [
+              {
+                name: 'chainId'
+                type: 'string'
+              },
+              {
+                name: 'multisig'
+                type: {
+                  defined: 'LegacyAminoPubKey'
+                }
+              },
+              {
+                name: 'signatures'
+                type: {
+                  vec: {
+                    defined: 'CosmosMultisigSignature'
+                  }
+                }
+              },
+              {
+                name: 'message'
+                type: 'bytes'
+              }
+            ]
+          },
+          {
+            name: 'AptosMultiEd25519'
+            fields: [
+              {
+                name: 'multisig'
+                type: {
+                  defined: 'AptosMultiEd25519Pubkey'
+                }
+              },
+              {
+                name: 'signers'
+                type: {
+                  vec: {
+                    defined: 'AptosMultiEd25519Signer'
+                  }
+                }
+              }
+            ]
+          },
+          {
+            name: 'AptosRotated'
+            fields: [
+              {
+                name: 'address'
+                type: {
+                  array: ['u8', 32]
+                }
+              },
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 32]
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              },
+              {
+                name: 'attestationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'SuiSecp256k1'
+            fields: [
+              {
+                name: 'signature'
+                type: {
+                  array: ['u8', 64]
+                }
+              },
+              {
+                name: 'recoveryId'
+                type: 'u8'
+              },
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 33]
+                }
+              },
+              {
+                name: 'message'
+                type: 'bytes'
+              }
+            ]
+          },
+          {
+            name: 'SuiSecp256r1'
+            fields: [
+              {
+                name: 'signature'
+                type: {
+                  array: ['u8', 64]
+                }
+              },
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 33]
+                }
+              },
+              {
+                name: 'message'
+                type: 'bytes'
+              }
+            ]
+          },
+          {
+            name: 'SuiMultisig'
+            fields: [
+              {
+                name: 'multisig'
+                type: {
+                  defined: 'SuiMultisigPubkey'
+                }
+              },
+              {
+                name: 'signers'
+                type: {
+                  vec: {
+                    defined: 'SuiMultisigSigner'
+                  }
+                }
+              }
+            ]
+          },
+          {
+            name: 'AlgorandMultisig'
+            fields: [
+              {
+                name: 'multisig'
+                type: {
+                  defined: 'AlgorandMultisigPubkey'
+                }
+              },
+              {
+                name: 'signers'
+                type: {
+                  vec: {
+                    defined: 'AlgorandMultisigSigner'
+                  }
+                }
+              }
+            ]
+          },
+          {
+            name: 'AlgorandRekeyed'
+            fields: [
+              {
+                name: 'address'
+                type: {
+                  array: ['u8', 32]
+                }
+              },
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 32]
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              },
+              {
+                name: 'attestationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'Bitcoin'
+            fields: [
+              {
+                name: 'chain'
+                type: {
+                  defined: 'BitcoinChain'
+                }
+              },
+              {
+                name: 'address'
+                type: {
+                  defined: 'BitcoinAddress'
+                }
+              },
+              {
+                name: 'signature'
+                type: {
+                  array: ['u8', 64]
+                }
+              },
+              {
+                name: 'header'
+                type: 'u8'
+              },
+              {
+                name: 'message'
+                type: 'bytes'
+              }
+            ]
+          },
+          {
+            name: 'BitcoinBip322'
+            fields: [
+              {
+                name: 'signature'
+                type: {
+                  array: ['u8', 64]
+                }
+              },
+              {
+                name: 'recoveryId'
+                type: 'u8'
+              },
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 33]
+                }
+              },
+              {
+                name: 'message'
+                type: 'bytes'
+              }
+            ]
+          },
+          {
+            name: 'Near'
+            fields: [
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 32]
+                }
+              },
+              {
+                name: 'message'
+                type: {
+                  defined: 'NearMessage'
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'NearNamed'
+            fields: [
+              {
+                name: 'accountId'
+                type: 'string'
+              },
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 32]
+                }
+              },
+              {
+                name: 'message'
+                type: {
+                  defined: 'NearMessage'
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              },
+              {
+                name: 'attestationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'Tron'
+            fields: [
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 20]
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'Ton'
+            fields: [
+              {
+                name: 'address'
+                type: {
+                  defined: 'TonAddress'
+                }
+              },
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 32]
+                }
+              },
+              {
+                name: 'message'
+                type: {
+                  defined: 'TonProofMessage'
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              },
+              {
+                name: 'attestationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'Substrate'
+            fields: [
+              {
+                name: 'network'
+                type: 'u16'
+              },
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 32]
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          },
+          {
+            name: 'Cardano'
+            fields: [
+              {
+                name: 'pubkey'
+                type: {
+                  array: ['u8', 32]
+                }
+              },
+              {
+                name: 'verificationInstructionIndex'
+                type: 'u8'
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      name: 'ClaimFailure'
+      docs: ['* The error `claim` would fail with.']
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'Forbidden'
+          },
+          {
+            name: 'InvalidInclusionProof'
+          },
+          {
+            name: 'WrongPda'
+          },
+          {
+            name: 'AlreadyClaimed'
+          },
+          {
+            name: 'TransferExceedsMax'
+          },
+          {
+            name: 'InvalidProofOfIdentity'
+            fields: [
+              {
+                name: 'errorCode'
+                type: 'u32'
+              }
+            ]
+          },
+          {
+            name: 'Other'
+            fields: [
+              {
+                name: 'programError'
+                type: 'u64'
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      name: 'HashAlgorithm'
+      docs: [
+        '* The hash function of the merkle tree of a campaign. `Keccak160` is the 20-byte truncated\n * keccak256 of `SolanaHasher`, kept for compatibility with the existing trees.'
+      ]
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'Keccak160'
+          },
+          {
+            name: 'Keccak256'
+          },
+          {
+            name: 'Sha256'
+          }
+        ]
+      }
+    },
+    {
+      name: 'ClaimMerkleRoot'
+      docs: ['* A merkle root along with the hash algorithm of its tree.']
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'Keccak160'
+            fields: [
+              {
+                array: ['u8', 20]
+              }
+            ]
+          },
+          {
+            name: 'Keccak256'
+            fields: [
+              {
+                array: ['u8', 32]
+              }
+            ]
+          },
+          {
+            name: 'Sha256'
+            fields: [
+              {
+                array: ['u8', 32]
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      name: 'ClaimMerklePath'
+      docs: ['* A merkle proof along with the hash algorithm of its tree.']
+      type: {
+        kind: 'enum'
+        variants: [
+          {
+            name: 'Keccak160'
+            fields: [
+              {
+                vec: {
+                  array: ['u8', 20]
+                }
+              }
+            ]
+          },
+          {
+            name: 'Keccak256'
+            fields: [
+              {
+                vec: {
+                  array: ['u8', 32]
+                }
+              }
+            ]
+          },
+          {
+            name: 'Sha256'
+            fields: [
+              {
+                vec: {
+                  array: ['u8', 32]
+                }
+              }
+            ]
+          }
+        ]
+      }
+    }
+  ]
+  events: [
+    {
+      name: 'ClaimEvent'
+      fields: [
+        {
+          name: 'treasury'
+          type: 'publicKey'
+          index: false
+        },
+        {
+          name: 'remainingBalance'
+          type: 'u64'
+          index: false
+        },
+        {
+          name: 'claimant'
+          type: 'publicKey'
+          index: false
+        },
+        {
+          name: 'claimInfo'
+          type: {
+            defined: 'ClaimInfo'
+          }
+          index: false
+        },
+        {
+          name: 'ecosystem'
+          type: {
+            defined: 'Ecosystem'
+          }
+          index: false
+        },
+        {
+          name: 'leafHash'
+          type: 'bytes'
+          index: false
+        },
+        {
+          name: 'receipt'
+          type: 'publicKey'
+          index: false
+        },
+        {
+          name: 'funder'
+          type: 'publicKey'
+          index: false
+        },
+        {
+          name: 'slot'
+          type: 'u64'
+          index: false
+        }
+      ]
+    },
+    {
+      name: 'InitializeEvent'
+      fields: [
+        {
+          name: 'config'
+          type: {
+            defined: 'Config'
+          }
+          index: false
+        },
+        {
+          name: 'payer'
+          type: 'publicKey'
+          index: false
+        },
+        {
+          name: 'slot'
+          type: 'u64'
+          index: false
+        }
+      ]
+    },
+    {
+      name: 'CosmosChainIdsUpdatedEvent'
+      fields: [
+        {
+          name: 'admin'
+          type: 'publicKey'
+          index: false
+        },
+        {
+          name: 'oldCosmosChainIds'
+          type: {
+            vec: 'string'
+          }
+          index: false
+        },
+        {
+          name: 'newCosmosChainIds'
+          type: {
+            vec: 'string'
+          }
+          index: false
+        },
+        {
+          name: 'slot'
+          type: 'u64'
+          index: false
+        }
+      ]
+    }
+  ]
+  errors: [
+    {
+      code: 6000
+      name: 'AlreadyClaimed'
+    },
+    {
+      code: 6001
+      name: 'InvalidInclusionProof'
+    },
+    {
+      code: 6002
+      name: 'WrongPda'
+    },
+    {
+      code: 6003
+      name: 'SignatureVerificationWrongProgram'
+    },
+    {
+      code: 6004
+      name: 'SignatureVerificationWrongAccounts'
+    },
+    {
+      code: 6005
+      name: 'SignatureVerificationWrongHeader'
+    },
+    {
+      code: 6006
+      name: 'SignatureVerificationWrongPayload'
+    },
+    {
+      code: 6007
+      name: 'SignatureVerificationWrongPayloadMetadata'
+    },
+    {
+      code: 6008
+      name: 'SignatureVerificationWrongSigner'
+    },
+    {
+      code: 6009
+      name: 'UnauthorizedCosmosChainId'
+    },
+    {
+      code: 6010
+      name: 'TransferExceedsMax'
+    },
+    {
+      code: 6011
+      name: 'Forbidden'
+    },
+    {
+      code: 6012
+      name: 'ArithmeticOverflow'
+    },
+    {
+      code: 6013
+      name: 'AuthorizationExpired'
+    },
+    {
+      code: 6014
+      name: 'InvalidAuthorizationTemplate'
+    },
+    {
+      code: 6015
+      name: 'AuthorizationNotYetValid'
+    },
+    {
+      code: 6016
+      name: 'InvalidCosmosChainIds'
+    },
+    {
+      code: 6017
+      name: 'InvalidCosmosMultisig'
+    },
+    {
+      code: 6018
+      name: 'InvalidAptosMultiEd25519'
+    },
+    {
+      code: 6019
+      name: 'InvalidSuiMultisig'
+    },
+    {
+      code: 6020
+      name: 'InvalidAlgorandMultisig'
+    },
+    {
+      code: 6021
+      name: 'InvalidBip137Header'
+    },
+    {
+      code: 6022
+      name: 'InvalidSubstrateNetwork'
+    },
+    {
+      code: 6023
+      name: 'UnsupportedAuthorizationPayloadVersion'
+    }
+  ]
+}
+export const IDL: TokenDispenser = {
+  version: '0.1.0',
+  name: 'token_dispenser',
+  instructions: [
+    {
+      name: 'initialize',
+      docs: [
+        'This can only be called once and should be called right after the program is deployed.',
+      ],
+      accounts: [
+        {
+          name: 'payer',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'config',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'claimStats',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'addressLookupTable',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'merkleRoot',
+          type: {
+            defined: 'ClaimMerkleRoot',
+          },
+        },
+        {
+          name: 'dispenserGuard',
+          type: 'publicKey',
+        },
+        {
+          name: 'maxTransfer',
+          type: 'u64',
+        },
+        {
+          name: 'leafFormat',
+          type: {
+            defined: 'LeafFormat',
+          },
+        },
+        {
+          name: 'genesisHash',
+          type: {
+            array: ['u8', 32],
+          },
+        },
+        {
+          name: 'minPayloadVersion',
+          type: 'u8',
+        },
+        {
+          name: 'authorizationTemplate',
+          type: 'string',
+        },
+      ],
+    },
+    {
+      name: 'claim',
+      docs: [
+        "* Claim a claimant's tokens. This instructions needs to enforce :\n     * - The dispenser guard has signed the transaction - DONE\n     * - The claimant is claiming no more than once per ecosystem - DONE\n     * - The claimant has provided a valid proof of identity (is the owner of the wallet\n     *   entitled to the tokens)\n     * - The claimant has provided a valid proof of inclusion (this confirm that the claimant --\n     *   DONE\n     * - The claimant has not already claimed tokens -- DONE\n     *\n     * This can also be called via CPI, for example by a multisig whose wallet is a PDA that signs\n     * as the claimant with `invoke_signed`. Signature verification instructions always live at the\n     * top level of the transaction since precompiles can't be invoked via CPI.",
+      ],
+      accounts: [
+        {
+          name: 'funder',
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: 'claimant',
+          isMut: false,
+          isSigner: true,
+          docs: [
+            'Either a wallet signing the transaction or a PDA signing via CPI',
+          ],
+        },
+        {
+          name: 'claimantFund',
+          isMut: true,
+          isSigner: false,
+          docs: [
+            "Claimant's associated token account to receive the tokens",
+            'Should be initialized outside of this program.',
+          ],
+        },
+        {
+          name: 'config',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'claimStats',
+          isMut: true,
+          isSigner: false,
+          docs: [
+            'Accounts created before the latest ecosystems were added are grown by the funder',
+          ],
+        },
+        {
+          name: 'mint',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'treasury',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'tokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'systemProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'sysvarInstruction',
+          isMut: false,
+          isSigner: false,
+          docs: [
+            "CHECK : Anchor wants me to write this comment because I'm using AccountInfo which doesn't check for ownership and doesn't deserialize the account automatically. But it's fine because I check the address and I load it using load_instruction_at_checked.",
+          ],
+        },
+        {
+          name: 'associatedTokenProgram',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'claimCertificate',
+          type: {
+            defined: 'ClaimCertificate',
+          },
+        },
+      ],
+    },
+    {
+      name: 'verifyClaim',
+      docs: [
+        '* Runs the same checks as `claim` without creating the receipt or transferring any tokens.\n     * The outcome is returned as a borsh-serialized `ClaimVerification` through\n     * `set_return_data` so that clients can simulate this instruction to learn whether a claim\n     * would succeed and, if not, why. Like in `claim`, the claim receipt is expected as the first\n     * remaining account.',
+      ],
+      accounts: [
+        {
+          name: 'claimant',
+          isMut: false,
+          isSigner: false,
+          docs: ['compared against the authorization payloads'],
+        },
+        {
+          name: 'config',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'sysvarInstruction',
+          isMut: false,
+          isSigner: false,
+          docs: [
+            'CHECK : Same as in `Claim`, the address is checked and the account is loaded using load_instruction_at_checked.',
+          ],
+        },
+      ],
+      args: [
+        {
+          name: 'claimCertificate',
+          type: {
+            defined: 'ClaimCertificate',
+          },
+        },
+      ],
+    },
+    {
+      name: 'setCosmosChainIds',
+      docs: [
+        '* Replace the bech32 prefixes of the Cosmos chains whose users can claim. Only the admin,\n     * who initialized the program, can call this.',
+      ],
+      accounts: [
+        {
+          name: 'admin',
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: 'config',
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: 'program',
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: 'cosmosChainIds',
+          type: {
+            vec: 'string',
+          },
+        },
+      ],
+    },
+    {
+      name: 'logEvent',
+      docs: [
+        "* Target of the self-CPI used to emit events, see `emit_cpi_event`. It does nothing\n     * besides checking that the event authority signed, so that events can't be forged by\n     * calling it directly.",
+      ],
+      accounts: [
+        {
+          name: 'eventAuthority',
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: 'event',
+          type: 'bytes',
+        },
+      ],
+    },
+  ],
+  accounts: [
+    {
+      name: 'Config',
+      docs: [
+        '* The layout of this account is not the one of the first release: the merkle root became a\n * `ClaimMerkleRoot` and the campaign parameters were added after `max_transfer`. There is no\n * instruction to migrate an existing account, so a dispenser initialized with the old layout has\n * to be replaced by a fresh deployment of the program, initialized again.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'merkleRoot',
+            type: {
+              defined: 'ClaimMerkleRoot',
+            },
+          },
+          {
+            name: 'dispenserGuard',
+            type: 'publicKey',
+          },
+          {
+            name: 'mint',
+            type: 'publicKey',
+          },
+          {
+            name: 'addressLookupTable',
+            type: 'publicKey',
+          },
+          {
+            name: 'maxTransfer',
+            type: 'u64',
+          },
+          {
+            name: 'leafFormat',
+            type: {
+              defined: 'LeafFormat',
+            },
+          },
+          {
+            name: 'genesisHash',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'minPayloadVersion',
+            type: 'u8',
+          },
+          {
+            name: 'authorizationTemplate',
+            type: 'string',
+          },
+          {
+            name: 'admin',
+            type: 'publicKey',
+          },
+          {
+            name: 'cosmosChainIds',
+            type: {
+              vec: 'string',
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'ClaimStats',
+      docs: [
+        '* Running totals of the claims, updated on every claim so that they can be read in a single\n * account fetch instead of replaying every `ClaimEvent`.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'bump',
+            type: 'u8',
+          },
+          {
+            name: 'totalClaimed',
+            type: 'u64',
+          },
+          {
+            name: 'claimCount',
+            type: 'u64',
+          },
+          {
+            name: 'ecosystems',
+            type: {
+              array: [
+                {
+                  defined: 'EcosystemClaimStats',
+                },
+                14,
+              ],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'Receipt',
+      type: {
+        kind: 'struct',
+        fields: [],
+      },
+    },
+  ],
+  types: [
+    {
+      name: 'AlgorandMultisigPubkey',
+      docs: [
+        '* An Algorand multisig account. The address is the SHA512/256 hash of `MultisigAddr` followed by\n * the version, the threshold and the subsig pubkeys, so it depends on the order of the subsigs.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'version',
+            type: 'u8',
+          },
+          {
+            name: 'threshold',
+            type: 'u8',
+          },
+          {
+            name: 'subsigs',
+            type: {
+              vec: {
+                array: ['u8', 32],
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'AlgorandMultisigSigner',
+      docs: [
+        '* The subsig `member_index` of an Algorand multisig signed the Algorand message, the signature is\n * checked by the Ed25519 instruction at `verification_instruction_index`.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'memberIndex',
+            type: 'u8',
+          },
+          {
+            name: 'verificationInstructionIndex',
+            type: 'u8',
+          },
+        ],
+      },
+    },
+    {
+      name: 'AlgorandRekeyMessage',
+      docs: [
+        '* This message (borsh-serialized) needs to be signed by the dispenser guard after\n * verifying on-chain that `pubkey` is the authorized key of the account `address`, for accounts\n * that were rekeyed.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'address',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'pubkey',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'AptosMultiEd25519Pubkey',
+      docs: [
+        '* A k-of-n MultiEd25519 Aptos account. The authentication key, and therefore the address if\n * the key was never rotated, is the SHA3-256 hash of the member pubkeys followed by the\n * threshold and the MultiEd25519 scheme id.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'pubkeys',
+            type: {
+              vec: {
+                array: ['u8', 32],
+              },
+            },
+          },
+          {
+            name: 'threshold',
+            type: 'u8',
+          },
+        ],
+      },
+    },
+    {
+      name: 'AptosMultiEd25519Signer',
+      docs: [
+        '* The member `member_index` of a MultiEd25519 account signed the Aptos message, the\n * signature is checked by the Ed25519 instruction at `verification_instruction_index`.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'memberIndex',
+            type: 'u8',
+          },
+          {
+            name: 'verificationInstructionIndex',
+            type: 'u8',
+          },
+        ],
+      },
+    },
+    {
+      name: 'AptosKeyRotationMessage',
+      docs: [
+        '* This message (borsh-serialized) needs to be signed by the dispenser guard after\n * verifying on-chain that `pubkey` is the current authentication key of the account `address`,\n * for accounts that rotated their key.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'address',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'pubkey',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'CosmosMessage',
+      docs: [
+        '* An ADR036 message used in Cosmos. ADR036 is a standard for signing arbitrary data.\n* Only the message payload is stored in this struct.\n* The message signed for Cosmos is a JSON serialized CosmosStdSignDoc containing the payload and ADR036 compliant parameters.\n* The message also contains the bech32 address of the signer. We check that the signer corresponds to the public key.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'payload',
+            type: 'bytes',
+          },
+          {
+            name: 'signer',
+            type: 'string',
+          },
+        ],
+      },
+    },
+    {
+      name: 'LegacyAminoPubKey',
+      docs: [
+        '* A Cosmos legacy threshold multisig, i.e. `cosmos.crypto.multisig.LegacyAminoPubKey`.\n * Its address is the truncated SHA256 hash of its amino encoding, so it depends on the order\n * of the members.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'threshold',
+            type: 'u32',
+          },
+          {
+            name: 'pubkeys',
+            type: {
+              vec: {
+                array: ['u8', 33],
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'CosmosMultisigSignature',
+      docs: [
+        '* The signature of the ADR036 signed doc by the member `member_index` of a `LegacyAminoPubKey`.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'memberIndex',
+            type: 'u8',
+          },
+          {
+            name: 'signature',
+            type: {
+              array: ['u8', 64],
+            },
+          },
+          {
+            name: 'recoveryId',
+            type: 'u8',
+          },
+        ],
+      },
+    },
     {
-      name: 'ClaimEvent'
-      fields: [
-        {
-          name: 'remainingBalance'
-          type: 'u64'
-          index: false
-        },
-        {
-          name: 'claimant'
-          type: 'publicKey'
-          index: false
-        },
-        {
-          name: 'claimInfo'
-          type: {
-            defined: 'ClaimInfo'
-          }
-          index: false
-        }
-      ]
-    }
-  ]
-  errors: [
+      name: 'DiscordMessage',
+      docs: [
+        "* This message (borsh-serialized) needs to be signed by the dispenser guard after\n * verifying the claimant's pubkey controls the discord account.\n * The dispenser guard key should not be used for anything else.",
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'username',
+            type: 'string',
+          },
+          {
+            name: 'claimant',
+            type: 'publicKey',
+          },
+        ],
+      },
+    },
     {
-      code: 6000
-      name: 'AlreadyClaimed'
+      name: 'Ed25519InstructionHeader',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'numSignatures',
+            type: 'u8',
+          },
+          {
+            name: 'padding',
+            type: 'u8',
+          },
+          {
+            name: 'signatureOffset',
+            type: 'u16',
+          },
+          {
+            name: 'signatureInstructionIndex',
+            type: 'u16',
+          },
+          {
+            name: 'publicKeyOffset',
+            type: 'u16',
+          },
+          {
+            name: 'publicKeyInstructionIndex',
+            type: 'u16',
+          },
+          {
+            name: 'messageDataOffset',
+            type: 'u16',
+          },
+          {
+            name: 'messageDataSize',
+            type: 'u16',
+          },
+          {
+            name: 'messageInstructionIndex',
+            type: 'u16',
+          },
+        ],
+      },
     },
     {
-      code: 6001
-      name: 'InvalidInclusionProof'
+      name: 'Eip712Message',
+      docs: [
+        '* The message an EVM wallet signs for `eth_signTypedData_v4`, that is\n * `\\x19\\x01 || domainSeparator || hashStruct(claim)`. The Secp256k1 program hashes it with\n * Keccak256 which yields the EIP-712 digest.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'domainSeparator',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'structHash',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+        ],
+      },
     },
     {
-      code: 6002
-      name: 'WrongPda'
+      name: 'NearMessage',
+      docs: [
+        '* The parameters of a NEP-413 `signMessage` request, other than the message itself which is the\n * authorization payload.\n * The wallet signs the SHA256 hash of the tag followed by the borsh-serialized `Nep413Payload`,\n * so like for Sui we check that the signed hash matches the hash of the expected payload.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'nonce',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+          {
+            name: 'recipient',
+            type: 'string',
+          },
+          {
+            name: 'callbackUrl',
+            type: {
+              option: 'string',
+            },
+          },
+        ],
+      },
     },
     {
-      code: 6003
-      name: 'SignatureVerificationWrongProgram'
+      name: 'NearKeyLinkMessage',
+      docs: [
+        '* This message (borsh-serialized) needs to be signed by the dispenser guard after\n * verifying on-chain that `pubkey` is a full access key of the named account `account_id`.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'accountId',
+            type: 'string',
+          },
+          {
+            name: 'pubkey',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+        ],
+      },
     },
     {
-      code: 6004
-      name: 'SignatureVerificationWrongAccounts'
+      name: 'Secp256k1InstructionHeader',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'numSignatures',
+            type: 'u8',
+          },
+          {
+            name: 'signatureOffset',
+            type: 'u16',
+          },
+          {
+            name: 'signatureInstructionIndex',
+            type: 'u8',
+          },
+          {
+            name: 'ethAddressOffset',
+            type: 'u16',
+          },
+          {
+            name: 'ethAddressInstructionIndex',
+            type: 'u8',
+          },
+          {
+            name: 'messageDataOffset',
+            type: 'u16',
+          },
+          {
+            name: 'messageDataSize',
+            type: 'u16',
+          },
+          {
+            name: 'messageInstructionIndex',
+            type: 'u8',
+          },
+        ],
+      },
+    },
+    {
+      name: 'SuiMultisigMember',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'pubkey',
+            type: {
+              defined: 'SuiMultisigMemberPubkey',
+            },
+          },
+          {
+            name: 'weight',
+            type: 'u8',
+          },
+        ],
+      },
+    },
+    {
+      name: 'SuiMultisigPubkey',
+      docs: [
+        "* A Sui multisig account, the members that signed need to add up to a weight of at least\n * `threshold`. The address is the Blake2b hash of the multisig flag, the threshold and each\n * member's flag, pubkey and weight, so it depends on the order of the members.",
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'members',
+            type: {
+              vec: {
+                defined: 'SuiMultisigMember',
+              },
+            },
+          },
+          {
+            name: 'threshold',
+            type: 'u16',
+          },
+        ],
+      },
+    },
+    {
+      name: 'SuiMultisigSigner',
+      docs: [
+        '* The member `member_index` of a Sui multisig signed the Sui message, the signature is checked\n * by the Ed25519 instruction at `verification_instruction_index`.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'memberIndex',
+            type: 'u8',
+          },
+          {
+            name: 'verificationInstructionIndex',
+            type: 'u8',
+          },
+        ],
+      },
+    },
+    {
+      name: 'TonAddress',
+      docs: [
+        '* A raw TON address, i.e. the workchain and the hash of the wallet contract, without the flags\n * and the checksum of the user-friendly base64 encoding.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'workchain',
+            type: 'i32',
+          },
+          {
+            name: 'hash',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'TonProofMessage',
+      docs: [
+        '* The fields of a TON Connect `ton_proof` other than the address and the payload, which is the\n * authorization payload.\n * The wallet signs `sha256(0xffff || "ton-connect" || sha256(message))` where `message` is\n * `"ton-proof-item-v2/" || workchain || address hash || domain length || domain || timestamp ||\n * payload`. Like for Sui, we check that the signed hash matches the hash of the expected payload.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'domain',
+            type: 'string',
+          },
+          {
+            name: 'timestamp',
+            type: 'u64',
+          },
+        ],
+      },
+    },
+    {
+      name: 'TonKeyLinkMessage',
+      docs: [
+        "* This message (borsh-serialized) needs to be signed by the dispenser guard after\n * verifying on the TON blockchain that `pubkey` is the key of the wallet contract at `address`,\n * since the address depends on the wallet contract and its state can't be read on Solana.",
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'address',
+            type: {
+              defined: 'TonAddress',
+            },
+          },
+          {
+            name: 'pubkey',
+            type: {
+              array: ['u8', 32],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'ClaimInfo',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'identity',
+            type: {
+              defined: 'Identity',
+            },
+          },
+          {
+            name: 'amount',
+            type: 'u64',
+          },
+        ],
+      },
+    },
+    {
+      name: 'ClaimCertificate',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'amount',
+            type: 'u64',
+          },
+          {
+            name: 'proofOfIdentity',
+            type: {
+              defined: 'IdentityCertificate',
+            },
+          },
+          {
+            name: 'proofOfInclusion',
+            type: {
+              defined: 'ClaimMerklePath',
+            },
+          },
+          {
+            name: 'authorizationPayload',
+            type: {
+              defined: 'AuthorizationPayload',
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: 'ClaimVerification',
+      docs: [
+        '* The outcome of `verify_claim`.\n * `claim_info` and `receipt` are only known once the proof of identity has been checked.\n * `failure_reason` is why `claim` would fail, if it would.',
+      ],
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'claimInfo',
+            type: {
+              option: {
+                defined: 'ClaimInfo',
+              },
+            },
+          },
+          {
+            name: 'receipt',
+            type: {
+              option: 'publicKey',
+            },
+          },
+          {
+            name: 'claimed',
+            type: 'bool',
+          },
+          {
+            name: 'failureReason',
+            type: {
+              option: {
+                defined: 'ClaimFailure',
+              },
+            },
+          },
+        ],
+      },
     },
     {
-      code: 6005
-      name: 'SignatureVerificationWrongHeader'
+      name: 'EcosystemClaimStats',
+      type: {
+        kind: 'struct',
+        fields: [
+          {
+            name: 'totalClaimed',
+            type: 'u64',
+          },
+          {
+            name: 'claimCount',
+            type: 'u64',
+          },
+        ],
+      },
     },
     {
-      code: 6006
-      name: 'SignatureVerificationWrongPayload'
+      name: 'BitcoinChain',
+      docs: [
+        '* Chains that sign messages the same way as Bitcoin, with their own prefix.\n * Their addresses hash keys the same way, so the `BitcoinAddress` of a key is the same on all of\n * them.',
+      ],
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Bitcoin',
+          },
+          {
+            name: 'Litecoin',
+          },
+          {
+            name: 'Dogecoin',
+          },
+        ],
+      },
     },
     {
-      code: 6007
-      name: 'SignatureVerificationWrongPayloadMetadata'
+      name: 'BitcoinAddress',
+      docs: [
+        '* A Bitcoin address without the version byte, the checksum and the base58 or bech32 encoding.\n * P2PKH addresses hold the HASH160 of a public key, P2SH-P2WPKH addresses the HASH160 of the\n * P2WPKH redeem script and P2WPKH addresses the version 0 witness program.',
+      ],
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'P2pkh',
+            fields: [
+              {
+                name: 'pubkeyHash',
+                type: {
+                  array: ['u8', 20],
+                },
+              },
+            ],
+          },
+          {
+            name: 'P2shP2wpkh',
+            fields: [
+              {
+                name: 'scriptHash',
+                type: {
+                  array: ['u8', 20],
+                },
+              },
+            ],
+          },
+          {
+            name: 'P2wpkh',
+            fields: [
+              {
+                name: 'witnessProgram',
+                type: {
+                  array: ['u8', 20],
+                },
+              },
+            ],
+          },
+        ],
+      },
     },
     {
-      code: 6008
-      name: 'SignatureVerificationWrongSigner'
+      name: 'SuiMultisigMemberPubkey',
+      docs: [
+        '* The public key of a member of a Sui multisig, with the signature scheme of the member.',
+      ],
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Ed25519',
+            fields: [
+              {
+                array: ['u8', 32],
+              },
+            ],
+          },
+          {
+            name: 'Secp256k1',
+            fields: [
+              {
+                array: ['u8', 33],
+              },
+            ],
+          },
+          {
+            name: 'Secp256r1',
+            fields: [
+              {
+                array: ['u8', 33],
+              },
+            ],
+          },
+        ],
+      },
     },
     {
-      code: 6009
-      name: 'UnauthorizedCosmosChainId'
+      name: 'AuthorizationPayload',
+      docs: [
+        "* The version of the authorization payload that the identity signed, along with the parameters\n * of the payload that can't be derived on-chain.",
+      ],
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'V1',
+          },
+          {
+            name: 'V2',
+            fields: [
+              {
+                name: 'expiry',
+                type: 'i64',
+              },
+              {
+                name: 'nonce',
+                type: 'u64',
+              },
+            ],
+          },
+        ],
+      },
     },
     {
-      code: 6010
-      name: 'TransferExceedsMax'
-    }
-  ]
-}
-export const IDL: TokenDispenser = {
-  version: '0.1.0',
-  name: 'token_dispenser',
-  instructions: [
-    {
-      name: 'initialize',
+      name: 'LeafFormat',
       docs: [
-        'This can only be called once and should be called right after the program is deployed.'
-      ],
-      accounts: [
-        {
-          name: 'payer',
-          isMut: true,
-          isSigner: true
-        },
-        {
-          name: 'config',
-          isMut: true,
-          isSigner: false
-        },
-        {
-          name: 'mint',
-          isMut: false,
-          isSigner: false,
-          docs: ['Mint of the treasury']
-        },
-        {
-          name: 'treasury',
-          isMut: false,
-          isSigner: false,
-          docs: [
-            'Treasury token account. This is an externally owned token account and',
-            'the owner of this account will approve the config as a delegate using the',
-            'solana CLI command `spl-token approve <treasury_account_address> <approve_amount> <config_address>`'
-          ]
-        },
-        {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false
-        },
-        {
-          name: 'addressLookupTable',
-          isMut: false,
-          isSigner: false
-        }
+        "* How a `ClaimInfo` is encoded into a leaf of the merkle tree.\n * - `V1` is the borsh serialization of the `ClaimInfo`.\n * - `V2` is `LeafFormat::V2_VERSION || program id || campaign_id (u64 LE) || mint || V1 leaf`,\n *   so that a tree and its proofs can't be replayed on another deployment or campaign.\n *\n * NOTE: Any changes to this must also be made to the leaf encoding in the typescript sdk and\n * the test vectors in `test_merkle_tree`",
       ],
-      args: [
-        {
-          name: 'merkleRoot',
-          type: {
-            array: ['u8', 20]
-          }
-        },
-        {
-          name: 'dispenserGuard',
-          type: 'publicKey'
-        },
-        {
-          name: 'funder',
-          type: 'publicKey'
-        },
-        {
-          name: 'maxTransfer',
-          type: 'u64'
-        }
-      ]
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'V1',
+          },
+          {
+            name: 'V2',
+            fields: [
+              {
+                name: 'campaignId',
+                type: 'u64',
+              },
+            ],
+          },
+        ],
+      },
     },
     {
-      name: 'claim',
+      name: 'Identity',
       docs: [
-        "* Claim a claimant's tokens. This instructions needs to enforce :\n     * - The dispenser guard has signed the transaction - DONE\n     * - The claimant is claiming no more than once per ecosystem - DONE\n     * - The claimant has provided a valid proof of identity (is the owner of the wallet\n     *   entitled to the tokens)\n     * - The claimant has provided a valid proof of inclusion (this confirm that the claimant --\n     *   DONE\n     * - The claimant has not already claimed tokens -- DONE"
-      ],
-      accounts: [
-        {
-          name: 'funder',
-          isMut: true,
-          isSigner: true
-        },
-        {
-          name: 'claimant',
-          isMut: false,
-          isSigner: true
-        },
-        {
-          name: 'claimantFund',
-          isMut: true,
-          isSigner: false,
-          docs: [
-            "Claimant's associated token account to receive the tokens",
-            'Should be initialized outside of this program.'
-          ]
-        },
-        {
-          name: 'config',
-          isMut: false,
-          isSigner: false
-        },
-        {
-          name: 'mint',
-          isMut: false,
-          isSigner: false
-        },
-        {
-          name: 'treasury',
-          isMut: true,
-          isSigner: false
-        },
-        {
-          name: 'tokenProgram',
-          isMut: false,
-          isSigner: false
-        },
-        {
-          name: 'systemProgram',
-          isMut: false,
-          isSigner: false
-        },
-        {
-          name: 'sysvarInstruction',
-          isMut: false,
-          isSigner: false,
-          docs: [
-            "CHECK : Anchor wants me to write this comment because I'm using AccountInfo which doesn't check for ownership and doesn't deserialize the account automatically. But it's fine because I check the address and I load it using load_instruction_at_checked."
-          ]
-        },
-        {
-          name: 'associatedTokenProgram',
-          isMut: false,
-          isSigner: false
-        }
+        "* This is the identity that the claimant will use to claim tokens.\n * A claimant can claim tokens for 1 identity on each ecosystem.\n * Typically for a blockchain it is a public key in the blockchain's address space.",
       ],
-      args: [
-        {
-          name: 'claimCertificate',
-          type: {
-            defined: 'ClaimCertificate'
-          }
-        }
-      ]
-    }
-  ],
-  accounts: [
-    {
-      name: 'Config',
       type: {
-        kind: 'struct',
-        fields: [
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Discord',
+            fields: [
+              {
+                name: 'username',
+                type: 'string',
+              },
+            ],
+          },
           {
-            name: 'bump',
-            type: 'u8'
+            name: 'Solana',
+            fields: [
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 32],
+                },
+              },
+            ],
           },
           {
-            name: 'merkleRoot',
-            type: {
-              array: ['u8', 20]
-            }
+            name: 'Evm',
+            fields: [
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 20],
+                },
+              },
+            ],
           },
           {
-            name: 'dispenserGuard',
-            type: 'publicKey'
+            name: 'Sui',
+            fields: [
+              {
+                name: 'address',
+                type: {
+                  array: ['u8', 32],
+                },
+              },
+            ],
           },
           {
-            name: 'mint',
-            type: 'publicKey'
+            name: 'Aptos',
+            fields: [
+              {
+                name: 'address',
+                type: {
+                  array: ['u8', 32],
+                },
+              },
+            ],
           },
           {
-            name: 'treasury',
-            type: 'publicKey'
+            name: 'Cosmwasm',
+            fields: [
+              {
+                name: 'address',
+                type: 'string',
+              },
+            ],
           },
           {
-            name: 'addressLookupTable',
-            type: 'publicKey'
+            name: 'Injective',
+            fields: [
+              {
+                name: 'address',
+                type: 'string',
+              },
+            ],
           },
           {
-            name: 'funder',
-            type: 'publicKey'
+            name: 'Algorand',
+            fields: [
+              {
+                name: 'address',
+                type: {
+                  array: ['u8', 32],
+                },
+              },
+            ],
           },
           {
-            name: 'maxTransfer',
-            type: 'u64'
-          }
-        ]
-      }
-    },
-    {
-      name: 'Receipt',
-      type: {
-        kind: 'struct',
-        fields: []
-      }
-    }
-  ],
-  types: [
-    {
-      name: 'CosmosMessage',
-      docs: [
-        '* An ADR036 message used in Cosmos. ADR036 is a standard for signing arbitrary data.\n* Only the message payload is stored in this struct.\n* The message signed for Cosmos is a JSON serialized CosmosStdSignDoc containing the payload and ADR036 compliant parameters.\n* The message also contains the bech32 address of the signer. We check that the signer corresponds to the public key.'
-      ],
-      type: {
-        kind: 'struct',
-        fields: [
+            name: 'Bitcoin',
+            fields: [
+              {
+                name: 'address',
+                type: {
+                  defined: 'BitcoinAddress',
+                },
+              },
+            ],
+          },
           {
-            name: 'payload',
-            type: 'bytes'
+            name: 'Near',
+            fields: [
+              {
+                name: 'accountId',
+                type: 'string',
+              },
+            ],
           },
           {
-            name: 'signer',
-            type: 'string'
-          }
-        ]
-      }
+            name: 'Tron',
+            fields: [
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 20],
+                },
+              },
+            ],
+          },
+          {
+            name: 'Ton',
+            fields: [
+              {
+                name: 'address',
+                type: {
+                  defined: 'TonAddress',
+                },
+              },
+            ],
+          },
+          {
+            name: 'Substrate',
+            fields: [
+              {
+                name: 'network',
+                type: 'u16',
+              },
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 32],
+                },
+              },
+            ],
+          },
+          {
+            name: 'Cardano',
+            fields: [
+              {
+                name: 'keyHash',
+                type: {
+                  array: ['u8', 28],
+                },
+              },
+            ],
+          },
+        ],
+      },
     },
     {
-      name: 'DiscordMessage',
+      name: 'Ecosystem',
       docs: [
-        "* This message (borsh-serialized) needs to be signed by the dispenser guard after\n * verifying the claimant's pubkey controls the discord account.\n * The dispenser guard key should not be used for anything else."
+        '* The ecosystem of an `Identity`, i.e. its variant without the data.',
       ],
       type: {
-        kind: 'struct',
-        fields: [
+        kind: 'enum',
+        variants: [
           {
-            name: 'username',
-            type: 'string'
+            name: 'Discord',
           },
           {
-            name: 'claimant',
-            type: 'publicKey'
-          }
-        ]
-      }
-    },
-    {
-      name: 'Ed25519InstructionHeader',
-      type: {
-        kind: 'struct',
-        fields: [
+            name: 'Solana',
+          },
           {
-            name: 'numSignatures',
-            type: 'u8'
+            name: 'Evm',
           },
           {
-            name: 'padding',
-            type: 'u8'
+            name: 'Sui',
           },
           {
-            name: 'signatureOffset',
-            type: 'u16'
+            name: 'Aptos',
           },
           {
-            name: 'signatureInstructionIndex',
-            type: 'u16'
+            name: 'Cosmwasm',
           },
           {
-            name: 'publicKeyOffset',
-            type: 'u16'
+            name: 'Injective',
           },
           {
-            name: 'publicKeyInstructionIndex',
-            type: 'u16'
+            name: 'Algorand',
           },
           {
-            name: 'messageDataOffset',
-            type: 'u16'
+            name: 'Bitcoin',
           },
           {
-            name: 'messageDataSize',
-            type: 'u16'
+            name: 'Near',
           },
           {
-            name: 'messageInstructionIndex',
-            type: 'u16'
-          }
-        ]
-      }
+            name: 'Tron',
+          },
+          {
+            name: 'Ton',
+          },
+          {
+            name: 'Substrate',
+          },
+          {
+            name: 'Cardano',
+          },
+        ],
+      },
     },
     {
-      name: 'Secp256k1InstructionHeader',
+      name: 'IdentityCertificate',
+      docs: [
+        '* `verification_instruction_index` is the index of the signature verification instruction\n * in the transaction, which the instructions sysvar exposes the same way whether `claim` is\n * called directly or via CPI.',
+      ],
       type: {
-        kind: 'struct',
-        fields: [
+        kind: 'enum',
+        variants: [
           {
-            name: 'numSignatures',
-            type: 'u8'
+            name: 'Discord',
+            fields: [
+              {
+                name: 'username',
+                type: 'string',
+              },
+              {
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+            ],
           },
           {
-            name: 'signatureOffset',
-            type: 'u16'
+            name: 'Evm',
+            fields: [
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 20],
+                },
+              },
+              {
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+            ],
           },
           {
-            name: 'signatureInstructionIndex',
-            type: 'u8'
+            name: 'Solana',
+          },
+          {
+            name: 'Sui',
+            fields: [
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 32],
+                },
+              },
+              {
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+            ],
+          },
+          {
+            name: 'Aptos',
+            fields: [
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 32],
+                },
+              },
+              {
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+            ],
+          },
+          {
+            name: 'Cosmwasm',
+            fields: [
+              {
+                name: 'chainId',
+                type: 'string',
+              },
+              {
+                name: 'signature',
+                type: {
+                  array: ['u8', 64],
+                },
+              },
+              {
+                name: 'recoveryId',
+                type: 'u8',
+              },
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 33],
+                },
+              },
+              {
+                name: 'message',
+                type: 'bytes',
+              },
+            ],
+          },
+          {
+            name: 'Injective',
+            fields: [
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 20],
+                },
+              },
+              {
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+            ],
           },
           {
-            name: 'ethAddressOffset',
-            type: 'u16'
+            name: 'Algorand',
+            fields: [
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 32],
+                },
+              },
+              {
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+            ],
           },
           {
-            name: 'ethAddressInstructionIndex',
-            type: 'u8'
+            name: 'EvmTypedData',
+            fields: [
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 20],
+                },
+              },
+              {
+                name: 'chainId',
+                type: 'u64',
+              },
+              {
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+            ],
           },
           {
-            name: 'messageDataOffset',
-            type: 'u16'
+            name: 'EvmSiwe',
+            fields: [
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 20],
+                },
+              },
+              {
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+            ],
           },
           {
-            name: 'messageDataSize',
-            type: 'u16'
+            name: 'Ethermint',
+            fields: [
+              {
+                name: 'chainId',
+                type: 'string',
+              },
+              {
+                name: 'signature',
+                type: {
+                  array: ['u8', 64],
+                },
+              },
+              {
+                name: 'recoveryId',
+                type: 'u8',
+              },
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 65],
+                },
+              },
+              {
+                name: 'message',
+                type: 'bytes',
+              },
+            ],
           },
           {
-            name: 'messageInstructionIndex',
-            type: 'u8'
-          }
-        ]
-      }
-    },
-    {
-      name: 'ClaimInfo',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'identity',
-            type: {
-              defined: 'Identity'
-            }
+            name: 'CosmwasmMultisig',
+            fields: [
+              {
+                name: 'chainId',
+                type: 'string',
+              },
+              {
+                name: 'multisig',
+                type: {
+                  defined: 'LegacyAminoPubKey',
+                },
+              },
+              {
+                name: 'signatures',
+                type: {
+                  vec: {
+                    defined: 'CosmosMultisigSignature',
+                  },
+                },
+              },
+              {
+                name: 'message',
+                type: 'bytes',
+              },
+            ],
           },
           {
-            name: 'amount',
-            type: 'u64'
-          }
-        ]
-      }
-    },
-    {
-      name: 'ClaimCertificate',
-      type: {
-        kind: 'struct',
-        fields: [
-          {
-            name: 'amount',
-            type: 'u64'
+            name: 'AptosMultiEd25519',
+            fields: [
+              {
+                name: 'multisig',
+                type: {
+                  defined: 'AptosMultiEd25519Pubkey',
+                },
+              },
+              {
+                name: 'signers',
+                type: {
+                  vec: {
+                    defined: 'AptosMultiEd25519Signer',
+                  },
+                },
+              },
+            ],
           },
           {
-            name: 'proofOfIdentity',
-            type: {
-              defined: 'IdentityCertificate'
-            }
+            name: 'AptosRotated',
+            fields: [
+              {
+                name: 'address',
+                type: {
+                  array: ['u8', 32],
+                },
+              },
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 32],
+                },
+              },
+              {
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+              {
+                name: 'attestationInstructionIndex',
+                type: 'u8',
+              },
+            ],
           },
           {
-            name: 'proofOfInclusion',
-            type: {
-              vec: {
-                array: ['u8', 20]
-              }
-            }
-          }
-        ]
-      }
-    },
-    {
-      name: 'Identity',
-      docs: [
-        "* This is the identity that the claimant will use to claim tokens.\n * A claimant can claim tokens for 1 identity on each ecosystem.\n * Typically for a blockchain it is a public key in the blockchain's address space."
-      ],
-      type: {
-        kind: 'enum',
-        variants: [
-          {
-            name: 'Discord',
+            name: 'SuiSecp256k1',
             fields: [
               {
-                name: 'username',
-                type: 'string'
-              }
-            ]
+                name: 'signature',
+                type: {
+                  array: ['u8', 64],
+                },
+              },
+              {
+                name: 'recoveryId',
+                type: 'u8',
+              },
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 33],
+                },
+              },
+              {
+                name: 'message',
+                type: 'bytes',
+              },
+            ],
           },
           {
-            name: 'Solana',
+            name: 'SuiSecp256r1',
             fields: [
+              {
+                name: 'signature',
+                type: {
+                  array: ['u8', 64],
+                },
+              },
               {
                 name: 'pubkey',
                 type: {
-                  array: ['u8', 32]
-                }
-              }
-            ]
+                  array: ['u8', 33],
+                },
+              },
+              {
+                name: 'message',
+                type: 'bytes',
+              },
+            ],
           },
           {
-            name: 'Evm',
+            name: 'SuiMultisig',
             fields: [
               {
-                name: 'pubkey',
+                name: 'multisig',
                 type: {
-                  array: ['u8', 20]
-                }
-              }
-            ]
+                  defined: 'SuiMultisigPubkey',
+                },
+              },
+              {
+                name: 'signers',
+                type: {
+                  vec: {
+                    defined: 'SuiMultisigSigner',
+                  },
+                },
+              },
+            ],
           },
           {
-            name: 'Sui',
+            name: 'AlgorandMultisig',
             fields: [
               {
-                name: 'address',
+                name: 'multisig',
                 type: {
-                  array: ['u8', 32]
-                }
-              }
-            ]
+                  defined: 'AlgorandMultisigPubkey',
+                },
+              },
+              {
+                name: 'signers',
+                type: {
+                  vec: {
+                    defined: 'AlgorandMultisigSigner',
+                  },
+                },
+              },
+            ],
           },
           {
-            name: 'Aptos',
+            name: 'AlgorandRekeyed',
             fields: [
               {
                 name: 'address',
                 type: {
-                  array: ['u8', 32]
-                }
-              }
-            ]
+                  array: ['u8', 32],
+                },
+              },
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 32],
+                },
+              },
+              {
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+              {
+                name: 'attestationInstructionIndex',
+                type: 'u8',
+              },
+            ],
           },
           {
-            name: 'Cosmwasm',
+            name: 'Bitcoin',
             fields: [
+              {
+                name: 'chain',
+                type: {
+                  defined: 'BitcoinChain',
+                },
+              },
               {
                 name: 'address',
-                type: 'string'
-              }
-            ]
+                type: {
+                  defined: 'BitcoinAddress',
+                },
+              },
+              {
+                name: 'signature',
+                type: {
+                  array: ['u8', 64],
+                },
+              },
+              {
+                name: 'header',
+                type: 'u8',
+              },
+              {
+                name: 'message',
+                type: 'bytes',
+              },
+            ],
           },
           {
-            name: 'Injective',
+            name: 'BitcoinBip322',
             fields: [
               {
-                name: 'address',
-                type: 'string'
-              }
-            ]
+                name: 'signature',
+                type: {
+                  array: ['u8', 64],
+                },
+              },
+              {
+                name: 'recoveryId',
+                type: 'u8',
+              },
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 33],
+                },
+              },
+              {
+                name: 'message',
+                type: 'bytes',
+              },
+            ],
           },
           {
-            name: 'Algorand',
+            name: 'Near',
             fields: [
               {
                 name: 'pubkey',
                 type: {
-                  array: ['u8', 32]
-                }
-              }
-            ]
-          }
-        ]
-      }
-    },
-    {
-      name: 'IdentityCertificate',
-      type: {
-        kind: 'enum',
-        variants: [
+                  array: ['u8', 32],
+                },
+              },
+              {
+                name: 'message',
+                type: {
+                  defined: 'NearMessage',
+                },
+              },
+              {
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+            ],
+          },
           {
-            name: 'Discord',
+            name: 'NearNamed',
             fields: [
               {
-                name: 'username',
-                type: 'string'
+                name: 'accountId',
+                type: 'string',
               },
               {
-                name: 'verification_instruction_index',
-                type: 'u8'
-              }
-            ]
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 32],
+                },
+              },
+              {
+                name: 'message',
+                type: {
+                  defined: 'NearMessage',
+                },
+              },
+              {
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+              {
+                name: 'attestationInstructionIndex',
+                type: 'u8',
+              },
+            ],
           },
           {
-            name: 'Evm',
+            name: 'Tron',
             fields: [
               {
                 name: 'pubkey',
                 type: {
-                  array: ['u8', 20]
-                }
+                  array: ['u8', 20],
+                },
               },
               {
-                name: 'verification_instruction_index',
-                type: 'u8'
-              }
-            ]
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+            ],
           },
           {
-            name: 'Solana'
+            name: 'Ton',
+            fields: [
+              {
+                name: 'address',
+                type: {
+                  defined: 'TonAddress',
+                },
+              },
+              {
+                name: 'pubkey',
+                type: {
+                  array: ['u8', 32],
+                },
+              },
+              {
+                name: 'message',
+                type: {
+                  defined: 'TonProofMessage',
+                },
+              },
+              {
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+              {
+                name: 'attestationInstructionIndex',
+                type: 'u8',
+              },
+            ],
           },
           {
-            name: 'Sui',
+            name: 'Substrate',
             fields: [
+              {
+                name: 'network',
+                type: 'u16',
+              },
               {
                 name: 'pubkey',
                 type: {
-                  array: ['u8', 32]
-                }
+                  array: ['u8', 32],
+                },
               },
               {
-                name: 'verification_instruction_index',
-                type: 'u8'
-              }
-            ]
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+            ],
           },
           {
-            name: 'Aptos',
+            name: 'Cardano',
             fields: [
               {
                 name: 'pubkey',
                 type: {
-                  array: ['u8', 32]
-                }
+                  array: ['u8', 32],
+                },
               },
               {
-                name: 'verification_instruction_index',
-                type: 'u8'
-              }
-            ]
+                name: 'verificationInstructionIndex',
+                type: 'u8',
+              },
+            ],
           },
+        ],
+      },
+    },
+    {
+      name: 'ClaimFailure',
+      docs: ['* The error `claim` would fail with.'],
+      type: {
+        kind: 'enum',
+        variants: [
           {
-            name: 'Cosmwasm',
+            name: 'Forbidden',
+          },
+          {
+            name: 'InvalidInclusionProof',
+          },
+          {
+            name: 'WrongPda',
+          },
+          {
+            name: 'AlreadyClaimed',
+          },
+          {
+            name: 'TransferExceedsMax',
+          },
+          {
+            name: 'InvalidProofOfIdentity',
             fields: [
               {
-                name: 'chain_id',
-                type: 'string'
+                name: 'errorCode',
+                type: 'u32',
               },
+            ],
+          },
+          {
+            name: 'Other',
+            fields: [
               {
-                name: 'signature',
-                type: {
-                  array: ['u8', 64]
-                }
+                name: 'programError',
+                type: 'u64',
               },
+            ],
+          },
+        ],
+      },
+    },
+    {
+      name: 'HashAlgorithm',
+      docs: [
+        '* The hash function of the merkle tree of a campaign. `Keccak160` is the 20-byte truncated\n * keccak256 of `SolanaHasher`, kept for compatibility with the existing trees.',
+      ],
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Keccak160',
+          },
+          {
+            name: 'Keccak256',
+          },
+          {
+            name: 'Sha256',
+          },
+        ],
+      },
+    },
+    {
+      name: 'ClaimMerkleRoot',
+      docs: ['* A merkle root along with the hash algorithm of its tree.'],
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Keccak160',
+            fields: [
               {
-                name: 'recovery_id',
-                type: 'u8'
+                array: ['u8', 20],
               },
+            ],
+          },
+          {
+            name: 'Keccak256',
+            fields: [
               {
-                name: 'pubkey',
-                type: {
-                  array: ['u8', 65]
-                }
+                array: ['u8', 32],
               },
-              {
-                name: 'message',
-                type: 'bytes'
-              }
-            ]
+            ],
           },
           {
-            name: 'Injective',
+            name: 'Sha256',
             fields: [
               {
-                name: 'pubkey',
-                type: {
-                  array: ['u8', 20]
-                }
+                array: ['u8', 32],
               },
+            ],
+          },
+        ],
+      },
+    },
+    {
+      name: 'ClaimMerklePath',
+      docs: ['* A merkle proof along with the hash algorithm of its tree.'],
+      type: {
+        kind: 'enum',
+        variants: [
+          {
+            name: 'Keccak160',
+            fields: [
               {
-                name: 'verification_instruction_index',
-                type: 'u8'
-              }
-            ]
+                vec: {
+                  array: ['u8', 20],
+                },
+              },
+            ],
           },
           {
-            name: 'Algorand',
+            name: 'Keccak256',
             fields: [
               {
-                name: 'pubkey',
-                type: {
-                  array: ['u8', 32]
-                }
+                vec: {
+                  array: ['u8', 32],
+                },
               },
+            ],
+          },
+          {
+            name: 'Sha256',
+            fields: [
               {
-                name: 'verification_instruction_index',
-                type: 'u8'
-              }
-            ]
-          }
-        ]
-      }
-    }
+                vec: {
+                  array: ['u8', 32],
+                },
+              },
+            ],
+          },
+        ],
+      },
+    },
   ],
   events: [
     {
       name: 'ClaimEvent',
       fields: [
+        {
+          name: 'treasury',
+          type: 'publicKey',
+          index: false,
+        },
         {
           name: 'remainingBalance',
           type: 'u64',
-          index: false
+          index: false,
         },
         {
           name: 'claimant',
           type: 'publicKey',
-          index: false
+          index: false,
         },
         {
           name: 'claimInfo',
           type: {
-            defined: 'ClaimInfo'
+            defined: 'ClaimInfo',
           },
-          index: false
-        }
-      ]
-    }
+          index: false,
+        },
+        {
+          name: 'ecosystem',
+          type: {
+            defined: 'Ecosystem',
+          },
+          index: false,
+        },
+        {
+          name: 'leafHash',
+          type: 'bytes',
+          index: false,
+        },
+        {
+          name: 'receipt',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'funder',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'slot',
+          type: 'u64',
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'InitializeEvent',
+      fields: [
+        {
+          name: 'config',
+          type: {
+            defined: 'Config',
+          },
+          index: false,
+        },
+        {
+          name: 'payer',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'slot',
+          type: 'u64',
+          index: false,
+        },
+      ],
+    },
+    {
+      name: 'CosmosChainIdsUpdatedEvent',
+      fields: [
+        {
+          name: 'admin',
+          type: 'publicKey',
+          index: false,
+        },
+        {
+          name: 'oldCosmosChainIds',
+          type: {
+            vec: 'string',
+          },
+          index: false,
+        },
+        {
+          name: 'newCosmosChainIds',
+          type: {
+            vec: 'string',
+          },
+          index: false,
+        },
+        {
+          name: 'slot',
+          type: 'u64',
+          index: false,
+        },
+      ],
+    },
   ],
   errors: [
     {
       code: 6000,
-      name: 'AlreadyClaimed'
+      name: 'AlreadyClaimed',
     },
     {
       code: 6001,
-      name: 'InvalidInclusionProof'
+      name: 'InvalidInclusionProof',
     },
     {
       code: 6002,
-      name: 'WrongPda'
+      name: 'WrongPda',
     },
     {
       code: 6003,
-      name: 'SignatureVerificationWrongProgram'
+      name: 'SignatureVerificationWrongProgram',
     },
     {
       code: 6004,
-      name: 'SignatureVerificationWrongAccounts'
+      name: 'SignatureVerificationWrongAccounts',
     },
     {
       code: 6005,
-      name: 'SignatureVerificationWrongHeader'
+      name: 'SignatureVerificationWrongHeader',
     },
     {
       code: 6006,
-      name: 'SignatureVerificationWrongPayload'
+      name: 'SignatureVerificationWrongPayload',
     },
     {
       code: 6007,
-      name: 'SignatureVerificationWrongPayloadMetadata'
+      name: 'SignatureVerificationWrongPayloadMetadata',
     },
     {
       code: 6008,
-      name: 'SignatureVerificationWrongSigner'
+      name: 'SignatureVerificationWrongSigner',
     },
     {
       code: 6009,
-      name: 'UnauthorizedCosmosChainId'
+      name: 'UnauthorizedCosmosChainId',
     },
     {
       code: 6010,
-      name: 'TransferExceedsMax'
-    }
-  ]
+      name: 'TransferExceedsMax',
+    },
+    {
+      code: 6011,
+      name: 'Forbidden',
+    },
+    {
+      code: 6012,
+      name: 'ArithmeticOverflow',
+    },
+    {
+      code: 6013,
+      name: 'AuthorizationExpired',
+    },
+    {
+      code: 6014,
+      name: 'InvalidAuthorizationTemplate',
+    },
+    {
+      code: 6015,
+      name: 'AuthorizationNotYetValid',
+    },
+    {
+      code: 6016,
+      name: 'InvalidCosmosChainIds',
+    },
+    {
+      code: 6017,
+      name: 'InvalidCosmosMultisig',
+    },
+    {
+      code: 6018,
+      name: 'InvalidAptosMultiEd25519',
+    },
+    {
+      code: 6019,
+      name: 'InvalidSuiMultisig',
+    },
+    {
+      code: 6020,
+      name: 'InvalidAlgorandMultisig',
+    },
+    {
+      code: 6021,
+      name: 'InvalidBip137Header',
+    },
+    {
+      code: 6022,
+      name: 'InvalidSubstrateNetwork',
+    },
+    {
+      code: 6023,
+      name: 'UnsupportedAuthorizationPayloadVersion',
+    },
+  ],
 }
 
 export const coder = new BorshCoder(IDL)
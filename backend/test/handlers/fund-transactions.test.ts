@@ -378,7 +378,8 @@ const createTokenDispenserProgramInstruction = async () => {
     proofOfIdentity: {
       discord: { username: 'username', verificationInstructionIndex: 0 }
     },
-    proofOfInclusion: []
+    proofOfInclusion: { keccak160: { 0: [] } },
+    authorizationPayload: { v1: {} }
   }
 
   const tokenDispenserInstruction = await tokenDispenser.methods
@@ -388,12 +389,15 @@ const createTokenDispenserProgramInstruction = async () => {
       claimant: PublicKey.unique(),
       claimantFund: PublicKey.unique(),
       config: PublicKey.unique(),
+      claimStats: PublicKey.unique(),
       mint: PublicKey.unique(),
       treasury: PublicKey.unique(),
       tokenProgram: PublicKey.unique(),
       systemProgram: PublicKey.unique(),
       sysvarInstruction: PublicKey.unique(),
-      associatedTokenProgram: PublicKey.unique()
+      associatedTokenProgram: PublicKey.unique(),
+      eventAuthority: PublicKey.unique(),
+      program: TokenDispenserPublicKey
     })
     .instruction()
 
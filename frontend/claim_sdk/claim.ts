@@ -79,7 +79,7 @@ export class ClaimInfo {
       case 'algorand': {
         identityStruct = {
           algorand: {
-            pubkey: base32decode(this.identity).subarray(0, 32),
+            address: base32decode(this.identity).subarray(0, 32),
           },
         }
         break
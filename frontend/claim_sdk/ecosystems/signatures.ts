@@ -64,9 +64,9 @@ export function cosmwasmBuildSignedMessage(
 ): SignedMessage {
   const fullMessage = cosmosGetFullMessage(address, payload)
   const signature = Buffer.from(signatureBase64, 'base64')
-  const uncompressedPublicKey = getUncompressedPubkey(
-    Buffer.from(pub_key.value, 'base64')
-  )
+  // Amino public keys are compressed, which is the form the program expects
+  const publicKey = Buffer.from(pub_key.value, 'base64')
+  const uncompressedPublicKey = getUncompressedPubkey(publicKey)
   const chainId = extractChainId(address)
   const evmPubkey = uncompressedToEvmPubkey(uncompressedPublicKey)
   if (chainId === 'inj') {
@@ -82,7 +82,7 @@ export function cosmwasmBuildSignedMessage(
     }
   } else {
     return {
-      publicKey: uncompressedPublicKey,
+      publicKey,
       signature,
       recoveryId: extractRecoveryId(
         signature,
@@ -12,7 +12,7 @@ import { TokenDispenser } from './idl/token_dispenser'
 import { base32encode } from './index'
 
 export class TokenDispenserEventSubscriber {
-  eventParser: anchor.EventParser
+  coder: BorshCoder
   connection: anchor.web3.Connection
   programId: anchor.web3.PublicKey
   timeWindowSecs: number
@@ -27,9 +27,8 @@ export class TokenDispenserEventSubscriber {
     chunkSize: number,
     confirmOpts?: anchor.web3.ConfirmOptions
   ) {
-    const coder = new BorshCoder(tokenDispenser as Idl)
+    this.coder = new BorshCoder(tokenDispenser as Idl)
     this.programId = programId
-    this.eventParser = new anchor.EventParser(this.programId, coder)
     this.timeWindowSecs = timeWindowSecs
     this.lastSignatureSeen = lastSignatureSeen
     this.chunkSize = chunkSize
@@ -46,7 +45,7 @@ export class TokenDispenserEventSubscriber {
   }
 
   /**
-   * Parses transaction logs for the program and returns the events
+   * Parses the transactions of the program and returns the events
    * for the transactions that occurred within the time window.
    */
   public async parseTransactionLogs(): Promise<{
@@ -103,32 +102,15 @@ export class TokenDispenserEventSubscriber {
     }
     const validTxnSigChunks = chunkArray(validTxnSigs, this.chunkSize)
 
-    const validTxns = (await this.fetchTxns(validTxnSigChunks)).map((txn) => {
-      return {
-        signature: txn?.transaction.signatures[0] ?? '',
-        logs: txn?.meta?.logMessages ?? [],
-        blockTime: txn?.blockTime ?? 0,
-        slot: txn?.slot ?? 0,
-      }
-    })
-
-    const txnEvents = validTxns
-      .map((txnLog) => {
-        const eventGen = this.eventParser.parseLogs(txnLog.logs)
-        const events = []
-        let event = eventGen.next()
+    const txnEvents = (await this.fetchTxns(validTxnSigChunks))
+      .map((txn) => {
         // Note: should only have 1 event/claim per txn at most
-        while (!event.done) {
-          events.push(
-            event.value.data as any as IdlEvents<TokenDispenser>['ClaimEvent']
-          )
-          event = eventGen.next()
-        }
+        const events = this.parseClaimEvents(txn)
 
         return {
-          signature: txnLog.signature,
-          blockTime: txnLog.blockTime,
-          slot: txnLog.slot,
+          signature: txn?.transaction.signatures[0] ?? '',
+          blockTime: txn?.blockTime ?? 0,
+          slot: txn?.slot ?? 0,
           event: events.length > 0 ? events[0] : undefined,
         }
       })
@@ -158,6 +140,40 @@ export class TokenDispenserEventSubscriber {
     }
   }
 
+  /**
+   * Events are emitted through a CPI of the program to its own `log_event` instruction,
+   * so they are decoded from the inner instructions of the transaction instead of its logs.
+   */
+  private parseClaimEvents(
+    txn: anchor.web3.VersionedTransactionResponse
+  ): IdlEvents<TokenDispenser>['ClaimEvent'][] {
+    const accountKeys = txn.transaction.message.getAccountKeys({
+      accountKeysFromLookups: txn.meta?.loadedAddresses,
+    })
+    const events: IdlEvents<TokenDispenser>['ClaimEvent'][] = []
+    for (const innerInstructions of txn.meta?.innerInstructions ?? []) {
+      for (const ix of innerInstructions.instructions) {
+        if (!accountKeys.get(ix.programIdIndex)?.equals(this.programId)) {
+          continue
+        }
+        const decodedIx = this.coder.instruction.decode(ix.data, 'base58')
+        if (decodedIx?.name !== 'logEvent') {
+          continue
+        }
+        const eventData = (decodedIx.data as { event: Buffer }).event
+        const event = this.coder.events.decode(
+          Buffer.from(eventData).toString('base64')
+        )
+        if (event?.name === 'ClaimEvent') {
+          events.push(
+            event.data as any as IdlEvents<TokenDispenser>['ClaimEvent']
+          )
+        }
+      }
+    }
+    return events
+  }
+
   private async getTransactionBlockTime(
     signature: string
   ): Promise<number | null | undefined> {
@@ -281,10 +297,10 @@ function formatClaimInfo(
       address: claimInfo.identity.injective.address,
       amount: claimInfo.amount.toString(),
     }
-  } else if (claimInfo.identity.algorand?.pubkey) {
+  } else if (claimInfo.identity.algorand?.address) {
     return {
       ecosystem: 'algorand',
-      address: base32encode(claimInfo.identity.algorand.pubkey),
+      address: base32encode(claimInfo.identity.algorand.address),
       amount: claimInfo.amount.toString(),
     }
   } else
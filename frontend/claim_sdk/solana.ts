@@ -44,6 +44,13 @@ const AUTHORIZATION_PAYLOAD = [
   '\nI authorize Solana wallet\n',
   '\nto claim my W tokens.\n',
 ]
+// The same payload with the placeholders the on-chain program fills in
+const AUTHORIZATION_TEMPLATE = AUTHORIZATION_PAYLOAD[0].concat(
+  '{program_id}',
+  AUTHORIZATION_PAYLOAD[1],
+  '{claimant}',
+  AUTHORIZATION_PAYLOAD[2]
+)
 
 export type TransactionWithPayers = {
   tx: VersionedTransaction
@@ -60,6 +67,8 @@ export type TransactionWithPayers = {
 export class TokenDispenserProvider {
   tokenDispenserProgram: anchor.Program<TokenDispenser>
   configPda: [anchor.web3.PublicKey, bump]
+  claimStatsPda: [anchor.web3.PublicKey, bump]
+  eventAuthorityPda: [anchor.web3.PublicKey, bump]
   config: IdlAccounts<TokenDispenser>['Config'] | undefined
   providers: anchor.Provider[]
 
@@ -93,6 +102,16 @@ export class TokenDispenserProvider {
       [Buffer.from('config')],
       this.programId
     )
+
+    this.claimStatsPda = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('claim_stats')],
+      this.programId
+    )
+
+    this.eventAuthorityPda = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('__event_authority')],
+      this.programId
+    )
   }
 
   get programId(): anchor.web3.PublicKey {
@@ -115,6 +134,14 @@ export class TokenDispenserProvider {
     return this.configPda
   }
 
+  public getClaimStatsPda(): [anchor.web3.PublicKey, bump] {
+    return this.claimStatsPda
+  }
+
+  public getEventAuthorityPda(): [anchor.web3.PublicKey, bump] {
+    return this.eventAuthorityPda
+  }
+
   public async getConfig(): Promise<IdlAccounts<TokenDispenser>['Config']> {
     // config is immutable once its been initialized so this is safe.
     if (this.config === undefined) {
@@ -167,13 +194,27 @@ export class TokenDispenserProvider {
       funders
     )
 
+    const genesisHash = bs58.decode(await this.connection.getGenesisHash())
+
+    // The leaves are hashed with `MerkleTree` and serialized by `ClaimInfo.toBuffer`
     return this.tokenDispenserProgram.methods
-      .initialize(Array.from(root), dispenserGuard, maxTransfer)
+      .initialize(
+        { keccak160: { 0: Array.from(root) } } as any,
+        dispenserGuard,
+        maxTransfer,
+        { v1: {} },
+        Array.from(genesisHash),
+        1,
+        AUTHORIZATION_TEMPLATE
+      )
       .accounts({
         config: this.getConfigPda()[0],
+        claimStats: this.getClaimStatsPda()[0],
         mint,
         systemProgram: anchor.web3.SystemProgram.programId,
         addressLookupTable,
+        eventAuthority: this.getEventAuthorityPda()[0],
+        program: this.programId,
       })
       .rpc()
   }
@@ -196,11 +237,13 @@ export class TokenDispenserProvider {
       lookupTable: lookupTableAddress,
       addresses: [
         this.configPda[0],
+        this.claimStatsPda[0],
         mint,
         TOKEN_PROGRAM_ID,
         SystemProgram.programId,
         SYSVAR_INSTRUCTIONS_PUBKEY,
         splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+        this.eventAuthorityPda[0],
         ...treasuries,
         ...funders,
       ],
@@ -329,7 +372,13 @@ export class TokenDispenserProvider {
     signedMessage: SignedMessage | undefined
   ): Promise<VersionedTransaction> {
     const [receiptPda, receiptBump] = this.getReceiptPda(claimInfo)
-    const { mint } = await this.getConfig()
+    const { mint, minPayloadVersion } = await this.getConfig()
+    // TODO: support the version 2 of the authorization payload, which also signs an expiry and a nonce
+    if (minPayloadVersion > 1) {
+      throw new Error(
+        `unsupported authorization payload version: ${minPayloadVersion}`
+      )
+    }
     //same as getClaimantFundAddress / getAssociatedTokenAddress but with bump
     const [claimantFund, claimaintFundBump] = PublicKey.findProgramAddressSync(
       [
@@ -362,7 +411,8 @@ export class TokenDispenserProvider {
     const claimCert: IdlTypes<TokenDispenser>['ClaimCertificate'] = {
       amount: claimInfo.amount,
       proofOfIdentity,
-      proofOfInclusion,
+      proofOfInclusion: { keccak160: { 0: proofOfInclusion } } as any,
+      authorizationPayload: { v1: {} },
     }
 
     //always rely on init_if_needed since we can't use createATAIdempotent without
@@ -374,12 +424,15 @@ export class TokenDispenserProvider {
         claimant: this.claimant,
         claimantFund,
         config: this.getConfigPda()[0],
+        claimStats: this.getClaimStatsPda()[0],
         mint,
         treasury,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: anchor.web3.SystemProgram.programId,
         sysvarInstruction: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
         associatedTokenProgram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
+        eventAuthority: this.getEventAuthorityPda()[0],
+        program: this.programId,
       })
       .remainingAccounts([
         {
@@ -143,7 +143,9 @@ describe('integration test', () => {
       const configAccount = await deployerTokenDispenserProvider.getConfig()
 
       expect(configAccount.bump).toEqual(configBump)
-      expect(configAccount.merkleRoot).toEqual(Array.from(root))
+      expect(configAccount.merkleRoot).toEqual({
+        keccak160: { 0: Array.from(root) },
+      })
       expect(configAccount.mint).toEqual(mint.publicKey)
       expect(configAccount.dispenserGuard).toEqual(dispenserGuard)
       const lookupTableAddress = configAccount.addressLookupTable
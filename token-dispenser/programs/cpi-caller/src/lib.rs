@@ -0,0 +1,103 @@
+#![allow(clippy::result_large_err)]
+use {
+    anchor_lang::prelude::*,
+    token_dispenser::{
+        cpi::accounts::Claim as TokenDispenserClaim,
+        program::TokenDispenser,
+        ClaimCertificate,
+    },
+};
+
+declare_id!("FfMvXXjkMMC1v3e54fWGhf41bSbsZN4zRTeoFPWLvrqA");
+
+pub const VAULT_SEED: &[u8] = b"vault";
+
+/**
+ * A minimal program that claims from the token dispenser through CPI. It stands in for
+ * smart wallets and multisigs (e.g. Squads vaults) whose wallet is a PDA that can only
+ * sign through `invoke_signed`. It is only used in tests.
+ */
+#[program]
+pub mod cpi_caller {
+    use super::*;
+
+    /// Claims the tokens allocated to this program's vault PDA, which signs as the claimant.
+    /// The claim receipt is expected in `remaining_accounts` like in a direct claim.
+    pub fn claim<'info>(
+        ctx: Context<'_, '_, '_, 'info, Claim<'info>>,
+        claim_certificate: ClaimCertificate,
+    ) -> Result<()> {
+        let bump = *ctx.bumps.get("vault").unwrap();
+        token_dispenser::cpi::claim(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_dispenser_program.to_account_info(),
+                TokenDispenserClaim {
+                    funder:                   ctx.accounts.funder.to_account_info(),
+                    claimant:                 ctx.accounts.vault.to_account_info(),
+                    claimant_fund:            ctx.accounts.claimant_fund.to_account_info(),
+                    config:                   ctx.accounts.config.to_account_info(),
+                    claim_stats:              ctx.accounts.claim_stats.to_account_info(),
+                    mint:                     ctx.accounts.mint.to_account_info(),
+                    treasury:                 ctx.accounts.treasury.to_account_info(),
+                    token_program:            ctx.accounts.token_program.to_account_info(),
+                    system_program:           ctx.accounts.system_program.to_account_info(),
+                    sysvar_instruction:       ctx.accounts.sysvar_instruction.to_account_info(),
+                    associated_token_program: ctx
+                        .accounts
+                        .associated_token_program
+                        .to_account_info(),
+                    event_authority:          ctx.accounts.event_authority.to_account_info(),
+                    program:                  ctx
+                        .accounts
+                        .token_dispenser_program
+                        .to_account_info(),
+                },
+                &[&[VAULT_SEED, &[bump]]],
+            )
+            .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+            claim_certificate,
+        )
+    }
+}
+
+/// All accounts except `vault` are forwarded as-is and validated by the token dispenser.
+#[derive(Accounts)]
+pub struct Claim<'info> {
+    #[account(mut)]
+    pub funder:                   Signer<'info>,
+    /// CHECK: PDA of this program that acts as the claimant, it holds no data
+    #[account(seeds = [VAULT_SEED], bump)]
+    pub vault:                    UncheckedAccount<'info>,
+    /// CHECK: checked by the token dispenser
+    #[account(mut)]
+    pub claimant_fund:            UncheckedAccount<'info>,
+    /// CHECK: checked by the token dispenser
+    pub config:                   UncheckedAccount<'info>,
+    /// CHECK: checked by the token dispenser
+    #[account(mut)]
+    pub claim_stats:              UncheckedAccount<'info>,
+    /// CHECK: checked by the token dispenser
+    pub mint:                     UncheckedAccount<'info>,
+    /// CHECK: checked by the token dispenser
+    #[account(mut)]
+    pub treasury:                 UncheckedAccount<'info>,
+    /// CHECK: checked by the token dispenser
+    pub token_program:            UncheckedAccount<'info>,
+    /// CHECK: checked by the token dispenser
+    pub system_program:           UncheckedAccount<'info>,
+    /// CHECK: checked by the token dispenser
+    pub sysvar_instruction:       UncheckedAccount<'info>,
+    /// CHECK: checked by the token dispenser
+    pub associated_token_program: UncheckedAccount<'info>,
+    /// CHECK: checked by the token dispenser
+    pub event_authority:          UncheckedAccount<'info>,
+    pub token_dispenser_program:  Program<'info, TokenDispenser>,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Sdk.
+////////////////////////////////////////////////////////////////////////////////
+
+pub fn get_vault_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VAULT_SEED], &crate::id())
+}
@@ -1,21 +1,36 @@
 #[cfg(test)]
 use super::{
-    ed25519::Ed25519TestMessage,
+    ed25519::{
+        Ed25519TestClaimantMessage,
+        Ed25519TestMessage,
+    },
     get_expected_payload,
+    DEFAULT_AUTHORIZATION_TEMPLATE,
 };
 use {
-    //super::ed25519::Ed25519Pubkey,
+    super::ed25519::{
+        Ed25519Pubkey,
+        GuardAttestation,
+    },
     crate::ErrorCode,
     anchor_lang::{
         prelude::*,
         AnchorDeserialize,
         AnchorSerialize,
     },
+    sha2::{
+        Digest,
+        Sha512_256,
+    },
 };
 
 pub const ALGORAND_PREFIX: &[u8] = b"MX";
+/// ARC-60 prefixes arbitrary data so that it can't be mistaken for a transaction
+pub const ALGORAND_ARC60_PREFIX: &[u8] = b"arc60";
+pub const ALGORAND_MULTISIG_PREFIX: &[u8] = b"MultisigAddr";
+pub const ALGORAND_MULTISIG_VERSION: u8 = 1;
 
-#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
 pub struct AlgorandMessage(Vec<u8>);
 
 impl AlgorandMessage {
@@ -23,23 +38,175 @@ impl AlgorandMessage {
         self.0.as_slice()
     }
 
+    /**
+     * Wallets sign either the `MX` prefixed payload or the ARC-60 prefixed payload.
+     */
     pub fn parse(data: &[u8]) -> Result<Self> {
         if let Some(payload) = data.strip_prefix(ALGORAND_PREFIX) {
             return Ok(AlgorandMessage(payload.to_vec()));
         }
+        if let Some(payload) = data.strip_prefix(ALGORAND_ARC60_PREFIX) {
+            return Ok(AlgorandMessage(payload.to_vec()));
+        }
         Err(ErrorCode::SignatureVerificationWrongPayloadMetadata.into())
     }
 }
 
+/**
+ * The 32 bytes of an Algorand address, without the checksum and the base32 encoding.
+ * For a single key account that was never rekeyed, it is the Ed25519 public key of the account.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Debug)]
+pub struct AlgorandAddress([u8; 32]);
+
+impl AlgorandAddress {
+    pub const LEN: usize = 32;
+}
+
+impl From<Ed25519Pubkey> for AlgorandAddress {
+    fn from(val: Ed25519Pubkey) -> Self {
+        AlgorandAddress(val.to_bytes())
+    }
+}
+
+/**
+ * An Algorand multisig account. The address is the SHA512/256 hash of `MultisigAddr` followed by
+ * the version, the threshold and the subsig pubkeys, so it depends on the order of the subsigs.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct AlgorandMultisigPubkey {
+    pub version:   u8,
+    pub threshold: u8,
+    pub subsigs:   Vec<Ed25519Pubkey>,
+}
+
+/**
+ * The subsig `member_index` of an Algorand multisig signed the Algorand message, the signature is
+ * checked by the Ed25519 instruction at `verification_instruction_index`.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct AlgorandMultisigSigner {
+    pub member_index:                   u8,
+    pub verification_instruction_index: u8,
+}
+
+impl AlgorandMultisigPubkey {
+    pub fn get_address(&self) -> AlgorandAddress {
+        let mut hasher = Sha512_256::new();
+        hasher.update(ALGORAND_MULTISIG_PREFIX);
+        hasher.update([self.version, self.threshold]);
+        for subsig in &self.subsigs {
+            hasher.update(subsig.to_bytes());
+        }
+        AlgorandAddress(hasher.finalize().into())
+    }
+
+    /**
+     * Check that at least `threshold` distinct subsigs signed. Signers need to be sorted by
+     * `member_index` so that each subsig is counted once. Returns the pubkey of each signer.
+     */
+    pub fn check_signers(&self, signers: &[AlgorandMultisigSigner]) -> Result<Vec<&Ed25519Pubkey>> {
+        require!(
+            self.version == ALGORAND_MULTISIG_VERSION
+                && self.threshold > 0
+                && self.threshold as usize <= self.subsigs.len()
+                && signers.len() >= self.threshold as usize,
+            ErrorCode::InvalidAlgorandMultisig
+        );
+
+        let mut previous_member_index = None;
+        let mut pubkeys = vec![];
+        for signer in signers {
+            require!(
+                previous_member_index < Some(signer.member_index),
+                ErrorCode::InvalidAlgorandMultisig
+            );
+            pubkeys.push(
+                self.subsigs
+                    .get(signer.member_index as usize)
+                    .ok_or(ErrorCode::InvalidAlgorandMultisig)?,
+            );
+            previous_member_index = Some(signer.member_index);
+        }
+        Ok(pubkeys)
+    }
+}
+
+/**
+ * This message (borsh-serialized) needs to be signed by the dispenser guard after
+ * verifying on-chain that `pubkey` is the authorized key of the account `address`, for accounts
+ * that were rekeyed.
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AlgorandRekeyMessage {
+    address: AlgorandAddress,
+    pubkey:  Ed25519Pubkey,
+}
+
+impl AlgorandRekeyMessage {
+    pub fn parse_and_check_address_and_pubkey(
+        data: &[u8],
+        address: &AlgorandAddress,
+        pubkey: &Ed25519Pubkey,
+    ) -> Result<Self> {
+        let result = AlgorandRekeyMessage::parse(data)?;
+
+        if result.address != *address || result.pubkey != *pubkey {
+            return err!(ErrorCode::SignatureVerificationWrongPayload);
+        }
+
+        Ok(result)
+    }
+}
+
+impl GuardAttestation for AlgorandRekeyMessage {
+    const TAG: &'static [u8] = b"algorand-rekey-v1";
+}
+
 #[cfg(test)]
-impl Ed25519TestMessage for AlgorandMessage {
-    fn for_claimant(claimant: &Pubkey) -> Self {
-        Self(get_expected_payload(claimant).into_bytes())
+impl AlgorandRekeyMessage {
+    pub fn new(address: AlgorandAddress, pubkey: Ed25519Pubkey) -> Self {
+        Self { address, pubkey }
+    }
+}
+
+#[cfg(test)]
+impl From<[u8; Self::LEN]> for AlgorandAddress {
+    fn from(bytes: [u8; Self::LEN]) -> Self {
+        AlgorandAddress(bytes)
     }
+}
 
+#[cfg(test)]
+impl Ed25519TestMessage for AlgorandMessage {
     fn get_message_with_metadata(&self) -> Vec<u8> {
         let mut message = ALGORAND_PREFIX.to_vec();
         message.extend_from_slice(&self.0);
         message.to_vec()
     }
 }
+
+#[cfg(test)]
+impl Ed25519TestClaimantMessage for AlgorandMessage {
+    fn for_claimant(claimant: &Pubkey) -> Self {
+        Self(get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant).into_bytes())
+    }
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        AlgorandMessage::parse(b"MXpayload").unwrap().get_payload(),
+        b"payload"
+    );
+    assert_eq!(
+        AlgorandMessage::parse(b"arc60payload")
+            .unwrap()
+            .get_payload(),
+        b"payload"
+    );
+    assert_eq!(
+        AlgorandMessage::parse(b"TXpayload").unwrap_err(),
+        ErrorCode::SignatureVerificationWrongPayloadMetadata.into()
+    );
+}
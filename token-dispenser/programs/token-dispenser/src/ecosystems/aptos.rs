@@ -1,10 +1,17 @@
 #[cfg(test)]
 use super::{
-    ed25519::Ed25519TestMessage,
+    ed25519::{
+        Ed25519TestClaimantMessage,
+        Ed25519TestMessage,
+    },
     get_expected_payload,
+    DEFAULT_AUTHORIZATION_TEMPLATE,
 };
 use {
-    super::ed25519::Ed25519Pubkey,
+    super::ed25519::{
+        Ed25519Pubkey,
+        GuardAttestation,
+    },
     crate::ErrorCode,
     anchor_lang::{
         prelude::*,
@@ -14,17 +21,25 @@ use {
     sha3::Digest,
 };
 
-pub const APTOS_PREFIX: &[u8] = b"APTOS\nmessage: ";
-pub const APTOS_SUFFIX: &[u8] = b"\nnonce: nonce";
+pub const APTOS_HEADER: &[u8] = b"APTOS\n";
+pub const APTOS_ADDRESS_FIELD: &[u8] = b"address: ";
+pub const APTOS_APPLICATION_FIELD: &[u8] = b"application: ";
+pub const APTOS_CHAIN_ID_FIELD: &[u8] = b"chainId: ";
+pub const APTOS_MESSAGE_FIELD: &[u8] = b"message: ";
+pub const APTOS_NONCE_FIELD: &[u8] = b"\nnonce: ";
 pub const APTOS_SIGNATURE_SCHEME_ID: u8 = 0;
+pub const APTOS_MULTI_ED25519_SCHEME_ID: u8 = 1;
+pub const APTOS_MAX_MULTI_ED25519_KEYS: usize = 32;
 
 /**
 * An arbitrary signed message used in Aptos.
 * Only the message payload is stored in this struct.
-* The message signed for Aptos is the payload prefixed with APTOS_PREFIX and suffixed with APTOS_SUFFIX.
+* The message signed for Aptos is the `fullMessage` of the wallet standard `signMessage`:
+* APTOS_HEADER, the optional `address`, `application` and `chainId` lines, then the payload
+* in the `message` field and a `nonce` chosen by the frontend.
  */
 
-#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
 pub struct AptosMessage(Vec<u8>);
 
 impl AptosMessage {
@@ -32,35 +47,116 @@ impl AptosMessage {
         self.0.as_slice()
     }
 
-    pub fn parse(data: &[u8]) -> Result<Self> {
-        if let Some(no_prefix) = data.strip_prefix(APTOS_PREFIX) {
-            if let Some(payload) = no_prefix.strip_suffix(APTOS_SUFFIX) {
-                return Ok(AptosMessage(payload.to_vec()));
+    /**
+     * The payload is the part between the `message` field and the last `nonce` field since it
+     * can contain newlines. If the wallet included the `address` field, it must be `address`.
+     */
+    pub fn parse(data: &[u8], address: &AptosAddress) -> Result<Self> {
+        let rest = data
+            .strip_prefix(APTOS_HEADER)
+            .ok_or(ErrorCode::SignatureVerificationWrongPayloadMetadata)?;
+
+        let (address_field, rest) = strip_optional_field(rest, APTOS_ADDRESS_FIELD)?;
+        if let Some(address_field) = address_field {
+            if AptosAddress::parse(address_field)? != *address {
+                return err!(ErrorCode::SignatureVerificationWrongSigner);
             }
         }
-        Err(ErrorCode::SignatureVerificationWrongPayloadMetadata.into())
+        let (_, rest) = strip_optional_field(rest, APTOS_APPLICATION_FIELD)?;
+        let (chain_id, rest) = strip_optional_field(rest, APTOS_CHAIN_ID_FIELD)?;
+        if let Some(chain_id) = chain_id {
+            require!(
+                !chain_id.is_empty() && chain_id.iter().all(u8::is_ascii_digit),
+                ErrorCode::SignatureVerificationWrongPayloadMetadata
+            );
+        }
+
+        let rest = rest
+            .strip_prefix(APTOS_MESSAGE_FIELD)
+            .ok_or(ErrorCode::SignatureVerificationWrongPayloadMetadata)?;
+        let nonce_start = rest
+            .windows(APTOS_NONCE_FIELD.len())
+            .rposition(|window| window == APTOS_NONCE_FIELD)
+            .ok_or(ErrorCode::SignatureVerificationWrongPayloadMetadata)?;
+        let nonce = &rest[nonce_start + APTOS_NONCE_FIELD.len()..];
+        require!(
+            !nonce.is_empty() && !nonce.contains(&b'\n'),
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+        Ok(AptosMessage(rest[..nonce_start].to_vec()))
+    }
+}
+
+/**
+ * If `data` starts with the line `field`, returns the value of that line and what follows it.
+ */
+fn strip_optional_field<'a>(data: &'a [u8], field: &[u8]) -> Result<(Option<&'a [u8]>, &'a [u8])> {
+    match data.strip_prefix(field) {
+        Some(no_field) => {
+            let line_end = no_field
+                .iter()
+                .position(|b| *b == b'\n')
+                .ok_or(ErrorCode::SignatureVerificationWrongPayloadMetadata)?;
+            Ok((Some(&no_field[..line_end]), &no_field[line_end + 1..]))
+        }
+        None => Ok((None, data)),
     }
 }
 
 #[cfg(test)]
-impl Ed25519TestMessage for AptosMessage {
-    fn for_claimant(claimant: &Pubkey) -> Self {
-        Self(get_expected_payload(claimant).into_bytes())
+impl From<&str> for AptosMessage {
+    fn from(payload: &str) -> Self {
+        AptosMessage(payload.as_bytes().to_vec())
     }
+}
 
+#[cfg(test)]
+impl Ed25519TestMessage for AptosMessage {
     fn get_message_with_metadata(&self) -> Vec<u8> {
-        let mut message = APTOS_PREFIX.to_vec();
+        let mut message = APTOS_HEADER.to_vec();
+        message.extend_from_slice(APTOS_MESSAGE_FIELD);
         message.extend_from_slice(&self.0);
-        message.extend_from_slice(APTOS_SUFFIX);
+        message.extend_from_slice(APTOS_NONCE_FIELD);
+        message.extend_from_slice(b"nonce");
         message.to_vec()
     }
 }
 
-#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+#[cfg(test)]
+impl Ed25519TestClaimantMessage for AptosMessage {
+    fn for_claimant(claimant: &Pubkey) -> Self {
+        Self(get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant).into_bytes())
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Debug)]
 pub struct AptosAddress([u8; 32]);
 
 impl AptosAddress {
     pub const LEN: usize = 32;
+
+    /**
+     * Parse a `0x` prefixed hex address. Wallets may drop the leading zeros.
+     */
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let digits = data
+            .strip_prefix(b"0x")
+            .ok_or(ErrorCode::SignatureVerificationWrongPayloadMetadata)?;
+        require!(
+            (1..=2 * Self::LEN).contains(&digits.len()),
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+
+        let mut address = [0u8; Self::LEN];
+        for (i, digit) in digits.iter().rev().enumerate() {
+            let value = (*digit as char)
+                .to_digit(16)
+                .ok_or(ErrorCode::SignatureVerificationWrongPayloadMetadata)?
+                as u8;
+            address[Self::LEN - 1 - i / 2] |= value << (4 * (i % 2));
+        }
+        Ok(AptosAddress(address))
+    }
 }
 
 impl From<Ed25519Pubkey> for AptosAddress {
@@ -73,6 +169,109 @@ impl From<Ed25519Pubkey> for AptosAddress {
     }
 }
 
+/**
+ * A k-of-n MultiEd25519 Aptos account. The authentication key, and therefore the address if
+ * the key was never rotated, is the SHA3-256 hash of the member pubkeys followed by the
+ * threshold and the MultiEd25519 scheme id.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct AptosMultiEd25519Pubkey {
+    pub pubkeys:   Vec<Ed25519Pubkey>,
+    pub threshold: u8,
+}
+
+/**
+ * The member `member_index` of a MultiEd25519 account signed the Aptos message, the
+ * signature is checked by the Ed25519 instruction at `verification_instruction_index`.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct AptosMultiEd25519Signer {
+    pub member_index:                   u8,
+    pub verification_instruction_index: u8,
+}
+
+impl AptosMultiEd25519Pubkey {
+    pub fn get_address(&self) -> AptosAddress {
+        let mut hasher = sha3::Sha3_256::new();
+        for pubkey in &self.pubkeys {
+            hasher.update(pubkey.to_bytes());
+        }
+        hasher.update([self.threshold, APTOS_MULTI_ED25519_SCHEME_ID]);
+        AptosAddress(hasher.finalize().into())
+    }
+
+    /**
+     * Check that at least `threshold` distinct members signed. Signers need to be sorted by
+     * `member_index` so that each member is counted once. Returns the pubkey of each signer.
+     */
+    pub fn check_signers(
+        &self,
+        signers: &[AptosMultiEd25519Signer],
+    ) -> Result<Vec<&Ed25519Pubkey>> {
+        require!(
+            self.pubkeys.len() <= APTOS_MAX_MULTI_ED25519_KEYS
+                && self.threshold > 0
+                && self.threshold as usize <= self.pubkeys.len()
+                && signers.len() >= self.threshold as usize,
+            ErrorCode::InvalidAptosMultiEd25519
+        );
+
+        let mut previous_member_index = None;
+        let mut pubkeys = vec![];
+        for signer in signers {
+            require!(
+                previous_member_index < Some(signer.member_index),
+                ErrorCode::InvalidAptosMultiEd25519
+            );
+            pubkeys.push(
+                self.pubkeys
+                    .get(signer.member_index as usize)
+                    .ok_or(ErrorCode::InvalidAptosMultiEd25519)?,
+            );
+            previous_member_index = Some(signer.member_index);
+        }
+        Ok(pubkeys)
+    }
+}
+
+/**
+ * This message (borsh-serialized) needs to be signed by the dispenser guard after
+ * verifying on-chain that `pubkey` is the current authentication key of the account `address`,
+ * for accounts that rotated their key.
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AptosKeyRotationMessage {
+    address: AptosAddress,
+    pubkey:  Ed25519Pubkey,
+}
+
+impl AptosKeyRotationMessage {
+    pub fn parse_and_check_address_and_pubkey(
+        data: &[u8],
+        address: &AptosAddress,
+        pubkey: &Ed25519Pubkey,
+    ) -> Result<Self> {
+        let result = AptosKeyRotationMessage::parse(data)?;
+
+        if result.address != *address || result.pubkey != *pubkey {
+            return err!(ErrorCode::SignatureVerificationWrongPayload);
+        }
+
+        Ok(result)
+    }
+}
+
+impl GuardAttestation for AptosKeyRotationMessage {
+    const TAG: &'static [u8] = b"aptos-key-rotation-v1";
+}
+
+#[cfg(test)]
+impl AptosKeyRotationMessage {
+    pub fn new(address: AptosAddress, pubkey: Ed25519Pubkey) -> Self {
+        Self { address, pubkey }
+    }
+}
+
 #[cfg(test)]
 impl From<[u8; Self::LEN]> for AptosAddress {
     fn from(bytes: [u8; Self::LEN]) -> Self {
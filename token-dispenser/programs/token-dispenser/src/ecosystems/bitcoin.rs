@@ -0,0 +1,307 @@
+#[cfg(test)]
+use super::cosmos::CompressedSecp256k1Pubkey;
+use {
+    super::cosmos::UncompressedSecp256k1Pubkey,
+    crate::ErrorCode,
+    anchor_lang::{
+        prelude::*,
+        solana_program::hash,
+        AnchorDeserialize,
+        AnchorSerialize,
+    },
+    ripemd::Digest,
+};
+
+pub const BITCOIN_MESSAGE_PREFIX: &[u8] = b"\x18Bitcoin Signed Message:\n";
+pub const LITECOIN_MESSAGE_PREFIX: &[u8] = b"\x19Litecoin Signed Message:\n";
+pub const DOGECOIN_MESSAGE_PREFIX: &[u8] = b"\x19Dogecoin Signed Message:\n";
+
+/// BIP-137 headers are 27 + recovery id, plus 4 for a compressed P2PKH key, 8 for P2SH-P2WPKH
+/// and 12 for P2WPKH
+pub const BIP137_MIN_HEADER: u8 = 27;
+pub const BIP137_MAX_HEADER: u8 = 42;
+pub const BIP137_COMPRESSED_HEADER: u8 = 31;
+
+pub const BIP322_TAG: &[u8] = b"BIP0322-signed-message";
+pub const BITCOIN_SIGHASH_ALL: u32 = 1;
+pub const BITCOIN_OP_RETURN: u8 = 0x6a;
+
+/**
+ * Chains that sign messages the same way as Bitcoin, with their own prefix.
+ * Their addresses hash keys the same way, so the `BitcoinAddress` of a key is the same on all of
+ * them.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Debug)]
+pub enum BitcoinChain {
+    Bitcoin,
+    Litecoin,
+    Dogecoin,
+}
+
+impl BitcoinChain {
+    pub fn get_message_prefix(&self) -> &'static [u8] {
+        match self {
+            BitcoinChain::Bitcoin => BITCOIN_MESSAGE_PREFIX,
+            BitcoinChain::Litecoin => LITECOIN_MESSAGE_PREFIX,
+            BitcoinChain::Dogecoin => DOGECOIN_MESSAGE_PREFIX,
+        }
+    }
+}
+
+/**
+ * A Bitcoin address without the version byte, the checksum and the base58 or bech32 encoding.
+ * P2PKH addresses hold the HASH160 of a public key, P2SH-P2WPKH addresses the HASH160 of the
+ * P2WPKH redeem script and P2WPKH addresses the version 0 witness program.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Debug)]
+pub enum BitcoinAddress {
+    P2pkh { pubkey_hash: [u8; 20] },
+    P2shP2wpkh { script_hash: [u8; 20] },
+    P2wpkh { witness_program: [u8; 20] },
+}
+
+impl BitcoinAddress {
+    /**
+     * Check that the key that produced a BIP-137 signature controls this address.
+     * Only the compression of the key is taken from the header, since some wallets use the P2PKH
+     * headers for segwit addresses.
+     */
+    pub fn check_bip137_signer(
+        &self,
+        pubkey: &UncompressedSecp256k1Pubkey,
+        header: u8,
+    ) -> Result<()> {
+        let compressed_pubkey = pubkey.compress();
+        let is_signer = match (self, header >= BIP137_COMPRESSED_HEADER) {
+            (BitcoinAddress::P2pkh { pubkey_hash }, false) => {
+                *pubkey_hash == hash160(&pubkey.as_bytes())
+            }
+            (BitcoinAddress::P2pkh { pubkey_hash }, true) => {
+                *pubkey_hash == hash160(&compressed_pubkey.as_bytes())
+            }
+            (BitcoinAddress::P2shP2wpkh { script_hash }, true) => {
+                let redeem_script = get_p2wpkh_script(&hash160(&compressed_pubkey.as_bytes()));
+                *script_hash == hash160(&redeem_script)
+            }
+            (BitcoinAddress::P2wpkh { witness_program }, true) => {
+                *witness_program == hash160(&compressed_pubkey.as_bytes())
+            }
+            _ => false,
+        };
+        if !is_signer {
+            return err!(ErrorCode::SignatureVerificationWrongSigner);
+        }
+        Ok(())
+    }
+}
+
+/**
+ * Returns the recovery id of a BIP-137 signature from its header.
+ */
+pub fn parse_bip137_header(header: u8) -> Result<u8> {
+    require!(
+        (BIP137_MIN_HEADER..=BIP137_MAX_HEADER).contains(&header),
+        ErrorCode::InvalidBip137Header
+    );
+    Ok((header - BIP137_MIN_HEADER) % 4)
+}
+
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    let mut hasher = ripemd::Ripemd160::new();
+    hasher.update(hash::hashv(&[data]));
+    hasher.finalize().into()
+}
+
+fn double_sha256(data: &[&[u8]]) -> [u8; 32] {
+    hash::hashv(&[&hash::hashv(data).to_bytes()]).to_bytes()
+}
+
+fn get_p2wpkh_script(pubkey_hash: &[u8; 20]) -> Vec<u8> {
+    let mut script = vec![0x00, 0x14];
+    script.extend_from_slice(pubkey_hash);
+    script
+}
+
+/// Bitcoin's CompactSize encoding of lengths
+fn push_compact_size(buffer: &mut Vec<u8>, value: usize) {
+    if value < 0xfd {
+        buffer.push(value as u8);
+    } else if value <= 0xffff {
+        buffer.push(0xfd);
+        buffer.extend_from_slice(&(value as u16).to_le_bytes());
+    } else {
+        buffer.push(0xfe);
+        buffer.extend_from_slice(&(value as u32).to_le_bytes());
+    }
+}
+
+/**
+ * A message signed with `signmessage` (BIP-137).
+ * The message that gets signed is the double SHA256 hash of the prefix of the chain, followed by
+ * the length of the payload and the payload. Like for Cosmos, we can't recover the payload from
+ * the hash, so we check that the hash matches the hash of the expected payload.
+ */
+pub struct BitcoinMessage;
+
+impl BitcoinMessage {
+    pub fn get_expected_hash(prefix: &[u8], payload: &str) -> [u8; 32] {
+        let mut length = vec![];
+        push_compact_size(&mut length, payload.len());
+        double_sha256(&[prefix, &length, payload.as_bytes()])
+    }
+
+    pub fn check_hashed_payload(
+        hashed_payload: &[u8],
+        prefix: &[u8],
+        expected_payload: &str,
+    ) -> Result<()> {
+        if hashed_payload != BitcoinMessage::get_expected_hash(prefix, expected_payload) {
+            return err!(ErrorCode::SignatureVerificationWrongPayload);
+        }
+        Ok(())
+    }
+}
+
+/**
+ * A simple BIP-322 signature of a P2WPKH address.
+ * The signer signs the segwit sighash of a virtual `to_sign` transaction, which spends the
+ * output of a virtual `to_spend` transaction that commits to the tagged hash of the payload.
+ */
+pub struct Bip322Message;
+
+impl Bip322Message {
+    pub fn get_message_hash(payload: &str) -> [u8; 32] {
+        let tag = hash::hashv(&[BIP322_TAG]).to_bytes();
+        hash::hashv(&[&tag, &tag, payload.as_bytes()]).to_bytes()
+    }
+
+    /// The txid of `to_spend`, in internal byte order
+    pub fn get_to_spend_txid(pubkey_hash: &[u8; 20], payload: &str) -> [u8; 32] {
+        let mut transaction = vec![];
+        transaction.extend_from_slice(&0u32.to_le_bytes()); // version
+        transaction.push(1); // inputs
+        transaction.extend_from_slice(&[0u8; 32]);
+        transaction.extend_from_slice(&u32::MAX.to_le_bytes());
+        transaction.extend_from_slice(&[0x22, 0x00, 0x20]); // OP_0 PUSH32
+        transaction.extend_from_slice(&Bip322Message::get_message_hash(payload));
+        transaction.extend_from_slice(&0u32.to_le_bytes()); // sequence
+        transaction.push(1); // outputs
+        transaction.extend_from_slice(&0u64.to_le_bytes());
+        let script = get_p2wpkh_script(pubkey_hash);
+        push_compact_size(&mut transaction, script.len());
+        transaction.extend_from_slice(&script);
+        transaction.extend_from_slice(&0u32.to_le_bytes()); // lock time
+        double_sha256(&[&transaction])
+    }
+
+    /// The BIP-143 sighash of the only input of `to_sign`, with `SIGHASH_ALL`
+    pub fn get_expected_hash(pubkey_hash: &[u8; 20], payload: &str) -> [u8; 32] {
+        let mut outpoint = Bip322Message::get_to_spend_txid(pubkey_hash, payload).to_vec();
+        outpoint.extend_from_slice(&0u32.to_le_bytes());
+
+        let mut script_code = vec![0x19, 0x76, 0xa9, 0x14]; // OP_DUP OP_HASH160 PUSH20
+        script_code.extend_from_slice(pubkey_hash);
+        script_code.extend_from_slice(&[0x88, 0xac]); // OP_EQUALVERIFY OP_CHECKSIG
+
+        let mut output = 0u64.to_le_bytes().to_vec();
+        output.extend_from_slice(&[1, BITCOIN_OP_RETURN]);
+
+        double_sha256(&[
+            &0u32.to_le_bytes(), // version
+            &double_sha256(&[&outpoint]),
+            &double_sha256(&[&0u32.to_le_bytes()]),
+            &outpoint,
+            &script_code,
+            &0u64.to_le_bytes(), // amount
+            &0u32.to_le_bytes(), // sequence
+            &double_sha256(&[&output]),
+            &0u32.to_le_bytes(), // lock time
+            &BITCOIN_SIGHASH_ALL.to_le_bytes(),
+        ])
+    }
+
+    pub fn check_hashed_payload(
+        hashed_payload: &[u8],
+        pubkey_hash: &[u8; 20],
+        expected_payload: &str,
+    ) -> Result<()> {
+        if hashed_payload != Bip322Message::get_expected_hash(pubkey_hash, expected_payload) {
+            return err!(ErrorCode::SignatureVerificationWrongPayload);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    hex::decode(hex).unwrap()
+}
+
+#[test]
+fn test_bip322_vectors() {
+    // From the test vectors of BIP-322
+    assert_eq!(
+        Bip322Message::get_message_hash("").to_vec(),
+        hex_to_bytes("c90c269c4f8fcbe6880f72a721ddfbf1914268a794cbb21cfafee13770ae19f1")
+    );
+    assert_eq!(
+        Bip322Message::get_message_hash("Hello World").to_vec(),
+        hex_to_bytes("f0eb03b1a75ac6d9847f55c624a99169b5dccba2a31f5b23bea77ba270de0a7a")
+    );
+
+    // bc1q9vza2e8x573nczrlzms0wvx3gsqjx7vavgkx0l
+    let pubkey = CompressedSecp256k1Pubkey::from(
+        <[u8; 33]>::try_from(hex_to_bytes(
+            "02c7f12003196442943d8588e01aee840423cc54fc1521526a3b85c2b0cbd58872",
+        ))
+        .unwrap(),
+    );
+    let pubkey_hash = hash160(&pubkey.as_bytes());
+    assert_eq!(
+        pubkey_hash.to_vec(),
+        hex_to_bytes("2b05d564e6a7a33c087f16e0f730d1440123799d")
+    );
+
+    let mut txid = Bip322Message::get_to_spend_txid(&pubkey_hash, "");
+    txid.reverse();
+    assert_eq!(
+        txid.to_vec(),
+        hex_to_bytes("c5680aa69bb8d860bf82d4e9cd3504b55dde018de765a91bb566283c545a99a7")
+    );
+    let mut txid = Bip322Message::get_to_spend_txid(&pubkey_hash, "Hello World");
+    txid.reverse();
+    assert_eq!(
+        txid.to_vec(),
+        hex_to_bytes("b79d196740ad5217771c1098fc4a4b51e0535c32236c71f1ea4d61a2d603352b")
+    );
+
+    // The witness of the "Hello World" signature
+    let signature = libsecp256k1::Signature::parse_der(&hex_to_bytes(concat!(
+        "304402206517c8637a7bfc3a154edcba6196d64bbd5b73955cb7da7d1626bcdde466c364",
+        "022022bf10d19fc0bb69b4596e306b362acaa835293cf693bb176f7324b531f5afec",
+    )))
+    .unwrap();
+    assert!(libsecp256k1::verify(
+        &libsecp256k1::Message::parse(&Bip322Message::get_expected_hash(
+            &pubkey_hash,
+            "Hello World"
+        )),
+        &signature,
+        &libsecp256k1::PublicKey::parse_compressed(&pubkey.as_bytes()).unwrap(),
+    ));
+}
+
+#[test]
+fn test_bip137_header() {
+    assert_eq!(parse_bip137_header(27).unwrap(), 0);
+    assert_eq!(parse_bip137_header(34).unwrap(), 3);
+    assert_eq!(parse_bip137_header(40).unwrap(), 1);
+    assert_eq!(
+        parse_bip137_header(26).unwrap_err(),
+        ErrorCode::InvalidBip137Header.into()
+    );
+    assert_eq!(
+        parse_bip137_header(43).unwrap_err(),
+        ErrorCode::InvalidBip137Header.into()
+    );
+}
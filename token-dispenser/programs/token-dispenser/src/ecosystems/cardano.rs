@@ -0,0 +1,378 @@
+#[cfg(test)]
+use super::{
+    ed25519::{
+        Ed25519TestClaimantMessage,
+        Ed25519TestMessage,
+    },
+    get_expected_payload,
+    DEFAULT_AUTHORIZATION_TEMPLATE,
+};
+use {
+    super::ed25519::Ed25519Pubkey,
+    crate::ErrorCode,
+    anchor_lang::{
+        prelude::*,
+        AnchorDeserialize,
+        AnchorSerialize,
+    },
+};
+
+pub const CARDANO_SIG_STRUCTURE_CONTEXT: &[u8] = b"Signature1";
+/// The CBOR encoding of the text string "address", the label of the address protected header
+pub const CARDANO_ADDRESS_HEADER_LABEL: &[u8] = b"\x67address";
+
+/// Types of Shelley addresses whose signing credential is a key hash, see CIP-19
+pub const CARDANO_BASE_ADDRESS: u8 = 0;
+pub const CARDANO_BASE_ADDRESS_STAKE_SCRIPT: u8 = 2;
+pub const CARDANO_POINTER_ADDRESS: u8 = 4;
+pub const CARDANO_ENTERPRISE_ADDRESS: u8 = 6;
+pub const CARDANO_REWARD_ADDRESS: u8 = 14;
+
+pub const CBOR_UNSIGNED_INTEGER: u8 = 0;
+pub const CBOR_NEGATIVE_INTEGER: u8 = 1;
+pub const CBOR_BYTE_STRING: u8 = 2;
+pub const CBOR_TEXT_STRING: u8 = 3;
+pub const CBOR_ARRAY: u8 = 4;
+pub const CBOR_MAP: u8 = 5;
+
+/**
+ * The blake2b-224 hash of an ed25519 key, which is the payment or stake credential of a Cardano
+ * address.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Debug)]
+pub struct CardanoKeyHash([u8; CardanoKeyHash::LEN]);
+
+impl CardanoKeyHash {
+    pub const LEN: usize = 28;
+
+    /**
+     * CIP-30 `signData` signs with the payment key for base, pointer and enterprise addresses and
+     * with the stake key for reward addresses. Script credentials can't sign.
+     */
+    pub fn from_signing_address(address: &[u8]) -> Result<Self> {
+        let (header, credentials) = address
+            .split_first()
+            .ok_or(ErrorCode::SignatureVerificationWrongPayloadMetadata)?;
+        match header >> 4 {
+            CARDANO_BASE_ADDRESS
+            | CARDANO_BASE_ADDRESS_STAKE_SCRIPT
+            | CARDANO_POINTER_ADDRESS
+            | CARDANO_ENTERPRISE_ADDRESS
+            | CARDANO_REWARD_ADDRESS => Ok(CardanoKeyHash(
+                credentials
+                    .get(..Self::LEN)
+                    .and_then(|key_hash| key_hash.try_into().ok())
+                    .ok_or(ErrorCode::SignatureVerificationWrongPayloadMetadata)?,
+            )),
+            _ => err!(ErrorCode::SignatureVerificationWrongSigner),
+        }
+    }
+}
+
+impl From<Ed25519Pubkey> for CardanoKeyHash {
+    fn from(val: Ed25519Pubkey) -> Self {
+        let mut key_hash = [0u8; Self::LEN];
+        key_hash.copy_from_slice(
+            blake2_rfc::blake2b::blake2b(Self::LEN, &[], &val.to_bytes()).as_bytes(),
+        );
+        CardanoKeyHash(key_hash)
+    }
+}
+
+#[cfg(test)]
+impl CardanoKeyHash {
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        self.0
+    }
+}
+
+/**
+ * A message signed with CIP-30 `signData`.
+ * Only the message payload is stored in this struct.
+ * The wallet signs the COSE `Sig_structure`, the CBOR array `["Signature1", protected headers,
+ * external aad, payload]` where the protected headers are a CBOR-encoded map that contains the
+ * address. Hashed payloads aren't supported.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
+pub struct CardanoMessage(Vec<u8>);
+
+impl CardanoMessage {
+    pub fn parse(data: &[u8], key_hash: &CardanoKeyHash) -> Result<Self> {
+        let mut reader = CborReader(data);
+        require!(
+            reader.read_head()? == (CBOR_ARRAY, 4),
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+        require!(
+            reader.read_bytes(CBOR_TEXT_STRING)? == CARDANO_SIG_STRUCTURE_CONTEXT,
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+        let protected_headers = reader.read_bytes(CBOR_BYTE_STRING)?;
+        require!(
+            reader.read_bytes(CBOR_BYTE_STRING)?.is_empty(),
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+        let payload = reader.read_bytes(CBOR_BYTE_STRING)?;
+        require!(
+            reader.0.is_empty(),
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+
+        let address = parse_address_header(protected_headers)?;
+        if CardanoKeyHash::from_signing_address(address)? != *key_hash {
+            return err!(ErrorCode::SignatureVerificationWrongSigner);
+        }
+        Ok(CardanoMessage(payload.to_vec()))
+    }
+
+    pub fn get_payload(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+/**
+ * Returns the address in the protected headers map, the other headers (like the algorithm) are
+ * ignored.
+ */
+fn parse_address_header(protected_headers: &[u8]) -> Result<&[u8]> {
+    let mut reader = CborReader(protected_headers);
+    let (major_type, len) = reader.read_head()?;
+    require!(
+        major_type == CBOR_MAP,
+        ErrorCode::SignatureVerificationWrongPayloadMetadata
+    );
+
+    let mut address = None;
+    for _ in 0..len {
+        if reader.read_item()? == CARDANO_ADDRESS_HEADER_LABEL {
+            require!(
+                address.is_none(),
+                ErrorCode::SignatureVerificationWrongPayloadMetadata
+            );
+            address = Some(reader.read_bytes(CBOR_BYTE_STRING)?);
+        } else {
+            reader.read_item()?;
+        }
+    }
+    require!(
+        reader.0.is_empty(),
+        ErrorCode::SignatureVerificationWrongPayloadMetadata
+    );
+    Ok(address.ok_or(ErrorCode::SignatureVerificationWrongPayloadMetadata)?)
+}
+
+/**
+ * A minimal CBOR reader for the definite-length integers, strings, arrays and maps that CIP-30
+ * wallets use. Other items (tags, floats, indefinite lengths) are rejected.
+ */
+struct CborReader<'a>(&'a [u8]);
+
+impl<'a> CborReader<'a> {
+    fn read(&mut self, len: u64) -> Result<&'a [u8]> {
+        require!(
+            len <= self.0.len() as u64,
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+        let (result, rest) = self.0.split_at(len as usize);
+        self.0 = rest;
+        Ok(result)
+    }
+
+    /// Returns the major type and the argument of the next item
+    fn read_head(&mut self) -> Result<(u8, u64)> {
+        let initial_byte = self.read(1)?[0];
+        let additional_information = initial_byte & 0x1f;
+        let argument = match additional_information {
+            0..=23 => additional_information as u64,
+            24..=27 => self
+                .read(1 << (additional_information - 24))?
+                .iter()
+                .fold(0, |argument, byte| (argument << 8) | *byte as u64),
+            _ => return err!(ErrorCode::SignatureVerificationWrongPayloadMetadata),
+        };
+        Ok((initial_byte >> 5, argument))
+    }
+
+    fn read_bytes(&mut self, expected_major_type: u8) -> Result<&'a [u8]> {
+        let (major_type, len) = self.read_head()?;
+        require!(
+            major_type == expected_major_type,
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+        self.read(len)
+    }
+
+    /// Skips the next item, including the items nested in it, and returns its encoding
+    fn read_item(&mut self) -> Result<&'a [u8]> {
+        let start = self.0;
+        let mut remaining_items: u64 = 1;
+        while remaining_items > 0 {
+            remaining_items -= 1;
+            let nested_items = match self.read_head()? {
+                (CBOR_UNSIGNED_INTEGER | CBOR_NEGATIVE_INTEGER, _) => 0,
+                (CBOR_BYTE_STRING | CBOR_TEXT_STRING, len) => {
+                    self.read(len)?;
+                    0
+                }
+                (CBOR_ARRAY, len) => len,
+                (CBOR_MAP, len) => len
+                    .checked_mul(2)
+                    .ok_or(ErrorCode::SignatureVerificationWrongPayloadMetadata)?,
+                _ => return err!(ErrorCode::SignatureVerificationWrongPayloadMetadata),
+            };
+            remaining_items = remaining_items
+                .checked_add(nested_items)
+                .ok_or(ErrorCode::SignatureVerificationWrongPayloadMetadata)?;
+        }
+        Ok(&start[..start.len() - self.0.len()])
+    }
+}
+
+#[cfg(test)]
+fn encode_cbor_head(major_type: u8, argument: usize) -> Vec<u8> {
+    match argument {
+        0..=23 => vec![(major_type << 5) | argument as u8],
+        24..=0xff => vec![(major_type << 5) | 24, argument as u8],
+        _ => [
+            &[(major_type << 5) | 25][..],
+            &(argument as u16).to_be_bytes(),
+        ]
+        .concat(),
+    }
+}
+
+#[cfg(test)]
+fn encode_cbor_bytes(major_type: u8, bytes: &[u8]) -> Vec<u8> {
+    [&encode_cbor_head(major_type, bytes.len())[..], bytes].concat()
+}
+
+/**
+ * The address that signs and the payload, encoded like the `Sig_structure` of CIP-30 wallets
+ * with the EdDSA algorithm header.
+ */
+#[cfg(test)]
+#[derive(Clone)]
+pub struct CardanoTestMessage {
+    pub address: Vec<u8>,
+    pub payload: Vec<u8>,
+}
+
+#[cfg(test)]
+impl CardanoTestMessage {
+    pub fn new(address: Vec<u8>, claimant: &Pubkey) -> Self {
+        Self {
+            address,
+            payload: get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant).into_bytes(),
+        }
+    }
+}
+
+#[cfg(test)]
+impl Ed25519TestMessage for CardanoTestMessage {
+    fn get_message_with_metadata(&self) -> Vec<u8> {
+        let protected_headers = [
+            &encode_cbor_head(CBOR_MAP, 2)[..],
+            &[0x01, 0x27], // alg: EdDSA
+            CARDANO_ADDRESS_HEADER_LABEL,
+            &encode_cbor_bytes(CBOR_BYTE_STRING, &self.address),
+        ]
+        .concat();
+        [
+            &encode_cbor_head(CBOR_ARRAY, 4)[..],
+            &encode_cbor_bytes(CBOR_TEXT_STRING, CARDANO_SIG_STRUCTURE_CONTEXT),
+            &encode_cbor_bytes(CBOR_BYTE_STRING, &protected_headers),
+            &encode_cbor_bytes(CBOR_BYTE_STRING, &[]),
+            &encode_cbor_bytes(CBOR_BYTE_STRING, &self.payload),
+        ]
+        .concat()
+    }
+}
+
+#[cfg(test)]
+impl Ed25519TestClaimantMessage for CardanoTestMessage {
+    fn for_claimant(claimant: &Pubkey) -> Self {
+        let mut address = vec![(CARDANO_ENTERPRISE_ADDRESS << 4) | 1];
+        address.extend_from_slice(&rand::random::<[u8; CardanoKeyHash::LEN]>());
+        Self::new(address, claimant)
+    }
+}
+
+#[test]
+fn test_parse() {
+    let mut address = vec![0x61];
+    address.extend_from_slice(&[7; CardanoKeyHash::LEN]);
+    let key_hash = CardanoKeyHash([7; CardanoKeyHash::LEN]);
+
+    // ["Signature1", <<{1: -8, "address": address}>>, h'', 'hello']
+    let data = [
+        &hex::decode("846a5369676e617475726531582aa201276761646472657373581d").unwrap()[..],
+        &address,
+        &hex::decode("404568656c6c6f").unwrap(),
+    ]
+    .concat();
+    assert_eq!(
+        CardanoMessage::parse(&data, &key_hash)
+            .unwrap()
+            .get_payload(),
+        b"hello"
+    );
+    assert_eq!(
+        CardanoTestMessage {
+            address: address.clone(),
+            payload: b"hello".to_vec(),
+        }
+        .get_message_with_metadata(),
+        data
+    );
+
+    assert_eq!(
+        CardanoMessage::parse(&data, &CardanoKeyHash([8; CardanoKeyHash::LEN])).unwrap_err(),
+        ErrorCode::SignatureVerificationWrongSigner.into()
+    );
+    assert_eq!(
+        CardanoMessage::parse(&data[..data.len() - 1], &key_hash).unwrap_err(),
+        ErrorCode::SignatureVerificationWrongPayloadMetadata.into()
+    );
+    assert_eq!(
+        CardanoMessage::parse(&[&data[..], &[0]].concat(), &key_hash).unwrap_err(),
+        ErrorCode::SignatureVerificationWrongPayloadMetadata.into()
+    );
+}
+
+#[test]
+fn test_signing_key_hash() {
+    let payment_key_hash = [1; CardanoKeyHash::LEN];
+    let stake_key_hash = [2; CardanoKeyHash::LEN];
+
+    let base_address = [&[0x01][..], &payment_key_hash, &stake_key_hash].concat();
+    let reward_address = [&[0xe1][..], &stake_key_hash].concat();
+    let script_address = [&[0x71][..], &payment_key_hash].concat();
+    assert_eq!(
+        CardanoKeyHash::from_signing_address(&base_address).unwrap(),
+        CardanoKeyHash(payment_key_hash)
+    );
+    assert_eq!(
+        CardanoKeyHash::from_signing_address(&reward_address).unwrap(),
+        CardanoKeyHash(stake_key_hash)
+    );
+    assert_eq!(
+        CardanoKeyHash::from_signing_address(&script_address).unwrap_err(),
+        ErrorCode::SignatureVerificationWrongSigner.into()
+    );
+    assert_eq!(
+        CardanoKeyHash::from_signing_address(&reward_address[..CardanoKeyHash::LEN]).unwrap_err(),
+        ErrorCode::SignatureVerificationWrongPayloadMetadata.into()
+    );
+}
+
+#[test]
+fn test_read_item() {
+    // {1: [-8, h'00'], "a": {}} followed by 0
+    let data = hex::decode("a201822741006161a000").unwrap();
+    let mut reader = CborReader(&data);
+    assert_eq!(reader.read_item().unwrap(), &data[..data.len() - 1]);
+    assert_eq!(reader.read_item().unwrap(), &[0]);
+    assert!(reader.read_item().is_err());
+    // indefinite-length arrays aren't supported
+    assert!(CborReader(&[0x9f, 0x00, 0xff]).read_item().is_err());
+}
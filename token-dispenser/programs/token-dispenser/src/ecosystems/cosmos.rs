@@ -1,19 +1,23 @@
 #[cfg(test)]
 use super::secp256k1::Secp256k1TestMessage;
 use {
-    super::{
-        get_expected_payload,
-        secp256k1::{
-            EvmPubkey,
-            SECP256K1_COMPRESSED_PUBKEY_LENGTH,
-            SECP256K1_EVEN_PREFIX,
-            SECP256K1_ODD_PREFIX,
-        },
+    super::secp256k1::{
+        secp256k1_verify_signer,
+        EvmPubkey,
+        Secp256k1Signature,
+        SECP256K1_COMPRESSED_PUBKEY_LENGTH,
+        SECP256K1_EVEN_PREFIX,
+        SECP256K1_FULL_PREFIX,
+        SECP256K1_ODD_PREFIX,
     },
     crate::ErrorCode,
     anchor_lang::{
         prelude::*,
-        solana_program::hash,
+        solana_program::{
+            hash,
+            keccak,
+            secp256k1_recover::Secp256k1Pubkey,
+        },
         AnchorDeserialize,
         AnchorSerialize,
     },
@@ -31,7 +35,37 @@ use {
 
 pub const EXPECTED_COSMOS_MESSAGE_TYPE: &str = "sign/MsgSignData";
 pub const INJECTIVE_CHAIN_ID: &str = "inj";
-pub const ADMISSIBLE_CHAIN_IDS: [&str; 2] = ["terra", "osmo"];
+/// The bech32 prefixes allowed at `initialize`, the admin can then edit `Config::cosmos_chain_ids`
+pub const DEFAULT_COSMOS_CHAIN_IDS: [&str; 2] = ["terra", "osmo"];
+pub const MAX_COSMOS_CHAIN_IDS: usize = 16;
+pub const MAX_COSMOS_CHAIN_ID_LEN: usize = 16;
+/// Amino prefixes of `tendermint/PubKeyMultisigThreshold` and `tendermint/PubKeySecp256k1`
+pub const AMINO_MULTISIG_THRESHOLD_PREFIX: [u8; 4] = [0x22, 0xc1, 0xf7, 0xe2];
+pub const AMINO_SECP256K1_PREFIX: [u8; 4] = [0xeb, 0x5a, 0xe9, 0x87];
+
+pub fn get_default_cosmos_chain_ids() -> Vec<String> {
+    DEFAULT_COSMOS_CHAIN_IDS
+        .iter()
+        .map(|chain_id| chain_id.to_string())
+        .collect()
+}
+
+/**
+ * Check that the allowlist fits in `Config` and only contains lowercase bech32 prefixes.
+ */
+pub fn check_cosmos_chain_ids(chain_ids: &[String]) -> Result<()> {
+    require!(
+        chain_ids.len() <= MAX_COSMOS_CHAIN_IDS
+            && chain_ids.iter().all(|chain_id| {
+                (1..=MAX_COSMOS_CHAIN_ID_LEN).contains(&chain_id.len())
+                    && chain_id
+                        .bytes()
+                        .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+            }),
+        ErrorCode::InvalidCosmosChainIds
+    );
+    Ok(())
+}
 
 /**
 * An ADR036 message used in Cosmos. ADR036 is a standard for signing arbitrary data.
@@ -113,10 +147,29 @@ impl CosmosMessage {
     pub fn check_hashed_payload(
         hashed_message: &[u8],
         signer: &CosmosBech32Address,
-        claimant: &Pubkey,
+        expected_payload: &str,
+    ) -> Result<()> {
+        if hashed_message != CosmosMessage::get_expected_hash(expected_payload.as_bytes(), signer) {
+            return err!(ErrorCode::SignatureVerificationWrongPayload);
+        }
+        Ok(())
+    }
+
+    /**
+     * Ethermint chains (Injective, Evmos...) use `ethsecp256k1` keys, which sign the Keccak256
+     * hash of the signed doc instead of its SHA256 hash.
+     */
+    pub fn get_expected_keccak_hash(payload: &[u8], signer: &CosmosBech32Address) -> [u8; 32] {
+        keccak::hashv(&[&CosmosMessage::build_message(payload, signer)]).to_bytes()
+    }
+
+    pub fn check_keccak_hashed_payload(
+        hashed_message: &[u8],
+        signer: &CosmosBech32Address,
+        expected_payload: &str,
     ) -> Result<()> {
         if hashed_message
-            != CosmosMessage::get_expected_hash(get_expected_payload(claimant).as_bytes(), signer)
+            != CosmosMessage::get_expected_keccak_hash(expected_payload.as_bytes(), signer)
         {
             return err!(ErrorCode::SignatureVerificationWrongPayload);
         }
@@ -175,28 +228,24 @@ struct CosmosCoin {
     denom:  String,
 }
 
-impl UncompressedSecp256k1Pubkey {
+impl CompressedSecp256k1Pubkey {
     /** Cosmos public addresses are different than the public key.
      * This one way algorithm converts the public key to the public address.
      * Note that the claimant needs to submit the public key to the program
      * to verify the signature.
      */
-    pub fn into_bech32(self, chain_id: &str) -> Result<CosmosBech32Address> {
-        let mut compressed: [u8; SECP256K1_COMPRESSED_PUBKEY_LENGTH] =
-            [0; SECP256K1_COMPRESSED_PUBKEY_LENGTH];
-        compressed[1..].copy_from_slice(&self.0[1..SECP256K1_COMPRESSED_PUBKEY_LENGTH]);
-        compressed[0] = if self.0[Self::LEN - 1] % 2 == 0 {
-            SECP256K1_EVEN_PREFIX
-        } else {
-            SECP256K1_ODD_PREFIX
-        };
-        let hash1 = hash::hashv(&[&compressed]);
+    pub fn into_bech32(
+        self,
+        chain_id: &str,
+        admissible_chain_ids: &[String],
+    ) -> Result<CosmosBech32Address> {
+        let hash1 = hash::hashv(&[&self.0]);
         let mut hasher: ripemd::Ripemd160 = ripemd::Ripemd160::new();
         hasher.update(hash1);
         let hash2 = hasher.finalize();
 
         require!(
-            ADMISSIBLE_CHAIN_IDS.contains(&chain_id),
+            admissible_chain_ids.iter().any(|id| id == chain_id),
             ErrorCode::UnauthorizedCosmosChainId
         );
 
@@ -210,6 +259,51 @@ impl UncompressedSecp256k1Pubkey {
     }
 }
 
+impl UncompressedSecp256k1Pubkey {
+    /// The 33 byte SEC1 encoding of the same point, i.e. its x coordinate prefixed by the
+    /// parity of its y coordinate
+    pub fn compress(&self) -> CompressedSecp256k1Pubkey {
+        let mut compressed = [0u8; CompressedSecp256k1Pubkey::LEN];
+        compressed[1..].copy_from_slice(&self.0[1..CompressedSecp256k1Pubkey::LEN]);
+        compressed[0] = if self.0[Self::LEN - 1] & 1 == 0 {
+            SECP256K1_EVEN_PREFIX
+        } else {
+            SECP256K1_ODD_PREFIX
+        };
+        CompressedSecp256k1Pubkey(compressed)
+    }
+
+    /**
+     * Ethermint `ethsecp256k1` addresses are EVM addresses (the last 20 bytes of the Keccak256
+     * hash of the public key) encoded in bech32. Injective is always allowed since its
+     * addresses are the same as the ones of `IdentityCertificate::Injective`.
+     */
+    pub fn into_ethermint_bech32(
+        self,
+        chain_id: &str,
+        admissible_chain_ids: &[String],
+    ) -> Result<CosmosBech32Address> {
+        require!(
+            chain_id == INJECTIVE_CHAIN_ID || admissible_chain_ids.iter().any(|id| id == chain_id),
+            ErrorCode::UnauthorizedCosmosChainId
+        );
+
+        let hash = keccak::hashv(&[&self.0[1..]]);
+        Ok(CosmosBech32Address(
+            bech32::encode(
+                chain_id,
+                (&hash.0[12..]).to_base32(),
+                bech32::Variant::Bech32,
+            )
+            .unwrap(),
+        ))
+    }
+
+    pub fn as_bytes(&self) -> [u8; Self::LEN] {
+        self.0
+    }
+}
+
 
 /**
  * A Secp256k1 pubkey used in Cosmos.
@@ -220,6 +314,134 @@ impl UncompressedSecp256k1Pubkey {
     pub const LEN: usize = 65;
 }
 
+impl From<Secp256k1Pubkey> for UncompressedSecp256k1Pubkey {
+    fn from(value: Secp256k1Pubkey) -> Self {
+        let mut bytes = [0u8; Self::LEN];
+        bytes[0] = SECP256K1_FULL_PREFIX;
+        bytes[1..].copy_from_slice(&value.0);
+        UncompressedSecp256k1Pubkey(bytes)
+    }
+}
+
+/**
+ * A Secp256k1 pubkey in compressed form, as returned by Cosmos wallets.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq)]
+pub struct CompressedSecp256k1Pubkey([u8; Self::LEN]);
+impl CompressedSecp256k1Pubkey {
+    pub const LEN: usize = SECP256K1_COMPRESSED_PUBKEY_LENGTH;
+}
+
+
+/**
+ * A Cosmos legacy threshold multisig, i.e. `cosmos.crypto.multisig.LegacyAminoPubKey`.
+ * Its address is the truncated SHA256 hash of its amino encoding, so it depends on the order
+ * of the members.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq)]
+pub struct LegacyAminoPubKey {
+    pub threshold: u32,
+    pub pubkeys:   Vec<CompressedSecp256k1Pubkey>,
+}
+
+/**
+ * The signature of the ADR036 signed doc by the member `member_index` of a `LegacyAminoPubKey`.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq)]
+pub struct CosmosMultisigSignature {
+    pub member_index: u8,
+    pub signature:    Secp256k1Signature,
+    pub recovery_id:  u8,
+}
+
+impl LegacyAminoPubKey {
+    /**
+     * The amino (binary bare) encoding of the multisig, as done by `encodeAminoPubkey` in
+     * cosmjs: field 1 is the threshold and field 2 is repeated for each member pubkey.
+     */
+    pub fn to_amino_bytes(&self) -> Vec<u8> {
+        let mut result = AMINO_MULTISIG_THRESHOLD_PREFIX.to_vec();
+        result.push(0x08);
+        push_uvarint(&mut result, self.threshold.into());
+        for pubkey in &self.pubkeys {
+            result.push(0x12);
+            push_uvarint(
+                &mut result,
+                (AMINO_SECP256K1_PREFIX.len() + 1 + CompressedSecp256k1Pubkey::LEN) as u64,
+            );
+            result.extend_from_slice(&AMINO_SECP256K1_PREFIX);
+            result.push(CompressedSecp256k1Pubkey::LEN as u8);
+            result.extend_from_slice(&pubkey.0);
+        }
+        result
+    }
+
+    pub fn to_bech32(
+        &self,
+        chain_id: &str,
+        admissible_chain_ids: &[String],
+    ) -> Result<CosmosBech32Address> {
+        require!(
+            admissible_chain_ids.iter().any(|id| id == chain_id),
+            ErrorCode::UnauthorizedCosmosChainId
+        );
+
+        let hash = hash::hashv(&[&self.to_amino_bytes()]);
+        Ok(CosmosBech32Address(
+            bech32::encode(
+                chain_id,
+                (&hash.to_bytes()[..20]).to_base32(),
+                bech32::Variant::Bech32,
+            )
+            .unwrap(),
+        ))
+    }
+
+    /**
+     * Check that at least `threshold` distinct members signed `hashed_message`. Signatures need
+     * to be sorted by `member_index` so that each member is counted once.
+     */
+    pub fn check_signatures(
+        &self,
+        signatures: &[CosmosMultisigSignature],
+        hashed_message: &[u8],
+    ) -> Result<()> {
+        require!(
+            self.threshold > 0
+                && self.threshold as usize <= self.pubkeys.len()
+                && signatures.len() >= self.threshold as usize,
+            ErrorCode::InvalidCosmosMultisig
+        );
+
+        let mut previous_member_index = None;
+        for signature in signatures {
+            require!(
+                previous_member_index < Some(signature.member_index),
+                ErrorCode::InvalidCosmosMultisig
+            );
+            let pubkey = self
+                .pubkeys
+                .get(signature.member_index as usize)
+                .ok_or(ErrorCode::InvalidCosmosMultisig)?;
+            secp256k1_verify_signer(
+                &signature.signature,
+                &signature.recovery_id,
+                pubkey,
+                hashed_message,
+            )?;
+            previous_member_index = Some(signature.member_index);
+        }
+        Ok(())
+    }
+}
+
+fn push_uvarint(result: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        result.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    result.push(value as u8);
+}
 
 #[cfg(test)]
 impl From<[u8; Self::LEN]> for UncompressedSecp256k1Pubkey {
@@ -228,6 +450,13 @@ impl From<[u8; Self::LEN]> for UncompressedSecp256k1Pubkey {
     }
 }
 
+#[cfg(test)]
+impl From<[u8; Self::LEN]> for CompressedSecp256k1Pubkey {
+    fn from(bytes: [u8; Self::LEN]) -> Self {
+        CompressedSecp256k1Pubkey(bytes)
+    }
+}
+
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
 pub struct CosmosBech32Address(String);
@@ -289,3 +518,91 @@ impl CosmosMessage {
         self.signer.clone()
     }
 }
+
+#[test]
+pub fn test_check_cosmos_chain_ids() {
+    assert!(check_cosmos_chain_ids(&get_default_cosmos_chain_ids()).is_ok());
+    assert!(check_cosmos_chain_ids(&[]).is_ok());
+    assert!(check_cosmos_chain_ids(&["cosmos".to_string(), "celestia".to_string()]).is_ok());
+
+    for wrong_chain_ids in [
+        vec!["".to_string()],
+        vec!["Cosmos".to_string()],
+        vec!["cosmos1".to_string() + "x".repeat(MAX_COSMOS_CHAIN_ID_LEN).as_str()],
+        vec!["juno".to_string(); MAX_COSMOS_CHAIN_IDS + 1],
+    ] {
+        assert_eq!(
+            check_cosmos_chain_ids(&wrong_chain_ids).unwrap_err(),
+            error!(ErrorCode::InvalidCosmosChainIds)
+        );
+    }
+}
+
+#[test]
+pub fn test_compress() {
+    let secret = libsecp256k1::SecretKey::random(&mut rand::thread_rng());
+    let public_key = libsecp256k1::PublicKey::from_secret_key(&secret);
+    assert!(
+        UncompressedSecp256k1Pubkey::from(public_key.serialize()).compress()
+            == CompressedSecp256k1Pubkey::from(public_key.serialize_compressed())
+    );
+}
+
+#[test]
+pub fn test_legacy_amino_pubkey() {
+    let multisig = LegacyAminoPubKey {
+        threshold: 2,
+        pubkeys:   vec![
+            CompressedSecp256k1Pubkey([2; CompressedSecp256k1Pubkey::LEN]),
+            CompressedSecp256k1Pubkey([3; CompressedSecp256k1Pubkey::LEN]),
+        ],
+    };
+    let mut expected = vec![0x22, 0xc1, 0xf7, 0xe2, 0x08, 0x02];
+    for prefix in [2, 3] {
+        expected.extend_from_slice(&[0x12, 0x26, 0xeb, 0x5a, 0xe9, 0x87, 0x21]);
+        expected.extend_from_slice(&[prefix; CompressedSecp256k1Pubkey::LEN]);
+    }
+    assert_eq!(multisig.to_amino_bytes(), expected);
+
+    let mut varint = vec![];
+    push_uvarint(&mut varint, 300);
+    assert_eq!(varint, vec![0xac, 0x02]);
+
+    assert!(multisig
+        .to_bech32("osmo", &get_default_cosmos_chain_ids())
+        .unwrap()
+        .0
+        .starts_with("osmo1"));
+    assert_eq!(
+        multisig.to_bech32("cosmos", &[]).unwrap_err(),
+        error!(ErrorCode::UnauthorizedCosmosChainId)
+    );
+}
+
+#[test]
+pub fn test_ethermint_bech32() {
+    let secret = libsecp256k1::SecretKey::random(&mut rand::thread_rng());
+    let public_key = UncompressedSecp256k1Pubkey::from(
+        libsecp256k1::PublicKey::from_secret_key(&secret).serialize(),
+    );
+    let mut evm_pubkey = [0u8; EvmPubkey::LEN];
+    evm_pubkey.copy_from_slice(&keccak::hashv(&[&public_key.as_bytes()[1..]]).0[12..]);
+
+    // Same address as the EIP-191 flow of Injective
+    assert_eq!(
+        public_key
+            .into_ethermint_bech32(INJECTIVE_CHAIN_ID, &[])
+            .unwrap()
+            .0,
+        CosmosBech32Address::from(EvmPubkey::from(evm_pubkey)).0
+    );
+    assert!(public_key
+        .into_ethermint_bech32("evmos", &["evmos".to_string()])
+        .unwrap()
+        .0
+        .starts_with("evmos1"));
+    assert_eq!(
+        public_key.into_ethermint_bech32("evmos", &[]).unwrap_err(),
+        error!(ErrorCode::UnauthorizedCosmosChainId)
+    );
+}
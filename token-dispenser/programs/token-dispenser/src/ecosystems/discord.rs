@@ -1,5 +1,8 @@
 #[cfg(test)]
-use super::ed25519::Ed25519TestMessage;
+use super::ed25519::{
+    Ed25519TestClaimantMessage,
+    Ed25519TestMessage,
+};
 use {
     crate::ErrorCode,
     anchor_lang::prelude::*,
@@ -42,16 +45,19 @@ impl DiscordMessage {
 
 #[cfg(test)]
 impl Ed25519TestMessage for DiscordMessage {
+    fn get_message_with_metadata(&self) -> Vec<u8> {
+        self.try_to_vec().unwrap()
+    }
+}
+
+#[cfg(test)]
+impl Ed25519TestClaimantMessage for DiscordMessage {
     fn for_claimant(claimant: &Pubkey) -> Self {
         Self {
             username: claimant.to_string(),
             claimant: *claimant,
         }
     }
-
-    fn get_message_with_metadata(&self) -> Vec<u8> {
-        self.try_to_vec().unwrap()
-    }
 }
 
 
@@ -166,15 +166,42 @@ impl AnchorSerialize for Ed25519InstructionData {
     }
 }
 
+/**
+ * A message (borsh-serialized) signed by the dispenser guard to attest something that can't be
+ * checked on Solana. The serialized message is prefixed with `TAG`, which is unique to each kind
+ * of attestation, so that an attestation can't be parsed as another kind with the same layout.
+ */
+pub trait GuardAttestation: AnchorSerialize + AnchorDeserialize {
+    const TAG: &'static [u8];
+
+    fn parse(data: &[u8]) -> Result<Self> {
+        let data = data
+            .strip_prefix(Self::TAG)
+            .ok_or(ErrorCode::SignatureVerificationWrongPayload)?;
+        Ok(Self::try_from_slice(data)?)
+    }
+}
+
+#[cfg(test)]
+pub trait Ed25519TestMessage {
+    fn get_message_with_metadata(&self) -> Vec<u8>;
+    fn get_message_length(&self) -> usize {
+        self.get_message_with_metadata().len()
+    }
+}
+
 #[cfg(test)]
-pub trait Ed25519TestMessage
+pub trait Ed25519TestClaimantMessage: Ed25519TestMessage
 where
     Self: Sized,
 {
     fn for_claimant(claimant: &Pubkey) -> Self;
-    fn get_message_with_metadata(&self) -> Vec<u8>;
-    fn get_message_length(&self) -> usize {
-        self.get_message_with_metadata().len()
+}
+
+#[cfg(test)]
+impl<T: GuardAttestation> Ed25519TestMessage for T {
+    fn get_message_with_metadata(&self) -> Vec<u8> {
+        [T::TAG, &self.try_to_vec().unwrap()].concat()
     }
 }
 
@@ -1,17 +1,47 @@
 #[cfg(test)]
 use super::secp256k1::Secp256k1TestMessage;
 use {
+    super::{
+        check_not_expired,
+        secp256k1::EvmPubkey,
+    },
     crate::ErrorCode,
     anchor_lang::{
         prelude::*,
+        solana_program::keccak::hashv,
         AnchorDeserialize,
         AnchorSerialize,
     },
-    std::str,
+    std::{
+        iter::Peekable,
+        str,
+    },
 };
 
 pub const EVM_MESSAGE_PREFIX: &str = "\x19Ethereum Signed Message:\n";
 
+/**
+ * EIP-712 typed data. The `Claim` struct binds the claimant and the program id and also carries
+ * the authorization payload as a string, so that wallets still display it. The chain is bound
+ * by the `chainId` of the domain.
+ *
+ * NOTE: Any changes to this must also be made to the corresponding
+ * constant in the typescript sdk
+ * */
+pub const EIP712_PREFIX: [u8; 2] = [0x19, 0x01];
+pub const EIP712_DOMAIN_TYPE: &str = "EIP712Domain(string name,string version,uint256 chainId)";
+pub const EIP712_DOMAIN_NAME: &str = "Token Dispenser";
+pub const EIP712_DOMAIN_VERSION: &str = "1";
+pub const EIP712_CLAIM_TYPE: &str = "Claim(bytes32 claimant,bytes32 programId,string message)";
+
+/**
+ * EIP-4361 (Sign-In with Ethereum) messages start with `${domain}` followed by this suffix.
+ */
+pub const SIWE_HEADER_SUFFIX: &str = " wants you to sign in with your Ethereum account:";
+pub const SIWE_VERSION: &str = "1";
+/// The cluster clock can lag behind the wallet's, so a message issued slightly in the future is accepted
+pub const SIWE_CLOCK_TOLERANCE: i64 = 300;
+
 /**
  * An EIP-191 prefixed signed message.
  * When a browser wallet signs a message, it prepends the payload with a prefix and the length of the payload (encoded as a string).
@@ -24,15 +54,18 @@ pub struct EvmPrefixedMessage(Vec<u8>);
 
 impl EvmPrefixedMessage {
     pub fn parse(data: &[u8]) -> Result<Self> {
-        if data.starts_with(EVM_MESSAGE_PREFIX.as_bytes()) {
-            let length_with_payload_length_prefix =
-                data.len().saturating_sub(EVM_MESSAGE_PREFIX.len());
+        Self::parse_with_prefix(data, EVM_MESSAGE_PREFIX)
+    }
+
+    /// Chains that copied EIP-191, like Tron, only change the prefix
+    pub fn parse_with_prefix(data: &[u8], prefix: &str) -> Result<Self> {
+        if data.starts_with(prefix.as_bytes()) {
+            let length_with_payload_length_prefix = data.len().saturating_sub(prefix.len());
             let length = get_payload_length(length_with_payload_length_prefix)?;
 
-            if data[EVM_MESSAGE_PREFIX.len()..].starts_with(length.to_string().as_bytes()) {
+            if data[prefix.len()..].starts_with(length.to_string().as_bytes()) {
                 return Ok(Self(
-                    data[EVM_MESSAGE_PREFIX.len()
-                        + length_with_payload_length_prefix.saturating_sub(length)..]
+                    data[prefix.len() + length_with_payload_length_prefix.saturating_sub(length)..]
                         .to_vec(),
                 ));
             }
@@ -59,6 +92,307 @@ pub fn get_payload_length(l: usize) -> Result<usize> {
     Ok(l.saturating_sub(number_of_digits))
 }
 
+/**
+ * The message an EVM wallet signs for `eth_signTypedData_v4`, that is
+ * `\x19\x01 || domainSeparator || hashStruct(claim)`. The Secp256k1 program hashes it with
+ * Keccak256 which yields the EIP-712 digest.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Debug)]
+pub struct Eip712Message {
+    domain_separator: [u8; 32],
+    struct_hash:      [u8; 32],
+}
+
+impl Eip712Message {
+    pub fn new(chain_id: u64, claimant: &Pubkey, payload: &str) -> Self {
+        Self {
+            domain_separator: Self::get_domain_separator(chain_id),
+            struct_hash:      Self::get_struct_hash(claimant, payload),
+        }
+    }
+
+    pub fn get_domain_separator(chain_id: u64) -> [u8; 32] {
+        let mut chain_id_bytes = [0u8; 32]; // uint256, big endian
+        chain_id_bytes[24..].copy_from_slice(&chain_id.to_be_bytes());
+        hashv(&[
+            &hashv(&[EIP712_DOMAIN_TYPE.as_bytes()]).0,
+            &hashv(&[EIP712_DOMAIN_NAME.as_bytes()]).0,
+            &hashv(&[EIP712_DOMAIN_VERSION.as_bytes()]).0,
+            &chain_id_bytes,
+        ])
+        .0
+    }
+
+    pub fn get_struct_hash(claimant: &Pubkey, payload: &str) -> [u8; 32] {
+        hashv(&[
+            &hashv(&[EIP712_CLAIM_TYPE.as_bytes()]).0,
+            &claimant.to_bytes(),
+            &crate::ID.to_bytes(),
+            &hashv(&[payload.as_bytes()]).0,
+        ])
+        .0
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        [
+            EIP712_PREFIX.as_slice(),
+            &self.domain_separator,
+            &self.struct_hash,
+        ]
+        .concat()
+    }
+
+    /**
+     * Check that the signed message is the typed data of the claim of `claimant` on `chain_id`.
+     */
+    pub fn check_message(
+        message: &[u8],
+        chain_id: u64,
+        claimant: &Pubkey,
+        expected_payload: &str,
+    ) -> Result<()> {
+        if message != Self::new(chain_id, claimant, expected_payload).to_bytes() {
+            return err!(ErrorCode::SignatureVerificationWrongPayload);
+        }
+        Ok(())
+    }
+}
+
+/**
+ * The fields of an EIP-4361 (Sign-In with Ethereum) message that the program checks.
+ * The statement carries the authorization payload, see `get_siwe_statement`.
+ * The domain, URI, chain id and nonce are only checked to be well formed : the message can't be
+ * replayed since a claim can only happen once.
+ */
+#[derive(Clone, PartialEq, Debug)]
+pub struct SiweMessage {
+    pub address:         String,
+    pub statement:       String,
+    pub issued_at:       i64,
+    pub expiration_time: Option<i64>,
+    pub not_before:      Option<i64>,
+}
+
+impl SiweMessage {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let message = str::from_utf8(data)
+            .map_err(|_| ErrorCode::SignatureVerificationWrongPayloadMetadata)?;
+        let mut lines = message.split('\n').peekable();
+
+        let header = lines.next().unwrap_or_default();
+        require!(
+            header.len() > SIWE_HEADER_SUFFIX.len() && header.ends_with(SIWE_HEADER_SUFFIX),
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+        let address = lines.next().unwrap_or_default().to_string();
+        // The statement is optional in EIP-4361 but it is the authorization here
+        require!(
+            lines.next() == Some(""),
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+        let statement = lines.next().unwrap_or_default().to_string();
+        require!(
+            !statement.is_empty() && lines.next() == Some(""),
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+
+        get_tagged_field(&mut lines, "URI: ")?;
+        require!(
+            get_tagged_field(&mut lines, "Version: ")? == SIWE_VERSION,
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+        get_tagged_field(&mut lines, "Chain ID: ")?
+            .parse::<u64>()
+            .map_err(|_| ErrorCode::SignatureVerificationWrongPayloadMetadata)?;
+        let nonce = get_tagged_field(&mut lines, "Nonce: ")?;
+        require!(
+            nonce.len() >= 8 && nonce.bytes().all(|b| b.is_ascii_alphanumeric()),
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+        let issued_at = parse_rfc3339(get_tagged_field(&mut lines, "Issued At: ")?)?;
+        let expiration_time = get_optional_tagged_field(&mut lines, "Expiration Time: ")
+            .map(parse_rfc3339)
+            .transpose()?;
+        let not_before = get_optional_tagged_field(&mut lines, "Not Before: ")
+            .map(parse_rfc3339)
+            .transpose()?;
+        get_optional_tagged_field(&mut lines, "Request ID: ");
+        if lines.next_if_eq(&"Resources:").is_some() {
+            while get_optional_tagged_field(&mut lines, "- ").is_some() {}
+        }
+        require!(
+            lines.next().is_none(),
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+
+        Ok(Self {
+            address,
+            statement,
+            issued_at,
+            expiration_time,
+            not_before,
+        })
+    }
+
+    /**
+     * Check that the message was signed by `pubkey` to authorize `expected_payload` and is valid at `now`.
+     */
+    pub fn check(&self, pubkey: &EvmPubkey, expected_payload: &str, now: i64) -> Result<()> {
+        require!(
+            self.address == get_checksum_address(pubkey),
+            ErrorCode::SignatureVerificationWrongSigner
+        );
+        require!(
+            self.statement == get_siwe_statement(expected_payload),
+            ErrorCode::SignatureVerificationWrongPayload
+        );
+        require!(
+            self.issued_at <= now.saturating_add(SIWE_CLOCK_TOLERANCE)
+                && !matches!(self.not_before, Some(not_before) if not_before > now),
+            ErrorCode::AuthorizationNotYetValid
+        );
+        if let Some(expiration_time) = self.expiration_time {
+            check_not_expired(expiration_time, now)?;
+        }
+        Ok(())
+    }
+}
+
+fn get_tagged_field<'a>(lines: &mut Peekable<str::Split<'a, char>>, tag: &str) -> Result<&'a str> {
+    get_optional_tagged_field(lines, tag)
+        .ok_or_else(|| error!(ErrorCode::SignatureVerificationWrongPayloadMetadata))
+}
+
+fn get_optional_tagged_field<'a>(
+    lines: &mut Peekable<str::Split<'a, char>>,
+    tag: &str,
+) -> Option<&'a str> {
+    lines
+        .next_if(|line| line.starts_with(tag))
+        .map(|line| &line[tag.len()..])
+}
+
+/**
+ * SIWE statements are a single line, so the lines of the authorization payload are joined with spaces.
+ */
+pub fn get_siwe_statement(expected_payload: &str) -> String {
+    expected_payload
+        .split('\n')
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/**
+ * The EIP-55 mixed-case checksum encoding of an EVM address, which EIP-4361 messages use.
+ */
+pub fn get_checksum_address(pubkey: &EvmPubkey) -> String {
+    let address: String = pubkey
+        .as_bytes()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    let hash = hashv(&[address.as_bytes()]).0;
+    let checksummed: String = address
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let nibble = if i % 2 == 0 {
+                hash[i / 2] >> 4
+            } else {
+                hash[i / 2] & 0x0f
+            };
+            if nibble >= 8 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect();
+    "0x".to_string() + &checksummed
+}
+
+/**
+ * Parse an RFC 3339 date-time, like `2023-11-01T16:25:24.000Z`, into a unix timestamp.
+ * Fractions of seconds are truncated.
+ */
+pub fn parse_rfc3339(date_time: &str) -> Result<i64> {
+    let bytes = date_time.as_bytes();
+    require!(
+        bytes.len() >= 20
+            && bytes[4] == b'-'
+            && bytes[7] == b'-'
+            && (bytes[10] == b'T' || bytes[10] == b't')
+            && bytes[13] == b':'
+            && bytes[16] == b':',
+        ErrorCode::SignatureVerificationWrongPayloadMetadata
+    );
+    let year = parse_digits(&bytes[0..4])?;
+    let month = parse_digits(&bytes[5..7])?;
+    let day = parse_digits(&bytes[8..10])?;
+    let hour = parse_digits(&bytes[11..13])?;
+    let minute = parse_digits(&bytes[14..16])?;
+    let second = parse_digits(&bytes[17..19])?;
+    require!(
+        (1..=12).contains(&month)
+            && (1..=31).contains(&day)
+            && hour < 24
+            && minute < 60
+            && second <= 60, // leap second
+        ErrorCode::SignatureVerificationWrongPayloadMetadata
+    );
+
+    // Work on bytes since slicing the `str` in the middle of a multi-byte character would panic
+    let mut offset = &bytes[19..];
+    if let Some(fraction) = offset.strip_prefix(b".") {
+        let digits = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+        require!(
+            digits > 0,
+            ErrorCode::SignatureVerificationWrongPayloadMetadata
+        );
+        offset = &fraction[digits..];
+    }
+    let offset_seconds = match offset {
+        [b'Z'] | [b'z'] => 0,
+        [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let offset_seconds =
+                parse_digits(&offset[1..3])? * 3600 + parse_digits(&offset[4..6])? * 60;
+            if *sign == b'+' {
+                offset_seconds
+            } else {
+                -offset_seconds
+            }
+        }
+        _ => return err!(ErrorCode::SignatureVerificationWrongPayloadMetadata),
+    };
+
+    Ok(
+        get_days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second
+            - offset_seconds,
+    )
+}
+
+fn parse_digits(digits: &[u8]) -> Result<i64> {
+    require!(
+        !digits.is_empty() && digits.iter().all(u8::is_ascii_digit),
+        ErrorCode::SignatureVerificationWrongPayloadMetadata
+    );
+    Ok(digits
+        .iter()
+        .fold(0, |number, digit| number * 10 + i64::from(digit - b'0')))
+}
+
+/// Days since 1970-01-01 of a date of the proleptic Gregorian calendar
+fn get_days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1; // the year starts in March
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
 #[cfg(test)]
 impl From<&str> for EvmPrefixedMessage {
     fn from(string: &str) -> Self {
@@ -74,3 +408,171 @@ impl Secp256k1TestMessage for EvmPrefixedMessage {
         prefixed_message
     }
 }
+
+#[cfg(test)]
+impl Secp256k1TestMessage for Eip712Message {
+    fn get_message_with_metadata(&self) -> Vec<u8> {
+        self.to_bytes()
+    }
+}
+
+#[test]
+pub fn test_eip712_message() {
+    // keccak256("EIP712Domain(string name,string version,uint256 chainId)")
+    assert_eq!(
+        hex::encode(hashv(&[EIP712_DOMAIN_TYPE.as_bytes()]).0),
+        "c2f8787176b8ac6bf7215b4adcc1e069bf4ab82d9ab1df05a57a91d425935b6e"
+    );
+
+    let claimant = Pubkey::new_unique();
+    let message = Eip712Message::new(1, &claimant, "payload");
+    let message_bytes = message.to_bytes();
+    assert_eq!(message_bytes.len(), 2 + 32 + 32);
+    assert_eq!(message_bytes[..2], EIP712_PREFIX);
+    assert!(Eip712Message::check_message(&message_bytes, 1, &claimant, "payload").is_ok());
+
+    // wrong chain, claimant or payload
+    for (chain_id, claimant, payload) in [
+        (10, claimant, "payload"),
+        (1, Pubkey::new_unique(), "payload"),
+        (1, claimant, "other payload"),
+    ] {
+        assert_eq!(
+            Eip712Message::check_message(&message_bytes, chain_id, &claimant, payload).unwrap_err(),
+            Error::from(ErrorCode::SignatureVerificationWrongPayload)
+        );
+    }
+}
+
+#[test]
+pub fn test_parse_rfc3339() {
+    assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z").unwrap(), 0);
+    assert_eq!(parse_rfc3339("2023-01-01T00:00:00Z").unwrap(), 1672531200);
+    assert_eq!(
+        parse_rfc3339("2024-02-29T12:30:15.123Z").unwrap(),
+        1709209815
+    );
+    assert_eq!(
+        parse_rfc3339("2024-02-29T14:30:15+02:00").unwrap(),
+        1709209815
+    );
+    assert_eq!(
+        parse_rfc3339("2024-02-29T07:30:15-05:00").unwrap(),
+        1709209815
+    );
+
+    for wrong_date_time in [
+        "2023-01-01",
+        "2023-01-01 00:00:00Z",
+        "2023-13-01T00:00:00Z",
+        "2023-01-01T00:00:00",
+        "2023-01-01T00:00:00.Z",
+        "2023-01-01T00:00:00+0200",
+        "2023-01-+1T00:00:00Z",
+        // non-ASCII characters, the first one straddles the end of the seconds
+        "2023-01-01T00:00:0éZ",
+        "２０２３-01-01T00:00:00Z",
+    ] {
+        assert_eq!(
+            parse_rfc3339(wrong_date_time).unwrap_err(),
+            Error::from(ErrorCode::SignatureVerificationWrongPayloadMetadata)
+        );
+    }
+}
+
+#[test]
+pub fn test_checksum_address() {
+    // from EIP-55
+    let mut address = [0u8; EvmPubkey::LEN];
+    address.copy_from_slice(&hex::decode("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap());
+    assert_eq!(
+        get_checksum_address(&address.into()),
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+    );
+}
+
+#[test]
+pub fn test_siwe_message() {
+    let mut address = [0u8; EvmPubkey::LEN];
+    address.copy_from_slice(&hex::decode("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed").unwrap());
+    let pubkey = EvmPubkey::from(address);
+    let expected_payload = "Airdrop PID:\nprogram\nI authorize Solana wallet\nclaimant\n";
+    let message = "example.com wants you to sign in with your Ethereum account:\n\
+        0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed\n\n\
+        Airdrop PID: program I authorize Solana wallet claimant\n\n\
+        URI: https://example.com/login\n\
+        Version: 1\n\
+        Chain ID: 1\n\
+        Nonce: 32891756abcd\n\
+        Issued At: 2023-01-01T00:00:00Z\n\
+        Expiration Time: 2023-01-02T00:00:00Z\n\
+        Resources:\n\
+        - https://example.com/terms";
+
+    let siwe_message = SiweMessage::parse(message.as_bytes()).unwrap();
+    assert_eq!(
+        siwe_message,
+        SiweMessage {
+            address:         "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            statement:       "Airdrop PID: program I authorize Solana wallet claimant".to_string(),
+            issued_at:       1672531200,
+            expiration_time: Some(1672617600),
+            not_before:      None,
+        }
+    );
+    assert!(siwe_message
+        .check(&pubkey, expected_payload, 1672531200)
+        .is_ok());
+
+    // wrong signer, wrong statement, not valid yet, expired
+    assert_eq!(
+        siwe_message
+            .check(
+                &EvmPubkey::from([0u8; EvmPubkey::LEN]),
+                expected_payload,
+                1672531200
+            )
+            .unwrap_err(),
+        Error::from(ErrorCode::SignatureVerificationWrongSigner)
+    );
+    assert_eq!(
+        siwe_message
+            .check(&pubkey, "Airdrop PID:\nprogram\n", 1672531200)
+            .unwrap_err(),
+        Error::from(ErrorCode::SignatureVerificationWrongPayload)
+    );
+    assert_eq!(
+        siwe_message
+            .check(
+                &pubkey,
+                expected_payload,
+                1672531200 - SIWE_CLOCK_TOLERANCE - 1
+            )
+            .unwrap_err(),
+        Error::from(ErrorCode::AuthorizationNotYetValid)
+    );
+    assert_eq!(
+        siwe_message
+            .check(&pubkey, expected_payload, 1672617601)
+            .unwrap_err(),
+        Error::from(ErrorCode::AuthorizationExpired)
+    );
+
+    // missing statement, version or nonce, trailing lines
+    for wrong_message in [
+        message.replace(
+            "Airdrop PID: program I authorize Solana wallet claimant\n\n",
+            "",
+        ),
+        message.replace("Version: 1\n", ""),
+        message.replace("Version: 1", "Version: 2"),
+        message.replace("Nonce: 32891756abcd", "Nonce: 123"),
+        message.to_string() + "\nunknown",
+        message.replace(" wants you to sign in", " wants you to log in"),
+    ] {
+        assert_eq!(
+            SiweMessage::parse(wrong_message.as_bytes()).unwrap_err(),
+            Error::from(ErrorCode::SignatureVerificationWrongPayloadMetadata)
+        );
+    }
+}
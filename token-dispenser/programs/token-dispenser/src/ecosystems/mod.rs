@@ -1,72 +1,235 @@
 use {
-    crate::ErrorCode,
-    anchor_lang::prelude::{
-        Pubkey,
-        *,
+    crate::{
+        Config,
+        ErrorCode,
+    },
+    anchor_lang::{
+        prelude::{
+            Pubkey,
+            *,
+        },
+        solana_program::hash::Hash,
     },
 };
 
 pub mod algorand;
 pub mod aptos;
+pub mod bitcoin;
+pub mod cardano;
 pub mod cosmos;
 pub mod discord;
 pub mod ed25519;
 pub mod evm;
+pub mod near;
 pub mod secp256k1;
+pub mod secp256r1;
+pub mod substrate;
 pub mod sui;
+pub mod ton;
+pub mod tron;
 
 /**
- * Ecosystem agnostic authorization payload that the identity on the leaf needs to sign.
+ * Ecosystem agnostic authorization payload that the identity on the leaf needs to sign, with
+ * `PROGRAM_ID_PLACEHOLDER` and `CLAIMANT_PLACEHOLDER` standing in for the program id and the
+ * claimant. Each campaign stores its own template in `Config`, this is the one of the W airdrop.
  *
  * NOTE: Any changes to this must also be made to the corresponding
  * constant in the typescript sdk
  * */
-pub const AUTHORIZATION_PAYLOAD: [&str; 3] = [
-    "W Airdrop PID:\n",
-    "\nI authorize Solana wallet\n",
-    "\nto claim my W tokens.\n",
+#[cfg(test)]
+pub const DEFAULT_AUTHORIZATION_TEMPLATE: &str =
+    "W Airdrop PID:\n{program_id}\nI authorize Solana wallet\n{claimant}\nto claim my W tokens.\n";
+pub const PROGRAM_ID_PLACEHOLDER: &str = "{program_id}";
+pub const CLAIMANT_PLACEHOLDER: &str = "{claimant}";
+pub const MAX_AUTHORIZATION_TEMPLATE_LEN: usize = 256;
+
+/**
+ * Version 2 of the authorization payload is built from the same template. On top of the
+ * claimant, it commits to the amount being claimed, the cluster through its genesis hash, an
+ * expiry timestamp after which the signature can't be used anymore, and a nonce picked by the
+ * frontend so that every authorization is a distinct message. A campaign can only accept it if
+ * its template has all of these placeholders.
+ */
+pub const AMOUNT_PLACEHOLDER: &str = "{amount}";
+pub const GENESIS_HASH_PLACEHOLDER: &str = "{genesis_hash}";
+pub const EXPIRY_PLACEHOLDER: &str = "{expiry}";
+pub const NONCE_PLACEHOLDER: &str = "{nonce}";
+pub const V2_PLACEHOLDERS: [&str; 4] = [
+    AMOUNT_PLACEHOLDER,
+    GENESIS_HASH_PLACEHOLDER,
+    EXPIRY_PLACEHOLDER,
+    NONCE_PLACEHOLDER,
 ];
 
+/**
+ * The W airdrop template with the placeholders of the version 2 of the authorization payload.
+ *
+ * NOTE: Any changes to this must also be made to the corresponding
+ * constant in the typescript sdk
+ * */
+#[cfg(test)]
+pub const DEFAULT_AUTHORIZATION_TEMPLATE_V2: &str = concat!(
+    "W Airdrop PID:\n{program_id}\nI authorize Solana wallet\n{claimant}\n",
+    "to claim {amount} base units of W tokens.\n",
+    "Cluster genesis hash:\n{genesis_hash}\n",
+    "Expires at (unix timestamp):\n{expiry}\n",
+    "Nonce:\n{nonce}\n"
+);
+
+/**
+ * The version of the authorization payload that the identity signed, along with the parameters
+ * of the payload that can't be derived on-chain.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug)]
+pub enum AuthorizationPayload {
+    V1,
+    V2 { expiry: i64, nonce: u64 },
+}
+
+impl AuthorizationPayload {
+    pub const LATEST_VERSION: u8 = 2;
+
+    pub fn version(&self) -> u8 {
+        match self {
+            AuthorizationPayload::V1 => 1,
+            AuthorizationPayload::V2 { .. } => 2,
+        }
+    }
+
+    /**
+     * Get the payload the identity needs to have signed, after checking that its version is
+     * accepted by the campaign and that it hasn't expired.
+     */
+    pub fn checked_get_expected_payload(
+        &self,
+        claimant: &Pubkey,
+        amount: u64,
+        config: &Config,
+    ) -> Result<String> {
+        require!(
+            self.version() >= config.min_payload_version,
+            ErrorCode::UnsupportedAuthorizationPayloadVersion
+        );
+        match self {
+            AuthorizationPayload::V1 => Ok(get_expected_payload(
+                &config.authorization_template,
+                claimant,
+            )),
+            AuthorizationPayload::V2 { expiry, nonce } => {
+                require!(
+                    supports_payload_v2(&config.authorization_template),
+                    ErrorCode::UnsupportedAuthorizationPayloadVersion
+                );
+                check_not_expired(*expiry, Clock::get()?.unix_timestamp)?;
+                Ok(get_expected_payload_v2(
+                    &config.authorization_template,
+                    claimant,
+                    amount,
+                    &config.genesis_hash,
+                    *expiry,
+                    *nonce,
+                ))
+            }
+        }
+    }
+}
+
+/**
+ * Check that the minimum version of the authorization payload of a campaign is one that can be
+ * signed, otherwise every claim would fail.
+ */
+pub fn check_min_payload_version(version: u8) -> Result<()> {
+    require!(
+        version <= AuthorizationPayload::LATEST_VERSION,
+        ErrorCode::UnsupportedAuthorizationPayloadVersion
+    );
+    Ok(())
+}
+
+pub fn check_not_expired(expiry: i64, now: i64) -> Result<()> {
+    require!(now <= expiry, ErrorCode::AuthorizationExpired);
+    Ok(())
+}
+
 /**
  * Check a payload matches the expected authorization payload.
  */
-pub fn check_payload(payload: &[u8], claimant: &Pubkey) -> Result<()> {
-    if payload != get_expected_payload(claimant).as_bytes() {
+pub fn check_payload(payload: &[u8], expected_payload: &str) -> Result<()> {
+    if payload != expected_payload.as_bytes() {
         return err!(ErrorCode::SignatureVerificationWrongPayload);
     }
     Ok(())
 }
 
 /**
- * Get the expected authorization payload given the claimant authorized to receive the claim.
+ * Check that a template fits in `Config` and commits to both the program id and the claimant,
+ * otherwise a signature could be replayed by another program or another claimant.
+ * Campaigns that reject version 1 also need the placeholders of version 2.
  */
-pub fn get_expected_payload(claimant: &Pubkey) -> String {
-    AUTHORIZATION_PAYLOAD[0].to_string()
-        + &crate::ID.to_string()
-        + AUTHORIZATION_PAYLOAD[1]
-        + claimant.to_string().as_str()
-        + AUTHORIZATION_PAYLOAD[2]
+pub fn check_authorization_template(template: &str, min_payload_version: u8) -> Result<()> {
+    require!(
+        template.len() <= MAX_AUTHORIZATION_TEMPLATE_LEN
+            && template.contains(PROGRAM_ID_PLACEHOLDER)
+            && template.contains(CLAIMANT_PLACEHOLDER)
+            && (min_payload_version < 2 || supports_payload_v2(template)),
+        ErrorCode::InvalidAuthorizationTemplate
+    );
+    Ok(())
+}
+
+pub fn supports_payload_v2(template: &str) -> bool {
+    V2_PLACEHOLDERS
+        .iter()
+        .all(|placeholder| template.contains(placeholder))
+}
+
+/**
+ * Get the expected authorization payload given the template of the campaign and the claimant
+ * authorized to receive the claim.
+ */
+pub fn get_expected_payload(template: &str, claimant: &Pubkey) -> String {
+    template
+        .replace(PROGRAM_ID_PLACEHOLDER, &crate::ID.to_string())
+        .replace(CLAIMANT_PLACEHOLDER, &claimant.to_string())
+}
+
+/**
+ * Get the expected version 2 authorization payload given the template of the campaign.
+ */
+pub fn get_expected_payload_v2(
+    template: &str,
+    claimant: &Pubkey,
+    amount: u64,
+    genesis_hash: &[u8; 32],
+    expiry: i64,
+    nonce: u64,
+) -> String {
+    get_expected_payload(template, claimant)
+        .replace(AMOUNT_PLACEHOLDER, &amount.to_string())
+        .replace(
+            GENESIS_HASH_PLACEHOLDER,
+            &Hash::new_from_array(*genesis_hash).to_string(),
+        )
+        .replace(EXPIRY_PLACEHOLDER, &expiry.to_string())
+        .replace(NONCE_PLACEHOLDER, &nonce.to_string())
 }
 
 #[test]
 pub fn test_check_payload() {
     let claimant = Pubkey::new_unique();
-    let payload = AUTHORIZATION_PAYLOAD[0].to_string()
+    let payload = "W Airdrop PID:\n".to_string()
         + &crate::ID.to_string()
-        + AUTHORIZATION_PAYLOAD[1]
+        + "\nI authorize Solana wallet\n"
         + claimant.to_string().as_str()
-        + AUTHORIZATION_PAYLOAD[2];
+        + "\nto claim my W tokens.\n";
+    let expected_payload = get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, &claimant);
 
-    assert!(check_payload(payload.as_bytes(), &claimant).is_ok());
+    assert!(check_payload(payload.as_bytes(), &expected_payload).is_ok());
 
     // incorrect claimant
-    let wrong_payload = AUTHORIZATION_PAYLOAD[0].to_string()
-        + &crate::ID.to_string()
-        + AUTHORIZATION_PAYLOAD[1]
-        + &(Pubkey::new_unique()).to_string()
-        + AUTHORIZATION_PAYLOAD[2];
+    let wrong_payload = get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, &Pubkey::new_unique());
 
-    let res = check_payload(wrong_payload.as_bytes(), &claimant);
+    let res = check_payload(wrong_payload.as_bytes(), &expected_payload);
     assert!(res.is_err());
     assert_eq!(
         res.unwrap_err(),
@@ -74,13 +237,9 @@ pub fn test_check_payload() {
     );
 
     // incorrect program id
-    let wrong_payload = AUTHORIZATION_PAYLOAD[0].to_string()
-        + &(Pubkey::new_unique()).to_string()
-        + AUTHORIZATION_PAYLOAD[1]
-        + &claimant.to_string()
-        + AUTHORIZATION_PAYLOAD[2];
+    let wrong_payload = payload.replace(&crate::ID.to_string(), &Pubkey::new_unique().to_string());
 
-    let res = check_payload(wrong_payload.as_bytes(), &claimant);
+    let res = check_payload(wrong_payload.as_bytes(), &expected_payload);
     assert!(res.is_err());
     assert_eq!(
         res.unwrap_err(),
@@ -88,16 +247,172 @@ pub fn test_check_payload() {
     );
 
     // incorrect constants
-    let wrong_payload = "Grant PID:\n".to_string()
-        + &crate::ID.to_string()
-        + AUTHORIZATION_PAYLOAD[1]
-        + &claimant.to_string()
-        + AUTHORIZATION_PAYLOAD[2];
+    let wrong_payload = payload.replace("W Airdrop PID", "Grant PID");
 
-    let res = check_payload(wrong_payload.as_bytes(), &claimant);
+    let res = check_payload(wrong_payload.as_bytes(), &expected_payload);
     assert!(res.is_err());
     assert_eq!(
         res.unwrap_err(),
         Error::from(ErrorCode::SignatureVerificationWrongPayload)
     );
+
+    // payload of another campaign
+    let other_template = "Acme Airdrop\n{program_id}\n{claimant}\n";
+    let wrong_payload = get_expected_payload(other_template, &claimant);
+    assert_eq!(
+        wrong_payload,
+        "Acme Airdrop\n".to_string() + &crate::ID.to_string() + "\n" + &claimant.to_string() + "\n"
+    );
+
+    let res = check_payload(wrong_payload.as_bytes(), &expected_payload);
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err(),
+        Error::from(ErrorCode::SignatureVerificationWrongPayload)
+    );
+}
+
+#[test]
+pub fn test_check_authorization_template() {
+    assert!(check_authorization_template(DEFAULT_AUTHORIZATION_TEMPLATE, 1).is_ok());
+    assert!(check_authorization_template(DEFAULT_AUTHORIZATION_TEMPLATE_V2, 1).is_ok());
+    assert!(check_authorization_template(DEFAULT_AUTHORIZATION_TEMPLATE_V2, 2).is_ok());
+    assert!(check_authorization_template("{claimant}{program_id}", 1).is_ok());
+
+    for wrong_template in [
+        "I authorize Solana wallet\n{claimant}\n".to_string(),
+        "PID:\n{program_id}\n".to_string(),
+        "{program_id}{claimant}".to_string() + &"a".repeat(MAX_AUTHORIZATION_TEMPLATE_LEN),
+    ] {
+        assert_eq!(
+            check_authorization_template(&wrong_template, 1).unwrap_err(),
+            Error::from(ErrorCode::InvalidAuthorizationTemplate)
+        );
+    }
+
+    // version 2 needs all of its placeholders
+    assert_eq!(
+        check_authorization_template(DEFAULT_AUTHORIZATION_TEMPLATE, 2).unwrap_err(),
+        Error::from(ErrorCode::InvalidAuthorizationTemplate)
+    );
+    for placeholder in V2_PLACEHOLDERS {
+        assert!(!supports_payload_v2(
+            &DEFAULT_AUTHORIZATION_TEMPLATE_V2.replace(placeholder, "")
+        ));
+    }
+}
+
+#[test]
+pub fn test_check_payload_v2() {
+    let claimant = Pubkey::new_unique();
+    let genesis_hash = [1u8; 32];
+    let payload = get_expected_payload_v2(
+        DEFAULT_AUTHORIZATION_TEMPLATE_V2,
+        &claimant,
+        1000,
+        &genesis_hash,
+        1_700_000_000,
+        42,
+    );
+    assert_eq!(
+        payload,
+        "W Airdrop PID:\n".to_string()
+            + &crate::ID.to_string()
+            + "\nI authorize Solana wallet\n"
+            + &claimant.to_string()
+            + "\nto claim 1000 base units of W tokens.\nCluster genesis hash:\n"
+            + "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi"
+            + "\nExpires at (unix timestamp):\n1700000000\nNonce:\n42\n"
+    );
+    assert!(check_payload(payload.as_bytes(), &payload).is_ok());
+
+    // the v1 payload doesn't match
+    assert_eq!(
+        check_payload(
+            get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, &claimant).as_bytes(),
+            &payload
+        )
+        .unwrap_err(),
+        Error::from(ErrorCode::SignatureVerificationWrongPayload)
+    );
+
+    // a payload of another campaign with the same parameters doesn't match
+    assert_eq!(
+        check_payload(
+            get_expected_payload_v2(
+                &DEFAULT_AUTHORIZATION_TEMPLATE_V2.replace("W tokens", "Acme tokens"),
+                &claimant,
+                1000,
+                &genesis_hash,
+                1_700_000_000,
+                42
+            )
+            .as_bytes(),
+            &payload
+        )
+        .unwrap_err(),
+        Error::from(ErrorCode::SignatureVerificationWrongPayload)
+    );
+
+    // incorrect amount, cluster, expiry or nonce
+    for wrong_payload in [
+        get_expected_payload_v2(
+            DEFAULT_AUTHORIZATION_TEMPLATE_V2,
+            &claimant,
+            1001,
+            &genesis_hash,
+            1_700_000_000,
+            42,
+        ),
+        get_expected_payload_v2(
+            DEFAULT_AUTHORIZATION_TEMPLATE_V2,
+            &claimant,
+            1000,
+            &[2u8; 32],
+            1_700_000_000,
+            42,
+        ),
+        get_expected_payload_v2(
+            DEFAULT_AUTHORIZATION_TEMPLATE_V2,
+            &claimant,
+            1000,
+            &genesis_hash,
+            1_700_000_001,
+            42,
+        ),
+        get_expected_payload_v2(
+            DEFAULT_AUTHORIZATION_TEMPLATE_V2,
+            &claimant,
+            1000,
+            &genesis_hash,
+            1_700_000_000,
+            43,
+        ),
+    ] {
+        assert_eq!(
+            check_payload(wrong_payload.as_bytes(), &payload).unwrap_err(),
+            Error::from(ErrorCode::SignatureVerificationWrongPayload)
+        );
+    }
+}
+
+#[test]
+pub fn test_check_min_payload_version() {
+    assert!(check_min_payload_version(0).is_ok());
+    assert!(check_min_payload_version(1).is_ok());
+    assert!(check_min_payload_version(AuthorizationPayload::LATEST_VERSION).is_ok());
+    assert_eq!(
+        check_min_payload_version(AuthorizationPayload::LATEST_VERSION + 1).unwrap_err(),
+        Error::from(ErrorCode::UnsupportedAuthorizationPayloadVersion)
+    );
+}
+
+#[test]
+pub fn test_check_not_expired() {
+    assert!(check_not_expired(100, 99).is_ok());
+    assert!(check_not_expired(100, 100).is_ok());
+    assert_eq!(
+        check_not_expired(100, 101).unwrap_err(),
+        Error::from(ErrorCode::AuthorizationExpired)
+    );
 }
@@ -0,0 +1,208 @@
+#[cfg(test)]
+use super::{
+    ed25519::{
+        Ed25519TestClaimantMessage,
+        Ed25519TestMessage,
+    },
+    get_expected_payload,
+    DEFAULT_AUTHORIZATION_TEMPLATE,
+};
+use {
+    super::ed25519::{
+        Ed25519Pubkey,
+        GuardAttestation,
+    },
+    crate::ErrorCode,
+    anchor_lang::{
+        prelude::*,
+        solana_program::hash,
+        AnchorDeserialize,
+        AnchorSerialize,
+    },
+};
+
+/// NEP-413 prefixes the payload with this tag, 2^31 + 413, so that it can't be a transaction
+pub const NEAR_NEP413_TAG: u32 = (1 << 31) + 413;
+
+/**
+ * The parameters of a NEP-413 `signMessage` request, other than the message itself which is the
+ * authorization payload.
+ * The wallet signs the SHA256 hash of the tag followed by the borsh-serialized `Nep413Payload`,
+ * so like for Sui we check that the signed hash matches the hash of the expected payload.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
+pub struct NearMessage {
+    pub nonce:        [u8; 32],
+    pub recipient:    String,
+    pub callback_url: Option<String>,
+}
+
+#[derive(AnchorSerialize)]
+struct Nep413Payload<'a> {
+    message:      &'a str,
+    nonce:        &'a [u8; 32],
+    recipient:    &'a str,
+    callback_url: &'a Option<String>,
+}
+
+impl NearMessage {
+    pub fn get_expected_hash(&self, payload: &str) -> [u8; 32] {
+        let nep413_payload = Nep413Payload {
+            message:      payload,
+            nonce:        &self.nonce,
+            recipient:    &self.recipient,
+            callback_url: &self.callback_url,
+        };
+        hash::hashv(&[
+            &NEAR_NEP413_TAG.to_le_bytes(),
+            &nep413_payload.try_to_vec().unwrap(),
+        ])
+        .to_bytes()
+    }
+
+    pub fn check_hashed_payload(
+        &self,
+        hashed_payload: &[u8],
+        expected_payload: &str,
+    ) -> Result<()> {
+        if hashed_payload != self.get_expected_hash(expected_payload) {
+            return err!(ErrorCode::SignatureVerificationWrongPayload);
+        }
+        Ok(())
+    }
+}
+
+/**
+ * A NEAR account id. Implicit accounts are the lowercase hex encoding of their Ed25519 public key,
+ * named accounts (like `alice.near`) need the dispenser guard to link them to a key.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Debug)]
+pub struct NearAccountId(String);
+
+impl From<Ed25519Pubkey> for NearAccountId {
+    fn from(val: Ed25519Pubkey) -> Self {
+        NearAccountId(
+            val.to_bytes()
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+impl From<&str> for NearAccountId {
+    fn from(account_id: &str) -> Self {
+        NearAccountId(account_id.to_string())
+    }
+}
+
+/**
+ * This message (borsh-serialized) needs to be signed by the dispenser guard after
+ * verifying on-chain that `pubkey` is a full access key of the named account `account_id`.
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct NearKeyLinkMessage {
+    account_id: NearAccountId,
+    pubkey:     Ed25519Pubkey,
+}
+
+impl NearKeyLinkMessage {
+    pub fn parse_and_check_account_id_and_pubkey(
+        data: &[u8],
+        account_id: &NearAccountId,
+        pubkey: &Ed25519Pubkey,
+    ) -> Result<Self> {
+        let result = NearKeyLinkMessage::parse(data)?;
+
+        if result.account_id != *account_id || result.pubkey != *pubkey {
+            return err!(ErrorCode::SignatureVerificationWrongPayload);
+        }
+
+        Ok(result)
+    }
+}
+
+impl GuardAttestation for NearKeyLinkMessage {
+    const TAG: &'static [u8] = b"near-key-link-v1";
+}
+
+#[cfg(test)]
+impl NearKeyLinkMessage {
+    pub fn new(account_id: NearAccountId, pubkey: Ed25519Pubkey) -> Self {
+        Self { account_id, pubkey }
+    }
+}
+
+/**
+ * A NEP-413 message along with the payload that the wallet was asked to sign.
+ */
+#[cfg(test)]
+#[derive(Clone)]
+pub struct NearTestMessage {
+    pub payload: String,
+    pub message: NearMessage,
+}
+
+#[cfg(test)]
+impl Ed25519TestMessage for NearTestMessage {
+    fn get_message_with_metadata(&self) -> Vec<u8> {
+        self.message.get_expected_hash(&self.payload).to_vec()
+    }
+}
+
+#[cfg(test)]
+impl Ed25519TestClaimantMessage for NearTestMessage {
+    fn for_claimant(claimant: &Pubkey) -> Self {
+        Self {
+            payload: get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant),
+            message: NearMessage {
+                nonce:        rand::random(),
+                recipient:    "wormhole.near".to_string(),
+                callback_url: None,
+            },
+        }
+    }
+}
+
+#[test]
+fn test_nep413_payload() {
+    let message = NearMessage {
+        nonce:        [1; 32],
+        recipient:    "alice.near".to_string(),
+        callback_url: Some("https://example.com".to_string()),
+    };
+
+    let mut expected_preimage = vec![];
+    expected_preimage.extend_from_slice(&[0x9d, 0x01, 0x00, 0x80]);
+    expected_preimage.extend_from_slice(&[5, 0, 0, 0]);
+    expected_preimage.extend_from_slice(b"hello");
+    expected_preimage.extend_from_slice(&[1; 32]);
+    expected_preimage.extend_from_slice(&[10, 0, 0, 0]);
+    expected_preimage.extend_from_slice(b"alice.near");
+    expected_preimage.extend_from_slice(&[1, 19, 0, 0, 0]);
+    expected_preimage.extend_from_slice(b"https://example.com");
+    assert_eq!(
+        message.get_expected_hash("hello"),
+        hash::hashv(&[&expected_preimage]).to_bytes()
+    );
+
+    assert!(message
+        .check_hashed_payload(&message.get_expected_hash("hello"), "hello")
+        .is_ok());
+    assert_eq!(
+        message.check_hashed_payload(&message.get_expected_hash("hello"), "world"),
+        err!(ErrorCode::SignatureVerificationWrongPayload)
+    );
+}
+
+#[test]
+fn test_implicit_account_id() {
+    let mut pubkey = [0u8; Ed25519Pubkey::LEN];
+    pubkey[0] = 0xab;
+    pubkey[31] = 0x01;
+    assert_eq!(
+        NearAccountId::from(Ed25519Pubkey::from(pubkey)),
+        NearAccountId::from("ab00000000000000000000000000000000000000000000000000000000000001")
+    );
+}
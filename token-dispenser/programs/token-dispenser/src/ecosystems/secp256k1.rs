@@ -1,5 +1,8 @@
 use {
-    super::cosmos::UncompressedSecp256k1Pubkey,
+    super::cosmos::{
+        CompressedSecp256k1Pubkey,
+        UncompressedSecp256k1Pubkey,
+    },
     crate::ErrorCode,
     anchor_lang::{
         prelude::*,
@@ -174,20 +177,42 @@ impl AnchorSerialize for Secp256k1InstructionData {
  * us from using the Secp256k1 instruction struct for Cosmos.
  */
 pub fn secp256k1_verify_signer(
+    signature: &Secp256k1Signature,
+    recovery_id: &u8,
+    pubkey: &CompressedSecp256k1Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    if secp256k1_recover_signer(signature, recovery_id, message)?.compress() != *pubkey {
+        return err!(ErrorCode::SignatureVerificationWrongSigner);
+    }
+    Ok(())
+}
+
+/// Same as `secp256k1_verify_signer` for signers that need the full public key, like Ethermint
+/// accounts whose address is derived from both coordinates
+pub fn secp256k1_verify_uncompressed_signer(
     signature: &Secp256k1Signature,
     recovery_id: &u8,
     pubkey: &UncompressedSecp256k1Pubkey,
     message: &[u8],
 ) -> Result<()> {
-    let recovered_key = secp256k1_recover(message, *recovery_id, &signature.0)
-        .map_err(|_| ErrorCode::SignatureVerificationWrongSigner)?;
-    if !(recovered_key.0 == pubkey.as_bytes()[1..] && pubkey.as_bytes()[0] == SECP256K1_FULL_PREFIX)
-    {
+    if secp256k1_recover_signer(signature, recovery_id, message)? != *pubkey {
         return err!(ErrorCode::SignatureVerificationWrongSigner);
     }
     Ok(())
 }
 
+/// Recovers the full public key of the signer, for ecosystems that derive the address from it
+pub fn secp256k1_recover_signer(
+    signature: &Secp256k1Signature,
+    recovery_id: &u8,
+    message: &[u8],
+) -> Result<UncompressedSecp256k1Pubkey> {
+    Ok(secp256k1_recover(message, *recovery_id, &signature.0)
+        .map_err(|_| ErrorCode::SignatureVerificationWrongSigner)?
+        .into())
+}
+
 #[cfg(test)]
 pub trait Secp256k1TestMessage
 where
@@ -356,8 +381,8 @@ pub fn test_secp256k1_sha256_verify_signer() {
     use anchor_lang::solana_program::hash::hashv;
     let secret = libsecp256k1::SecretKey::random(&mut rand::thread_rng());
     let public_key = libsecp256k1::PublicKey::from_secret_key(&secret);
-    let mut public_key_bytes = public_key.serialize();
-    let uncompressed_public_key = &UncompressedSecp256k1Pubkey::from(public_key_bytes);
+    let mut public_key_bytes = public_key.serialize_compressed();
+    let compressed_public_key = &CompressedSecp256k1Pubkey::from(public_key_bytes);
     let message = b"hello".to_vec();
     let message_hash = libsecp256k1::Message::parse_slice(hashv(&[&message]).as_ref()).unwrap();
     let (signature, recovery_id) = libsecp256k1::sign(&message_hash, &secret);
@@ -366,7 +391,14 @@ pub fn test_secp256k1_sha256_verify_signer() {
     assert!(secp256k1_verify_signer(
         &Secp256k1Signature::from(signature_bytes),
         &recovery_id.serialize(),
-        uncompressed_public_key,
+        compressed_public_key,
+        &message_hash_bytes,
+    )
+    .is_ok());
+    assert!(secp256k1_verify_uncompressed_signer(
+        &Secp256k1Signature::from(signature_bytes),
+        &recovery_id.serialize(),
+        &UncompressedSecp256k1Pubkey::from(public_key.serialize()),
         &message_hash_bytes,
     )
     .is_ok());
@@ -374,11 +406,11 @@ pub fn test_secp256k1_sha256_verify_signer() {
 
     // wrong public key
     public_key_bytes[0] ^= 0xff;
-    let uncompressed_public_key = &UncompressedSecp256k1Pubkey::from(public_key_bytes);
+    let compressed_public_key = &CompressedSecp256k1Pubkey::from(public_key_bytes);
     let res = secp256k1_verify_signer(
         &Secp256k1Signature::from(signature_bytes),
         &recovery_id.serialize(),
-        uncompressed_public_key,
+        compressed_public_key,
         &message_hash_bytes,
     );
     assert!(res.is_err());
@@ -388,7 +420,7 @@ pub fn test_secp256k1_sha256_verify_signer() {
     );
 
     public_key_bytes[0] ^= 0xff;
-    let uncompressed_public_key = &UncompressedSecp256k1Pubkey::from(public_key_bytes);
+    let compressed_public_key = &CompressedSecp256k1Pubkey::from(public_key_bytes);
 
     // invalid signature
     signature_bytes[0] ^= 0xff;
@@ -396,7 +428,7 @@ pub fn test_secp256k1_sha256_verify_signer() {
     let res = secp256k1_verify_signer(
         &Secp256k1Signature::from(signature_bytes),
         &recovery_id.serialize(),
-        uncompressed_public_key,
+        compressed_public_key,
         &message_hash_bytes,
     );
     assert!(res.is_err());
@@ -412,7 +444,7 @@ pub fn test_secp256k1_sha256_verify_signer() {
     let res = secp256k1_verify_signer(
         &Secp256k1Signature::from(signature_bytes),
         &recovery_id.serialize(),
-        uncompressed_public_key,
+        compressed_public_key,
         &message_hash_bytes,
     );
     assert!(res.is_err());
@@ -429,7 +461,7 @@ pub fn test_secp256k1_sha256_verify_signer() {
     let res = secp256k1_verify_signer(
         &Secp256k1Signature::from(signature_bytes),
         &recovery_id_bytes,
-        uncompressed_public_key,
+        compressed_public_key,
         &message_hash_bytes,
     );
     assert!(res.is_err());
@@ -442,7 +474,7 @@ pub fn test_secp256k1_sha256_verify_signer() {
     assert!(secp256k1_verify_signer(
         &Secp256k1Signature::from(signature_bytes),
         &recovery_id_bytes,
-        uncompressed_public_key,
+        compressed_public_key,
         &message_hash_bytes,
     )
     .is_ok());
@@ -0,0 +1,112 @@
+use {
+    crate::ErrorCode,
+    anchor_lang::{
+        prelude::*,
+        AnchorDeserialize,
+        AnchorSerialize,
+    },
+    p256::ecdsa::{
+        signature::Verifier,
+        Signature,
+        VerifyingKey,
+    },
+};
+
+/**
+ * A compressed Secp256r1 (P-256) pubkey.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Debug)]
+pub struct Secp256r1Pubkey([u8; Self::LEN]);
+impl Secp256r1Pubkey {
+    pub const LEN: usize = 33;
+
+    pub fn as_bytes(&self) -> [u8; Self::LEN] {
+        self.0
+    }
+}
+
+#[cfg(test)]
+impl From<[u8; Self::LEN]> for Secp256r1Pubkey {
+    fn from(bytes: [u8; Self::LEN]) -> Self {
+        Secp256r1Pubkey(bytes)
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Debug)]
+pub struct Secp256r1Signature([u8; Self::LEN]);
+impl Secp256r1Signature {
+    pub const LEN: usize = 64;
+}
+
+#[cfg(test)]
+impl From<[u8; Self::LEN]> for Secp256r1Signature {
+    fn from(bytes: [u8; Self::LEN]) -> Self {
+        Secp256r1Signature(bytes)
+    }
+}
+
+/**
+ * There is no Secp256r1 precompile on Solana, so the ECDSA signature of the SHA256 hash of
+ * `message` is verified by the program itself. This takes a lot of compute units, claim
+ * transactions using it should request a higher compute budget.
+ */
+pub fn secp256r1_verify_signer(
+    signature: &Secp256r1Signature,
+    pubkey: &Secp256r1Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let verifying_key = VerifyingKey::from_sec1_bytes(&pubkey.0)
+        .map_err(|_| ErrorCode::SignatureVerificationWrongSigner)?;
+    let signature = Signature::try_from(signature.0.as_slice())
+        .map_err(|_| ErrorCode::SignatureVerificationWrongSigner)?;
+    verifying_key
+        .verify(message, &signature)
+        .map_err(|_| ErrorCode::SignatureVerificationWrongSigner)?;
+    Ok(())
+}
+
+#[test]
+pub fn test_secp256r1_verify_signer() {
+    use p256::ecdsa::{
+        signature::Signer,
+        SigningKey,
+    };
+
+    let signing_key = SigningKey::from_bytes(&[7u8; 32]).unwrap();
+    let mut pubkey = [0u8; Secp256r1Pubkey::LEN];
+    pubkey.copy_from_slice(
+        signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes(),
+    );
+    let signature: Signature = signing_key.sign(b"hello");
+    let mut signature_bytes = [0u8; Secp256r1Signature::LEN];
+    signature_bytes.copy_from_slice(signature.as_ref());
+
+    assert!(secp256r1_verify_signer(
+        &Secp256r1Signature(signature_bytes),
+        &Secp256r1Pubkey(pubkey),
+        b"hello"
+    )
+    .is_ok());
+    assert_eq!(
+        secp256r1_verify_signer(
+            &Secp256r1Signature(signature_bytes),
+            &Secp256r1Pubkey(pubkey),
+            b"world"
+        )
+        .unwrap_err(),
+        error!(ErrorCode::SignatureVerificationWrongSigner)
+    );
+    pubkey[0] ^= 1;
+    assert_eq!(
+        secp256r1_verify_signer(
+            &Secp256r1Signature(signature_bytes),
+            &Secp256r1Pubkey(pubkey),
+            b"hello"
+        )
+        .unwrap_err(),
+        error!(ErrorCode::SignatureVerificationWrongSigner)
+    );
+}
@@ -0,0 +1,96 @@
+#[cfg(test)]
+use super::{
+    ed25519::{
+        Ed25519TestClaimantMessage,
+        Ed25519TestMessage,
+    },
+    get_expected_payload,
+    DEFAULT_AUTHORIZATION_TEMPLATE,
+};
+use {
+    crate::ErrorCode,
+    anchor_lang::{
+        prelude::*,
+        AnchorDeserialize,
+        AnchorSerialize,
+    },
+};
+
+pub const SUBSTRATE_BYTES_PREFIX: &[u8] = b"<Bytes>";
+pub const SUBSTRATE_BYTES_SUFFIX: &[u8] = b"</Bytes>";
+/// SS58 network prefixes are 14 bits
+pub const SUBSTRATE_MAX_SS58_NETWORK: u16 = (1 << 14) - 1;
+
+/**
+ * A raw message signed by a Substrate wallet (Polkadot.js, Talisman, ...).
+ * Only the message payload is stored in this struct.
+ * `signRaw` wraps the payload in `<Bytes>` and `</Bytes>` so that it can't be an extrinsic.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
+pub struct SubstrateMessage(Vec<u8>);
+
+impl SubstrateMessage {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        Ok(SubstrateMessage(
+            data.strip_prefix(SUBSTRATE_BYTES_PREFIX)
+                .and_then(|rest| rest.strip_suffix(SUBSTRATE_BYTES_SUFFIX))
+                .ok_or(ErrorCode::SignatureVerificationWrongPayloadMetadata)?
+                .to_vec(),
+        ))
+    }
+
+    pub fn get_payload(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+/**
+ * Substrate addresses are the SS58 encoding of the network prefix and the public key, which is
+ * done off-chain when building the allocations. Only ed25519 accounts are supported since
+ * sr25519 signatures can't be verified on Solana.
+ */
+pub fn check_ss58_network(network: u16) -> Result<()> {
+    require!(
+        network <= SUBSTRATE_MAX_SS58_NETWORK,
+        ErrorCode::InvalidSubstrateNetwork
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+impl Ed25519TestMessage for SubstrateMessage {
+    fn get_message_with_metadata(&self) -> Vec<u8> {
+        [SUBSTRATE_BYTES_PREFIX, &self.0, SUBSTRATE_BYTES_SUFFIX].concat()
+    }
+}
+
+#[cfg(test)]
+impl Ed25519TestClaimantMessage for SubstrateMessage {
+    fn for_claimant(claimant: &Pubkey) -> Self {
+        Self(get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant).into_bytes())
+    }
+}
+
+#[test]
+fn test_parse() {
+    assert_eq!(
+        SubstrateMessage::parse(b"<Bytes>hello</Bytes>")
+            .unwrap()
+            .get_payload(),
+        b"hello"
+    );
+    assert_eq!(
+        SubstrateMessage::parse(b"<Bytes>hello").unwrap_err(),
+        ErrorCode::SignatureVerificationWrongPayloadMetadata.into()
+    );
+    assert_eq!(
+        SubstrateMessage::parse(b"hello</Bytes>").unwrap_err(),
+        ErrorCode::SignatureVerificationWrongPayloadMetadata.into()
+    );
+    assert!(check_ss58_network(0).is_ok());
+    assert!(check_ss58_network(SUBSTRATE_MAX_SS58_NETWORK).is_ok());
+    assert_eq!(
+        check_ss58_network(SUBSTRATE_MAX_SS58_NETWORK + 1).unwrap_err(),
+        ErrorCode::InvalidSubstrateNetwork.into()
+    );
+}
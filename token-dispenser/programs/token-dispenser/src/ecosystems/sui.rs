@@ -1,13 +1,22 @@
 #[cfg(test)]
-use super::ed25519::Ed25519TestMessage;
+use super::{
+    ed25519::{
+        Ed25519TestClaimantMessage,
+        Ed25519TestMessage,
+    },
+    get_expected_payload,
+    DEFAULT_AUTHORIZATION_TEMPLATE,
+};
 use {
     super::{
+        cosmos::CompressedSecp256k1Pubkey,
         ed25519::Ed25519Pubkey,
-        get_expected_payload,
+        secp256r1::Secp256r1Pubkey,
     },
     crate::ErrorCode,
     anchor_lang::{
         prelude::*,
+        solana_program::hash,
         AnchorDeserialize,
         AnchorSerialize,
     },
@@ -17,6 +26,10 @@ use {
 
 
 pub const SUI_SIGNATURE_SCHEME_ID: u8 = 0;
+pub const SUI_SECP256K1_SIGNATURE_SCHEME_ID: u8 = 1;
+pub const SUI_SECP256R1_SIGNATURE_SCHEME_ID: u8 = 2;
+pub const SUI_MULTISIG_SIGNATURE_SCHEME_ID: u8 = 3;
+pub const SUI_MAX_MULTISIG_MEMBERS: usize = 10;
 pub const SUI_PREFIX: &[u8] = &[3, 0, 0];
 
 /**
@@ -46,21 +59,32 @@ impl SuiMessage {
      * of parsing the message as in other ecosystems. Instead we just check that the hash of the prefixed payload
      * matches the hash of the expected payload
      */
-    pub fn check_hashed_payload(payload: &[u8], claimant: &Pubkey) -> Result<()> {
-        if payload != SuiMessage::get_expected_hash(&get_expected_payload(claimant)) {
+    pub fn check_hashed_payload(payload: &[u8], expected_payload: &str) -> Result<()> {
+        if payload != SuiMessage::get_expected_hash(expected_payload) {
             return err!(ErrorCode::SignatureVerificationWrongPayload);
         }
         Ok(())
     }
+
+    /**
+     * Secp256k1 Sui keys sign the SHA256 hash of the Blake2b hash. The Secp256r1 scheme does the
+     * same, but `secp256r1_verify_signer` takes care of the SHA256 hash.
+     */
+    pub fn get_secp256_prehash(hashed_payload: &[u8]) -> [u8; 32] {
+        hash::hashv(&[hashed_payload]).to_bytes()
+    }
 }
 
 
 #[cfg(test)]
-impl Ed25519TestMessage for SuiMessage {
-    fn for_claimant(claimant: &Pubkey) -> Self {
-        Self(get_expected_payload(claimant).into_bytes())
+impl From<&str> for SuiMessage {
+    fn from(payload: &str) -> Self {
+        SuiMessage(payload.as_bytes().to_vec())
     }
+}
 
+#[cfg(test)]
+impl Ed25519TestMessage for SuiMessage {
     fn get_message_with_metadata(&self) -> Vec<u8> {
         let mut result: Vec<u8> = Vec::<u8>::new();
         result.extend(SUI_PREFIX);
@@ -72,26 +96,163 @@ impl Ed25519TestMessage for SuiMessage {
     }
 }
 
+#[cfg(test)]
+impl Ed25519TestClaimantMessage for SuiMessage {
+    fn for_claimant(claimant: &Pubkey) -> Self {
+        Self(get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant).into_bytes())
+    }
+}
+
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
 pub struct SuiAddress([u8; 32]);
 
 impl SuiAddress {
     pub const LEN: usize = 32;
-}
 
-impl From<Ed25519Pubkey> for SuiAddress {
-    fn from(val: Ed25519Pubkey) -> Self {
+    /**
+     * Sui addresses are the Blake2b hash of the signature scheme flag followed by the pubkey.
+     */
+    fn from_scheme_and_pubkey(signature_scheme_id: u8, pubkey: &[u8]) -> Self {
         let mut context = Blake2b::new(32);
         let mut result = SuiAddress([0u8; 32]);
-        context.update(&[SUI_SIGNATURE_SCHEME_ID]);
-        context.update(&val.to_bytes());
+        context.update(&[signature_scheme_id]);
+        context.update(pubkey);
 
         result.0.copy_from_slice(context.finalize().as_bytes());
         result
     }
 }
 
+impl From<Ed25519Pubkey> for SuiAddress {
+    fn from(val: Ed25519Pubkey) -> Self {
+        SuiAddress::from_scheme_and_pubkey(SUI_SIGNATURE_SCHEME_ID, &val.to_bytes())
+    }
+}
+
+impl From<CompressedSecp256k1Pubkey> for SuiAddress {
+    fn from(val: CompressedSecp256k1Pubkey) -> Self {
+        SuiAddress::from_scheme_and_pubkey(SUI_SECP256K1_SIGNATURE_SCHEME_ID, &val.as_bytes())
+    }
+}
+
+impl From<Secp256r1Pubkey> for SuiAddress {
+    fn from(val: Secp256r1Pubkey) -> Self {
+        SuiAddress::from_scheme_and_pubkey(SUI_SECP256R1_SIGNATURE_SCHEME_ID, &val.as_bytes())
+    }
+}
+
+/**
+ * The public key of a member of a Sui multisig, with the signature scheme of the member.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub enum SuiMultisigMemberPubkey {
+    Ed25519(Ed25519Pubkey),
+    Secp256k1(CompressedSecp256k1Pubkey),
+    Secp256r1(Secp256r1Pubkey),
+}
+
+impl SuiMultisigMemberPubkey {
+    fn get_signature_scheme_id(&self) -> u8 {
+        match self {
+            SuiMultisigMemberPubkey::Ed25519(_) => SUI_SIGNATURE_SCHEME_ID,
+            SuiMultisigMemberPubkey::Secp256k1(_) => SUI_SECP256K1_SIGNATURE_SCHEME_ID,
+            SuiMultisigMemberPubkey::Secp256r1(_) => SUI_SECP256R1_SIGNATURE_SCHEME_ID,
+        }
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            SuiMultisigMemberPubkey::Ed25519(pubkey) => pubkey.to_bytes().to_vec(),
+            SuiMultisigMemberPubkey::Secp256k1(pubkey) => pubkey.as_bytes().to_vec(),
+            SuiMultisigMemberPubkey::Secp256r1(pubkey) => pubkey.as_bytes().to_vec(),
+        }
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct SuiMultisigMember {
+    pub pubkey: SuiMultisigMemberPubkey,
+    pub weight: u8,
+}
+
+/**
+ * A Sui multisig account, the members that signed need to add up to a weight of at least
+ * `threshold`. The address is the Blake2b hash of the multisig flag, the threshold and each
+ * member's flag, pubkey and weight, so it depends on the order of the members.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct SuiMultisigPubkey {
+    pub members:   Vec<SuiMultisigMember>,
+    pub threshold: u16,
+}
+
+/**
+ * The member `member_index` of a Sui multisig signed the Sui message, the signature is checked
+ * by the Ed25519 instruction at `verification_instruction_index`.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct SuiMultisigSigner {
+    pub member_index:                   u8,
+    pub verification_instruction_index: u8,
+}
+
+impl SuiMultisigPubkey {
+    pub fn get_address(&self) -> SuiAddress {
+        let mut pubkey = vec![];
+        pubkey.extend_from_slice(&self.threshold.to_le_bytes());
+        for member in &self.members {
+            pubkey.push(member.pubkey.get_signature_scheme_id());
+            pubkey.extend_from_slice(&member.pubkey.to_bytes());
+            pubkey.push(member.weight);
+        }
+        SuiAddress::from_scheme_and_pubkey(SUI_MULTISIG_SIGNATURE_SCHEME_ID, &pubkey)
+    }
+
+    /**
+     * Check that the signers have enough weight to reach the threshold. Signers need to be
+     * sorted by `member_index` so that each member is counted once, and only Ed25519 members
+     * can sign. Returns the pubkey of each signer.
+     */
+    pub fn check_signers(&self, signers: &[SuiMultisigSigner]) -> Result<Vec<&Ed25519Pubkey>> {
+        require!(
+            !self.members.is_empty()
+                && self.members.len() <= SUI_MAX_MULTISIG_MEMBERS
+                && self.members.iter().all(|member| member.weight > 0)
+                && self.threshold > 0
+                && self
+                    .members
+                    .iter()
+                    .map(|member| member.weight as u16)
+                    .sum::<u16>()
+                    >= self.threshold,
+            ErrorCode::InvalidSuiMultisig
+        );
+
+        let mut previous_member_index = None;
+        let mut weight: u16 = 0;
+        let mut pubkeys = vec![];
+        for signer in signers {
+            require!(
+                previous_member_index < Some(signer.member_index),
+                ErrorCode::InvalidSuiMultisig
+            );
+            let member = self
+                .members
+                .get(signer.member_index as usize)
+                .ok_or(ErrorCode::InvalidSuiMultisig)?;
+            match &member.pubkey {
+                SuiMultisigMemberPubkey::Ed25519(pubkey) => pubkeys.push(pubkey),
+                _ => return err!(ErrorCode::InvalidSuiMultisig),
+            }
+            weight += member.weight as u16;
+            previous_member_index = Some(signer.member_index);
+        }
+        require!(weight >= self.threshold, ErrorCode::InvalidSuiMultisig);
+        Ok(pubkeys)
+    }
+}
+
 #[cfg(test)]
 impl From<[u8; Self::LEN]> for SuiAddress {
     fn from(bytes: [u8; Self::LEN]) -> Self {
@@ -103,18 +264,22 @@ impl From<[u8; Self::LEN]> for SuiAddress {
 #[test]
 pub fn test_check_hashed_payload() {
     let claimant = Pubkey::new_unique();
-    let expected_hash = SuiMessage::get_expected_hash(&get_expected_payload(&claimant));
+    let expected_payload = get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, &claimant);
+    let expected_hash = SuiMessage::get_expected_hash(&expected_payload);
 
-    assert!(SuiMessage::check_hashed_payload(&expected_hash, &claimant).is_ok());
+    assert!(SuiMessage::check_hashed_payload(&expected_hash, &expected_payload).is_ok());
 
     assert_eq!(
-        SuiMessage::check_hashed_payload(&expected_hash, &Pubkey::new_unique()),
+        SuiMessage::check_hashed_payload(
+            &expected_hash,
+            &get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, &Pubkey::new_unique())
+        ),
         err!(ErrorCode::SignatureVerificationWrongPayload)
     );
     assert_eq!(
         SuiMessage::check_hashed_payload(
             &SuiMessage::get_expected_hash("this_is_the_wrong_payload"),
-            &claimant
+            &expected_payload
         ),
         err!(ErrorCode::SignatureVerificationWrongPayload)
     );
@@ -0,0 +1,203 @@
+#[cfg(test)]
+use super::{
+    ed25519::{
+        Ed25519TestClaimantMessage,
+        Ed25519TestMessage,
+    },
+    get_expected_payload,
+    DEFAULT_AUTHORIZATION_TEMPLATE,
+};
+use {
+    super::ed25519::{
+        Ed25519Pubkey,
+        GuardAttestation,
+    },
+    crate::ErrorCode,
+    anchor_lang::{
+        prelude::*,
+        solana_program::hash,
+        AnchorDeserialize,
+        AnchorSerialize,
+    },
+};
+
+pub const TON_PROOF_PREFIX: &[u8] = b"ton-proof-item-v2/";
+pub const TON_CONNECT_PREFIX: &[u8] = b"ton-connect";
+pub const TON_SIGNATURE_PREFIX: [u8; 2] = [0xff, 0xff];
+
+/**
+ * A raw TON address, i.e. the workchain and the hash of the wallet contract, without the flags
+ * and the checksum of the user-friendly base64 encoding.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Debug)]
+pub struct TonAddress {
+    pub workchain: i32,
+    pub hash:      [u8; 32],
+}
+
+/**
+ * The fields of a TON Connect `ton_proof` other than the address and the payload, which is the
+ * authorization payload.
+ * The wallet signs `sha256(0xffff || "ton-connect" || sha256(message))` where `message` is
+ * `"ton-proof-item-v2/" || workchain || address hash || domain length || domain || timestamp ||
+ * payload`. Like for Sui, we check that the signed hash matches the hash of the expected payload.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
+pub struct TonProofMessage {
+    pub domain:    String,
+    pub timestamp: u64,
+}
+
+impl TonProofMessage {
+    pub fn get_expected_hash(&self, address: &TonAddress, payload: &str) -> [u8; 32] {
+        let message = hash::hashv(&[
+            TON_PROOF_PREFIX,
+            &address.workchain.to_be_bytes(),
+            &address.hash,
+            &(self.domain.len() as u32).to_le_bytes(),
+            self.domain.as_bytes(),
+            &self.timestamp.to_le_bytes(),
+            payload.as_bytes(),
+        ]);
+        hash::hashv(&[
+            &TON_SIGNATURE_PREFIX,
+            TON_CONNECT_PREFIX,
+            &message.to_bytes(),
+        ])
+        .to_bytes()
+    }
+
+    pub fn check_hashed_payload(
+        &self,
+        hashed_payload: &[u8],
+        address: &TonAddress,
+        expected_payload: &str,
+    ) -> Result<()> {
+        if hashed_payload != self.get_expected_hash(address, expected_payload) {
+            return err!(ErrorCode::SignatureVerificationWrongPayload);
+        }
+        Ok(())
+    }
+}
+
+/**
+ * This message (borsh-serialized) needs to be signed by the dispenser guard after
+ * verifying on the TON blockchain that `pubkey` is the key of the wallet contract at `address`,
+ * since the address depends on the wallet contract and its state can't be read on Solana.
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TonKeyLinkMessage {
+    address: TonAddress,
+    pubkey:  Ed25519Pubkey,
+}
+
+impl TonKeyLinkMessage {
+    pub fn parse_and_check_address_and_pubkey(
+        data: &[u8],
+        address: &TonAddress,
+        pubkey: &Ed25519Pubkey,
+    ) -> Result<Self> {
+        let result = TonKeyLinkMessage::parse(data)?;
+
+        if result.address != *address || result.pubkey != *pubkey {
+            return err!(ErrorCode::SignatureVerificationWrongPayload);
+        }
+
+        Ok(result)
+    }
+}
+
+impl GuardAttestation for TonKeyLinkMessage {
+    const TAG: &'static [u8] = b"ton-key-link-v1";
+}
+
+#[cfg(test)]
+impl TonKeyLinkMessage {
+    pub fn new(address: TonAddress, pubkey: Ed25519Pubkey) -> Self {
+        Self { address, pubkey }
+    }
+}
+
+/**
+ * A `ton_proof` along with the address of the wallet and the payload that it was asked to sign.
+ */
+#[cfg(test)]
+#[derive(Clone)]
+pub struct TonTestMessage {
+    pub address: TonAddress,
+    pub payload: String,
+    pub message: TonProofMessage,
+}
+
+#[cfg(test)]
+impl Ed25519TestMessage for TonTestMessage {
+    fn get_message_with_metadata(&self) -> Vec<u8> {
+        self.message
+            .get_expected_hash(&self.address, &self.payload)
+            .to_vec()
+    }
+}
+
+#[cfg(test)]
+impl Ed25519TestClaimantMessage for TonTestMessage {
+    fn for_claimant(claimant: &Pubkey) -> Self {
+        Self {
+            address: TonAddress {
+                workchain: 0,
+                hash:      rand::random(),
+            },
+            payload: get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant),
+            message: TonProofMessage {
+                domain:    "airdrop.example.com".to_string(),
+                timestamp: 1699999999,
+            },
+        }
+    }
+}
+
+#[test]
+fn test_ton_proof_message() {
+    let address = TonAddress {
+        workchain: -1,
+        hash:      [2; 32],
+    };
+    let message = TonProofMessage {
+        domain:    "ton.org".to_string(),
+        timestamp: 1,
+    };
+
+    let mut expected_message = b"ton-proof-item-v2/".to_vec();
+    expected_message.extend_from_slice(&[0xff, 0xff, 0xff, 0xff]);
+    expected_message.extend_from_slice(&[2; 32]);
+    expected_message.extend_from_slice(&[7, 0, 0, 0]);
+    expected_message.extend_from_slice(b"ton.org");
+    expected_message.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0]);
+    expected_message.extend_from_slice(b"hello");
+    let mut expected_signed_message = vec![0xff, 0xff];
+    expected_signed_message.extend_from_slice(b"ton-connect");
+    expected_signed_message.extend_from_slice(&hash::hashv(&[&expected_message]).to_bytes());
+    assert_eq!(
+        message.get_expected_hash(&address, "hello"),
+        hash::hashv(&[&expected_signed_message]).to_bytes()
+    );
+
+    let hashed_payload = message.get_expected_hash(&address, "hello");
+    assert!(message
+        .check_hashed_payload(&hashed_payload, &address, "hello")
+        .is_ok());
+    assert_eq!(
+        message.check_hashed_payload(&hashed_payload, &address, "world"),
+        err!(ErrorCode::SignatureVerificationWrongPayload)
+    );
+    assert_eq!(
+        message.check_hashed_payload(
+            &hashed_payload,
+            &TonAddress {
+                workchain: 0,
+                hash:      [2; 32],
+            },
+            "hello"
+        ),
+        err!(ErrorCode::SignatureVerificationWrongPayload)
+    );
+}
@@ -0,0 +1,70 @@
+#[cfg(test)]
+use super::secp256k1::Secp256k1TestMessage;
+use {
+    super::evm::EvmPrefixedMessage,
+    anchor_lang::{
+        prelude::*,
+        AnchorDeserialize,
+        AnchorSerialize,
+    },
+};
+
+pub const TRON_MESSAGE_PREFIX: &str = "\x19TRON Signed Message:\n";
+
+/**
+ * A TIP-191 prefixed signed message, as signed by `signMessageV2` in TronLink.
+ * It's the same as an EIP-191 message with a different prefix, and Tron addresses are the EVM
+ * address of the key with a 0x41 prefix (encoded with base58check), so Tron signatures are
+ * checked by the Secp256k1 program like EVM ones.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
+pub struct TronMessage(Vec<u8>);
+
+impl TronMessage {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        Ok(TronMessage(
+            EvmPrefixedMessage::parse_with_prefix(data, TRON_MESSAGE_PREFIX)?
+                .get_payload()
+                .to_vec(),
+        ))
+    }
+
+    pub fn get_payload(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+#[cfg(test)]
+impl From<&str> for TronMessage {
+    fn from(string: &str) -> Self {
+        TronMessage(string.as_bytes().to_vec())
+    }
+}
+
+#[cfg(test)]
+impl Secp256k1TestMessage for TronMessage {
+    fn get_message_with_metadata(&self) -> Vec<u8> {
+        let mut prefixed_message = format!("{}{}", TRON_MESSAGE_PREFIX, self.0.len()).into_bytes();
+        prefixed_message.extend_from_slice(&self.0);
+        prefixed_message
+    }
+}
+
+#[test]
+fn test_parse() {
+    let message = TronMessage::from("hello");
+    assert_eq!(
+        TronMessage::parse(&message.get_message_with_metadata())
+            .unwrap()
+            .get_payload(),
+        b"hello"
+    );
+    assert_eq!(
+        TronMessage::parse(b"\x19Ethereum Signed Message:\n5hello").unwrap_err(),
+        crate::ErrorCode::SignatureVerificationWrongPayloadMetadata.into()
+    );
+    assert_eq!(
+        TronMessage::parse(b"\x19TRON Signed Message:\n6hello").unwrap_err(),
+        crate::ErrorCode::SignatureVerificationWrongPayloadMetadata.into()
+    );
+}
@@ -1,14 +1,18 @@
 #![allow(clippy::result_large_err)]
+#![allow(clippy::too_many_arguments)]
 use {
     anchor_lang::{
         prelude::*,
         solana_program::{
-            pubkey,
+            hash,
+            instruction::Instruction,
             keccak::hashv,
             program::{
                 invoke,
                 invoke_signed,
+                set_return_data,
             },
+            pubkey,
             system_instruction,
             sysvar::instructions::{
                 load_instruction_at_checked,
@@ -16,6 +20,8 @@ use {
             },
         },
         system_program,
+        Event,
+        InstructionData,
     },
     anchor_spl::{
         associated_token::{
@@ -30,33 +36,94 @@ use {
         },
     },
     ecosystems::{
-        algorand::AlgorandMessage,
+        algorand::{
+            AlgorandAddress,
+            AlgorandMessage,
+            AlgorandMultisigPubkey,
+            AlgorandMultisigSigner,
+            AlgorandRekeyMessage,
+        },
         aptos::{
             AptosAddress,
+            AptosKeyRotationMessage,
             AptosMessage,
+            AptosMultiEd25519Pubkey,
+            AptosMultiEd25519Signer,
+        },
+        bitcoin::{
+            hash160,
+            parse_bip137_header,
+            Bip322Message,
+            BitcoinAddress,
+            BitcoinChain,
+            BitcoinMessage,
         },
+        cardano::{
+            CardanoKeyHash,
+            CardanoMessage,
+        },
+        check_authorization_template,
+        check_min_payload_version,
         check_payload,
         cosmos::{
+            check_cosmos_chain_ids,
+            get_default_cosmos_chain_ids,
+            CompressedSecp256k1Pubkey,
             CosmosBech32Address,
             CosmosMessage,
+            CosmosMultisigSignature,
+            LegacyAminoPubKey,
             UncompressedSecp256k1Pubkey,
+            INJECTIVE_CHAIN_ID,
+            MAX_COSMOS_CHAIN_IDS,
+            MAX_COSMOS_CHAIN_ID_LEN,
         },
         discord::DiscordMessage,
         ed25519::{
             Ed25519InstructionData,
             Ed25519Pubkey,
         },
-        evm::EvmPrefixedMessage,
+        evm::{
+            Eip712Message,
+            EvmPrefixedMessage,
+            SiweMessage,
+        },
+        near::{
+            NearAccountId,
+            NearKeyLinkMessage,
+            NearMessage,
+        },
         secp256k1::{
+            secp256k1_recover_signer,
             secp256k1_verify_signer,
+            secp256k1_verify_uncompressed_signer,
             EvmPubkey,
             Secp256k1InstructionData,
             Secp256k1Signature,
         },
+        secp256r1::{
+            secp256r1_verify_signer,
+            Secp256r1Pubkey,
+            Secp256r1Signature,
+        },
+        substrate::{
+            check_ss58_network,
+            SubstrateMessage,
+        },
         sui::{
             SuiAddress,
             SuiMessage,
+            SuiMultisigPubkey,
+            SuiMultisigSigner,
+        },
+        ton::{
+            TonAddress,
+            TonKeyLinkMessage,
+            TonProofMessage,
         },
+        tron::TronMessage,
+        AuthorizationPayload,
+        MAX_AUTHORIZATION_TEMPLATE_LEN,
     },
     pythnet_sdk::{
         accumulators::merkle::{
@@ -94,13 +161,15 @@ const FORBIDDEN_SOL: &[Pubkey] = &[
 ];
 
 const FORBIDDEN_EVM: &[[u8; EvmPubkey::LEN]] = &[
-    [   //0x748e1932a18dc7adce63ab7e8e705004128402fd
-        0x74, 0x8e, 0x19, 0x32, 0xa1, 0x8d, 0xc7, 0xad, 0xce, 0x63,
-        0xab, 0x7e, 0x8e, 0x70, 0x50, 0x04, 0x12, 0x84, 0x02, 0xfd,
+    [
+        //0x748e1932a18dc7adce63ab7e8e705004128402fd
+        0x74, 0x8e, 0x19, 0x32, 0xa1, 0x8d, 0xc7, 0xad, 0xce, 0x63, 0xab, 0x7e, 0x8e, 0x70, 0x50,
+        0x04, 0x12, 0x84, 0x02, 0xfd,
     ],
-    [   //0x2fc617e933a52713247ce25730f6695920b3befe
-        0x2f, 0xc6, 0x17, 0xe9, 0x33, 0xa5, 0x27, 0x13, 0x24, 0x7c,
-        0xe2, 0x57, 0x30, 0xf6, 0x69, 0x59, 0x20, 0xb3, 0xbe, 0xfe,
+    [
+        //0x2fc617e933a52713247ce25730f6695920b3befe
+        0x2f, 0xc6, 0x17, 0xe9, 0x33, 0xa5, 0x27, 0x13, 0x24, 0x7c, 0xe2, 0x57, 0x30, 0xf6, 0x69,
+        0x59, 0x20, 0xb3, 0xbe, 0xfe,
     ],
 ];
 
@@ -141,6 +210,8 @@ declare_id!("Wapq3Hpv2aSKjWrh4pM8eweh8jVJB7D1nLBw9ikjVYx");
 
 const CONFIG_SEED: &[u8] = b"config";
 const RECEIPT_SEED: &[u8] = b"receipt";
+const CLAIM_STATS_SEED: &[u8] = b"claim_stats";
+const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
 #[program]
 pub mod token_dispenser {
     use {
@@ -151,11 +222,17 @@ pub mod token_dispenser {
     /// This can only be called once and should be called right after the program is deployed.
     pub fn initialize(
         ctx: Context<Initialize>,
-        merkle_root: MerkleRoot<SolanaHasher>,
+        merkle_root: ClaimMerkleRoot,
         dispenser_guard: Pubkey,
         max_transfer: u64,
+        leaf_format: LeafFormat,
+        genesis_hash: [u8; 32],
+        min_payload_version: u8,
+        authorization_template: String,
     ) -> Result<()> {
         require_keys_neq!(dispenser_guard, Pubkey::default());
+        check_min_payload_version(min_payload_version)?;
+        check_authorization_template(&authorization_template, min_payload_version)?;
         let config: &mut Account<'_, Config> = &mut ctx.accounts.config;
         config.bump = *ctx.bumps.get("config").unwrap();
         config.merkle_root = merkle_root;
@@ -163,6 +240,25 @@ pub mod token_dispenser {
         config.mint = ctx.accounts.mint.key();
         config.address_lookup_table = ctx.accounts.address_lookup_table.key();
         config.max_transfer = max_transfer;
+        config.leaf_format = leaf_format;
+        config.genesis_hash = genesis_hash;
+        config.min_payload_version = min_payload_version;
+        config.authorization_template = authorization_template;
+        config.admin = ctx.accounts.payer.key();
+        config.cosmos_chain_ids = get_default_cosmos_chain_ids();
+
+        ctx.accounts.claim_stats.bump = *ctx.bumps.get("claim_stats").unwrap();
+
+        emit_cpi_event(
+            &InitializeEvent {
+                config: (**config).clone(),
+                payer:  ctx.accounts.payer.key(),
+                slot:   Clock::get()?.slot,
+            },
+            &ctx.accounts.event_authority,
+            *ctx.bumps.get("event_authority").unwrap(),
+            &ctx.accounts.program,
+        )?;
         Ok(())
     }
 
@@ -175,6 +271,10 @@ pub mod token_dispenser {
      * - The claimant has provided a valid proof of inclusion (this confirm that the claimant --
      *   DONE
      * - The claimant has not already claimed tokens -- DONE
+     *
+     * This can also be called via CPI, for example by a multisig whose wallet is a PDA that signs
+     * as the claimant with `invoke_signed`. Signature verification instructions always live at the
+     * top level of the transaction since precompiles can't be invoked via CPI.
      */
     pub fn claim<'info>(
         ctx: Context<'_, '_, '_, 'info, Claim<'info>>,
@@ -184,34 +284,18 @@ pub mod token_dispenser {
         let treasury = &mut ctx.accounts.treasury;
         let claimant_fund = &ctx.accounts.claimant_fund;
 
-        match claim_certificate.proof_of_identity {
-            IdentityCertificate::Solana => {
-                let claimant_key = ctx.accounts.claimant.key;
-                require!(
-                    !FORBIDDEN_SOL
-                        .iter()
-                        .any(|&key| *claimant_key == key),
-                    ErrorCode::Forbidden
-                );
-            }
-            IdentityCertificate::Evm { pubkey, .. } => {
-                let pubkey_bytes = &pubkey.as_bytes();
-                require!(
-                    !FORBIDDEN_EVM.iter().any(|addr| *pubkey_bytes == *addr),
-                    ErrorCode::Forbidden
-                );
-            }
-            _ => {}
-        }
+        claim_certificate
+            .proof_of_identity
+            .check_not_forbidden(ctx.accounts.claimant.key)?;
 
         // Check that the identity corresponding to the leaf has authorized the claimant
         let claim_info = claim_certificate.checked_into_claim_info(
             &ctx.accounts.sysvar_instruction,
             ctx.accounts.claimant.key,
-            &ctx.accounts.config.dispenser_guard,
+            config,
         )?;
-        // Each leaf of the tree is a hash of the serialized claim info
-        let leaf_vector = claim_info.try_to_vec()?;
+        // Each leaf of the tree is a hash of the claim info encoded with the leaf format
+        let leaf_vector = config.leaf_format.encode_leaf(&claim_info, &config.mint)?;
 
         if !config
             .merkle_root
@@ -224,6 +308,7 @@ pub mod token_dispenser {
         checked_create_claim_receipt(
             0,
             &leaf_vector,
+            config.merkle_root.hash_algorithm(),
             &ctx.accounts.funder,
             &ctx.accounts.system_program,
             ctx.remaining_accounts,
@@ -251,14 +336,86 @@ pub mod token_dispenser {
         // reload treasury account from storage to get the updated balance
         treasury.reload()?;
 
-        emit!(ClaimEvent {
-            remaining_balance: treasury.amount,
-            treasury: ctx.accounts.treasury.key(),
-            claimant: *ctx.accounts.claimant.key,
-            claim_info,
-        });
+        ctx.accounts
+            .claim_stats
+            .record_claim(claim_info.identity.ecosystem(), claim_info.amount)?;
+
+        emit_cpi_event(
+            &ClaimEvent {
+                remaining_balance: treasury.amount,
+                treasury: ctx.accounts.treasury.key(),
+                claimant: *ctx.accounts.claimant.key,
+                ecosystem: claim_info.identity.ecosystem(),
+                claim_info,
+                leaf_hash: config.merkle_root.hash_algorithm().hash_leaf(&leaf_vector),
+                receipt: ctx.remaining_accounts[0].key(),
+                funder: ctx.accounts.funder.key(),
+                slot: Clock::get()?.slot,
+            },
+            &ctx.accounts.event_authority,
+            *ctx.bumps.get("event_authority").unwrap(),
+            &ctx.accounts.program,
+        )?;
+
+
+        Ok(())
+    }
+
+    /**
+     * Runs the same checks as `claim` without creating the receipt or transferring any tokens.
+     * The outcome is returned as a borsh-serialized `ClaimVerification` through
+     * `set_return_data` so that clients can simulate this instruction to learn whether a claim
+     * would succeed and, if not, why. Like in `claim`, the claim receipt is expected as the first
+     * remaining account.
+     */
+    pub fn verify_claim<'info>(
+        ctx: Context<'_, '_, '_, 'info, VerifyClaim<'info>>,
+        claim_certificate: ClaimCertificate,
+    ) -> Result<()> {
+        let verification = ClaimVerification::verify(
+            &claim_certificate,
+            ctx.accounts.claimant.key,
+            &ctx.accounts.config,
+            &ctx.accounts.sysvar_instruction,
+            ctx.remaining_accounts,
+        );
+        set_return_data(&verification.try_to_vec()?);
+        Ok(())
+    }
+
+    /**
+     * Replace the bech32 prefixes of the Cosmos chains whose users can claim. Only the admin,
+     * who initialized the program, can call this.
+     */
+    pub fn set_cosmos_chain_ids(
+        ctx: Context<SetCosmosChainIds>,
+        cosmos_chain_ids: Vec<String>,
+    ) -> Result<()> {
+        check_cosmos_chain_ids(&cosmos_chain_ids)?;
+        let config = &mut ctx.accounts.config;
+        let old_cosmos_chain_ids =
+            std::mem::replace(&mut config.cosmos_chain_ids, cosmos_chain_ids);
 
+        emit_cpi_event(
+            &CosmosChainIdsUpdatedEvent {
+                admin: ctx.accounts.admin.key(),
+                old_cosmos_chain_ids,
+                new_cosmos_chain_ids: config.cosmos_chain_ids.clone(),
+                slot: Clock::get()?.slot,
+            },
+            &ctx.accounts.event_authority,
+            *ctx.bumps.get("event_authority").unwrap(),
+            &ctx.accounts.program,
+        )?;
+        Ok(())
+    }
 
+    /**
+     * Target of the self-CPI used to emit events, see `emit_cpi_event`. It does nothing
+     * besides checking that the event authority signed, so that events can't be forged by
+     * calling it directly.
+     */
+    pub fn log_event(_ctx: Context<LogEvent>, _event: Vec<u8>) -> Result<()> {
         Ok(())
     }
 }
@@ -273,11 +430,17 @@ pub struct Initialize<'info> {
     pub payer:                Signer<'info>,
     #[account(init, payer = payer, space = Config::LEN, seeds = [CONFIG_SEED], bump)]
     pub config:               Account<'info, Config>,
+    #[account(init, payer = payer, space = ClaimStats::LEN, seeds = [CLAIM_STATS_SEED], bump)]
+    pub claim_stats:          Account<'info, ClaimStats>,
     pub mint:                 Account<'info, Mint>,
     pub system_program:       Program<'info, System>,
     /// CHECK: we only store this on-chain so it can be conveniently looked up off-chain
     #[account(owner = solana_address_lookup_table_program::id())]
     pub address_lookup_table: UncheckedAccount<'info>,
+    /// CHECK: PDA that signs the self-CPI emitting the events
+    #[account(seeds = [EVENT_AUTHORITY_SEED], bump)]
+    pub event_authority:      UncheckedAccount<'info>,
+    pub program:              Program<'info, crate::program::TokenDispenser>,
 }
 
 #[derive(Accounts)]
@@ -285,6 +448,7 @@ pub struct Initialize<'info> {
 pub struct Claim<'info> {
     #[account(mut)]
     pub funder:                   Signer<'info>, // Funds the claimant_fund and the claim receipt account
+    /// Either a wallet signing the transaction or a PDA signing via CPI
     pub claimant:                 Signer<'info>,
     /// Claimant's associated token account to receive the tokens
     /// Should be initialized outside of this program.
@@ -297,6 +461,16 @@ pub struct Claim<'info> {
     pub claimant_fund:            Account<'info, TokenAccount>,
     #[account(seeds = [CONFIG_SEED], bump = config.bump, has_one = mint)]
     pub config:                   Account<'info, Config>,
+    /// Accounts created before the latest ecosystems were added are grown by the funder
+    #[account(
+        mut,
+        seeds = [CLAIM_STATS_SEED],
+        bump = claim_stats.bump,
+        realloc = ClaimStats::LEN,
+        realloc::payer = funder,
+        realloc::zero = false,
+    )]
+    pub claim_stats:              Account<'info, ClaimStats>,
     pub mint:                     Account<'info, Mint>,
     #[account(mut)]
     pub treasury:                 Account<'info, TokenAccount>,
@@ -306,6 +480,39 @@ pub struct Claim<'info> {
     #[account(address = SYSVAR_IX_ID)]
     pub sysvar_instruction:       AccountInfo<'info>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: PDA that signs the self-CPI emitting the events
+    #[account(seeds = [EVENT_AUTHORITY_SEED], bump)]
+    pub event_authority:          UncheckedAccount<'info>,
+    pub program:                  Program<'info, crate::program::TokenDispenser>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyClaim<'info> {
+    /// CHECK: verify_claim doesn't require the claimant's signature, the claimant is only
+    /// compared against the authorization payloads
+    pub claimant:           UncheckedAccount<'info>,
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config:             Account<'info, Config>,
+    /// CHECK : Same as in `Claim`, the address is checked and the account is loaded using load_instruction_at_checked.
+    #[account(address = SYSVAR_IX_ID)]
+    pub sysvar_instruction: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetCosmosChainIds<'info> {
+    pub admin:           Signer<'info>,
+    #[account(mut, seeds = [CONFIG_SEED], bump = config.bump, has_one = admin)]
+    pub config:          Account<'info, Config>,
+    /// CHECK: PDA that signs the self-CPI emitting the events
+    #[account(seeds = [EVENT_AUTHORITY_SEED], bump)]
+    pub event_authority: UncheckedAccount<'info>,
+    pub program:         Program<'info, crate::program::TokenDispenser>,
+}
+
+#[derive(Accounts)]
+pub struct LogEvent<'info> {
+    #[account(seeds = [EVENT_AUTHORITY_SEED], bump)]
+    pub event_authority: Signer<'info>,
 }
 
 
@@ -319,6 +526,41 @@ pub struct ClaimInfo {
     pub amount:   u64,
 }
 
+/**
+ * How a `ClaimInfo` is encoded into a leaf of the merkle tree.
+ * - `V1` is the borsh serialization of the `ClaimInfo`.
+ * - `V2` is `LeafFormat::V2_VERSION || program id || campaign_id (u64 LE) || mint || V1 leaf`,
+ *   so that a tree and its proofs can't be replayed on another deployment or campaign.
+ *
+ * NOTE: Any changes to this must also be made to the leaf encoding in the typescript sdk and
+ * the test vectors in `test_merkle_tree`
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Eq, Debug)]
+pub enum LeafFormat {
+    V1,
+    V2 { campaign_id: u64 },
+}
+
+impl LeafFormat {
+    pub const LEN: usize = 1 + 8;
+    pub const V2_VERSION: u8 = 2;
+
+    pub fn encode_leaf(&self, claim_info: &ClaimInfo, mint: &Pubkey) -> Result<Vec<u8>> {
+        let claim_info_vector = claim_info.try_to_vec()?;
+        match self {
+            LeafFormat::V1 => Ok(claim_info_vector),
+            LeafFormat::V2 { campaign_id } => Ok([
+                &[Self::V2_VERSION][..],
+                crate::id().as_ref(),
+                &campaign_id.to_le_bytes(),
+                mint.as_ref(),
+                &claim_info_vector,
+            ]
+            .concat()),
+        }
+    }
+}
+
 /**
  * This is the identity that the claimant will use to claim tokens.
  * A claimant can claim tokens for 1 identity on each ecosystem.
@@ -326,16 +568,101 @@ pub struct ClaimInfo {
  */
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
 pub enum Identity {
-    Discord { username: String },
-    Solana { pubkey: Ed25519Pubkey },
-    Evm { pubkey: EvmPubkey },
-    Sui { address: SuiAddress },
-    Aptos { address: AptosAddress },
-    Cosmwasm { address: CosmosBech32Address },
-    Injective { address: CosmosBech32Address },
-    Algorand { pubkey: Ed25519Pubkey },
+    Discord {
+        username: String,
+    },
+    Solana {
+        pubkey: Ed25519Pubkey,
+    },
+    Evm {
+        pubkey: EvmPubkey,
+    },
+    Sui {
+        address: SuiAddress,
+    },
+    Aptos {
+        address: AptosAddress,
+    },
+    Cosmwasm {
+        address: CosmosBech32Address,
+    },
+    Injective {
+        address: CosmosBech32Address,
+    },
+    Algorand {
+        address: AlgorandAddress,
+    },
+    Bitcoin {
+        address: BitcoinAddress,
+    },
+    Near {
+        account_id: NearAccountId,
+    },
+    Tron {
+        pubkey: EvmPubkey,
+    },
+    Ton {
+        address: TonAddress,
+    },
+    Substrate {
+        network: u16,
+        pubkey:  Ed25519Pubkey,
+    },
+    Cardano {
+        key_hash: CardanoKeyHash,
+    },
+}
+
+/// Number of `Ecosystem` variants
+pub const ECOSYSTEM_COUNT: usize = 14;
+
+/**
+ * The ecosystem of an `Identity`, i.e. its variant without the data.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Ecosystem {
+    Discord,
+    Solana,
+    Evm,
+    Sui,
+    Aptos,
+    Cosmwasm,
+    Injective,
+    Algorand,
+    Bitcoin,
+    Near,
+    Tron,
+    Ton,
+    Substrate,
+    Cardano,
 }
 
+impl Identity {
+    pub fn ecosystem(&self) -> Ecosystem {
+        match self {
+            Identity::Discord { .. } => Ecosystem::Discord,
+            Identity::Solana { .. } => Ecosystem::Solana,
+            Identity::Evm { .. } => Ecosystem::Evm,
+            Identity::Sui { .. } => Ecosystem::Sui,
+            Identity::Aptos { .. } => Ecosystem::Aptos,
+            Identity::Cosmwasm { .. } => Ecosystem::Cosmwasm,
+            Identity::Injective { .. } => Ecosystem::Injective,
+            Identity::Algorand { .. } => Ecosystem::Algorand,
+            Identity::Bitcoin { .. } => Ecosystem::Bitcoin,
+            Identity::Near { .. } => Ecosystem::Near,
+            Identity::Tron { .. } => Ecosystem::Tron,
+            Identity::Ton { .. } => Ecosystem::Ton,
+            Identity::Substrate { .. } => Ecosystem::Substrate,
+            Identity::Cardano { .. } => Ecosystem::Cardano,
+        }
+    }
+}
+
+/**
+ * `verification_instruction_index` is the index of the signature verification instruction
+ * in the transaction, which the instructions sysvar exposes the same way whether `claim` is
+ * called directly or via CPI.
+ */
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
 pub enum IdentityCertificate {
     Discord {
@@ -359,7 +686,7 @@ pub enum IdentityCertificate {
         chain_id:    String,
         signature:   Secp256k1Signature,
         recovery_id: u8,
-        pubkey:      UncompressedSecp256k1Pubkey,
+        pubkey:      CompressedSecp256k1Pubkey,
         message:     Vec<u8>,
     },
     Injective {
@@ -370,13 +697,207 @@ pub enum IdentityCertificate {
         pubkey:                         Ed25519Pubkey,
         verification_instruction_index: u8,
     },
+    /// EIP-712 typed data signed on the EVM chain `chain_id`, see `Eip712Message`
+    EvmTypedData {
+        pubkey:                         EvmPubkey,
+        chain_id:                       u64,
+        verification_instruction_index: u8,
+    },
+    /// EIP-191 signature of an EIP-4361 (Sign-In with Ethereum) message, see `SiweMessage`
+    EvmSiwe {
+        pubkey:                         EvmPubkey,
+        verification_instruction_index: u8,
+    },
+    /// ADR-036 signature of an `ethsecp256k1` key of an Ethermint chain (Injective, Evmos...)
+    Ethermint {
+        chain_id:    String,
+        signature:   Secp256k1Signature,
+        recovery_id: u8,
+        pubkey:      UncompressedSecp256k1Pubkey,
+        message:     Vec<u8>,
+    },
+    /// ADR-036 signatures of members of a Cosmos legacy multisig, see `LegacyAminoPubKey`
+    CosmwasmMultisig {
+        chain_id:   String,
+        multisig:   LegacyAminoPubKey,
+        signatures: Vec<CosmosMultisigSignature>,
+        message:    Vec<u8>,
+    },
+    /// Members of an Aptos MultiEd25519 account signing the same Aptos message
+    AptosMultiEd25519 {
+        multisig: AptosMultiEd25519Pubkey,
+        signers:  Vec<AptosMultiEd25519Signer>,
+    },
+    /// Aptos account whose current key `pubkey` is linked to `address` by the dispenser guard,
+    /// see `AptosKeyRotationMessage`
+    AptosRotated {
+        address:                        AptosAddress,
+        pubkey:                         Ed25519Pubkey,
+        verification_instruction_index: u8,
+        attestation_instruction_index:  u8,
+    },
+    /// Sui account with the Secp256k1 signature scheme, `message` is the hash of the signed
+    /// Sui message, see `SuiMessage::get_expected_hash`
+    SuiSecp256k1 {
+        signature:   Secp256k1Signature,
+        recovery_id: u8,
+        pubkey:      CompressedSecp256k1Pubkey,
+        message:     Vec<u8>,
+    },
+    /// Sui account with the Secp256r1 signature scheme, verified without a precompile
+    SuiSecp256r1 {
+        signature: Secp256r1Signature,
+        pubkey:    Secp256r1Pubkey,
+        message:   Vec<u8>,
+    },
+    /// Ed25519 members of a Sui multisig signing the same Sui message, see `SuiMultisigPubkey`
+    SuiMultisig {
+        multisig: SuiMultisigPubkey,
+        signers:  Vec<SuiMultisigSigner>,
+    },
+    /// Subsigs of an Algorand multisig signing the same Algorand message
+    AlgorandMultisig {
+        multisig: AlgorandMultisigPubkey,
+        signers:  Vec<AlgorandMultisigSigner>,
+    },
+    /// Algorand account whose authorized key `pubkey` is linked to `address` by the dispenser
+    /// guard, see `AlgorandRekeyMessage`
+    AlgorandRekeyed {
+        address:                        AlgorandAddress,
+        pubkey:                         Ed25519Pubkey,
+        verification_instruction_index: u8,
+        attestation_instruction_index:  u8,
+    },
+    /// BIP-137 signature of `address`, `message` is the hash of the message signed with the
+    /// prefix of `chain`, see `BitcoinMessage::get_expected_hash`
+    Bitcoin {
+        chain:     BitcoinChain,
+        address:   BitcoinAddress,
+        signature: Secp256k1Signature,
+        header:    u8,
+        message:   Vec<u8>,
+    },
+    /// Simple BIP-322 signature of a P2WPKH address, `message` is the sighash of the `to_sign`
+    /// transaction, see `Bip322Message::get_expected_hash`
+    BitcoinBip322 {
+        signature:   Secp256k1Signature,
+        recovery_id: u8,
+        pubkey:      CompressedSecp256k1Pubkey,
+        message:     Vec<u8>,
+    },
+    /// NEP-413 signature of an implicit NEAR account, see `NearMessage`
+    Near {
+        pubkey:                         Ed25519Pubkey,
+        message:                        NearMessage,
+        verification_instruction_index: u8,
+    },
+    /// NEP-413 signature of a named NEAR account whose key `pubkey` is linked to `account_id` by
+    /// the dispenser guard, see `NearKeyLinkMessage`
+    NearNamed {
+        account_id:                     NearAccountId,
+        pubkey:                         Ed25519Pubkey,
+        message:                        NearMessage,
+        verification_instruction_index: u8,
+        attestation_instruction_index:  u8,
+    },
+    /// TIP-191 signature of a Tron account, `pubkey` is the address without the 0x41 prefix
+    Tron {
+        pubkey:                         EvmPubkey,
+        verification_instruction_index: u8,
+    },
+    /// TON Connect `ton_proof` of the wallet at `address` whose key `pubkey` is linked to
+    /// `address` by the dispenser guard, see `TonProofMessage` and `TonKeyLinkMessage`
+    Ton {
+        address:                        TonAddress,
+        pubkey:                         Ed25519Pubkey,
+        message:                        TonProofMessage,
+        verification_instruction_index: u8,
+        attestation_instruction_index:  u8,
+    },
+    /// Raw message signed by an ed25519 Substrate account, see `SubstrateMessage`. `network` is
+    /// the SS58 prefix of the address
+    Substrate {
+        network:                        u16,
+        pubkey:                         Ed25519Pubkey,
+        verification_instruction_index: u8,
+    },
+    /// CIP-30 `signData` signature, the address in the protected headers must have the key hash
+    /// of `pubkey` as its signing credential, see `CardanoMessage`
+    Cardano {
+        pubkey:                         Ed25519Pubkey,
+        verification_instruction_index: u8,
+    },
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
 pub struct ClaimCertificate {
-    pub amount:             u64,
-    pub proof_of_identity:  IdentityCertificate,
-    pub proof_of_inclusion: MerklePath<SolanaHasher>, // Proof that the leaf is in the tree
+    pub amount:                u64,
+    pub proof_of_identity:     IdentityCertificate,
+    pub proof_of_inclusion:    ClaimMerklePath, // Proof that the leaf is in the tree
+    pub authorization_payload: AuthorizationPayload, // Version of the payload signed by the identity
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Return data.
+////////////////////////////////////////////////////////////////////////////////
+
+/**
+ * The outcome of `verify_claim`.
+ * `claim_info` and `receipt` are only known once the proof of identity has been checked.
+ * `failure_reason` is why `claim` would fail, if it would.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub struct ClaimVerification {
+    pub claim_info:     Option<ClaimInfo>,
+    pub receipt:        Option<Pubkey>,
+    pub claimed:        bool,
+    pub failure_reason: Option<ClaimFailure>,
+}
+
+/**
+ * The error `claim` would fail with.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, PartialEq)]
+pub enum ClaimFailure {
+    Forbidden,
+    InvalidInclusionProof,
+    WrongPda,
+    AlreadyClaimed,
+    TransferExceedsMax,
+    InvalidProofOfIdentity { error_code: u32 }, // Code of the `ErrorCode` the proof was rejected with
+    Other { program_error: u64 }, // An error not raised by this program, encoded like a `ProgramError`
+}
+
+impl From<Error> for ClaimFailure {
+    fn from(error: Error) -> Self {
+        let failures = [
+            (ErrorCode::Forbidden, ClaimFailure::Forbidden),
+            (
+                ErrorCode::InvalidInclusionProof,
+                ClaimFailure::InvalidInclusionProof,
+            ),
+            (ErrorCode::WrongPda, ClaimFailure::WrongPda),
+            (ErrorCode::AlreadyClaimed, ClaimFailure::AlreadyClaimed),
+            (
+                ErrorCode::TransferExceedsMax,
+                ClaimFailure::TransferExceedsMax,
+            ),
+        ];
+        if let Some((_, failure)) = failures
+            .into_iter()
+            .find(|(error_code, _)| error == Error::from(*error_code))
+        {
+            return failure;
+        }
+        match error {
+            Error::AnchorError(anchor_error) => ClaimFailure::InvalidProofOfIdentity {
+                error_code: anchor_error.error_code_number,
+            },
+            Error::ProgramError(_) => ClaimFailure::Other {
+                program_error: u64::from(ProgramError::from(error)),
+            },
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -384,7 +905,7 @@ pub struct ClaimCertificate {
 ////////////////////////////////////////////////////////////////////////////////
 
 /**
- * A hasher that uses the solana pre-compiled keccak256 function.
+ * A hasher that uses the solana pre-compiled keccak256 function, truncated to 20 bytes.
  */
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SolanaHasher {}
@@ -402,73 +923,376 @@ impl Hasher for SolanaHasher {
     }
 }
 
-#[account]
-#[derive(PartialEq, Debug)]
-pub struct Config {
-    pub bump:                 u8,
-    pub merkle_root:          MerkleRoot<SolanaHasher>,
-    pub dispenser_guard:      Pubkey,
-    pub mint:                 Pubkey,
-    pub address_lookup_table: Pubkey,
-    pub max_transfer:         u64, // This is an extra safeguard to prevent the dispenser from being drained
-}
+/**
+ * A hasher that uses the solana pre-compiled keccak256 function.
+ */
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Keccak256Hasher {}
+impl Hasher for Keccak256Hasher {
+    type Hash = [u8; 32];
 
-impl Config {
-    pub const LEN: usize = 8 + 1 + SolanaHasher::LEN + 32 + 32 + 32 + 8;
+    fn hashv(data: &[impl AsRef<[u8]>]) -> Self::Hash {
+        hashv(&data.iter().map(|x| x.as_ref()).collect::<Vec<&[u8]>>()).to_bytes()
+    }
 }
 
-#[account]
-pub struct Receipt {}
+/**
+ * A hasher that uses the solana pre-compiled sha256 function.
+ */
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Sha256Hasher {}
+impl Hasher for Sha256Hasher {
+    type Hash = [u8; 32];
 
-////////////////////////////////////////////////////////////////////////////////
-// Error.
-////////////////////////////////////////////////////////////////////////////////
+    fn hashv(data: &[impl AsRef<[u8]>]) -> Self::Hash {
+        hash::hashv(&data.iter().map(|x| x.as_ref()).collect::<Vec<&[u8]>>()).to_bytes()
+    }
+}
 
-#[error_code]
-pub enum ErrorCode {
-    AlreadyClaimed,
-    InvalidInclusionProof,
-    WrongPda,
-    // Signature verification errors
-    SignatureVerificationWrongProgram,
-    SignatureVerificationWrongAccounts,
-    SignatureVerificationWrongHeader,
-    SignatureVerificationWrongPayload,
-    SignatureVerificationWrongPayloadMetadata,
-    SignatureVerificationWrongSigner,
-    UnauthorizedCosmosChainId,
-    TransferExceedsMax,
-    Forbidden,
+/**
+ * The hash function of the merkle tree of a campaign. `Keccak160` is the 20-byte truncated
+ * keccak256 of `SolanaHasher`, kept for compatibility with the existing trees.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HashAlgorithm {
+    Keccak160,
+    Keccak256,
+    Sha256,
 }
 
-pub fn check_claim_receipt_is_uninitialized(claim_receipt_account: &AccountInfo) -> Result<()> {
-    if claim_receipt_account.owner.eq(&crate::id()) {
-        return Err(ErrorCode::AlreadyClaimed.into());
+impl HashAlgorithm {
+    pub fn hash_leaf(&self, leaf: &[u8]) -> Vec<u8> {
+        match self {
+            HashAlgorithm::Keccak160 => MerkleTree::<SolanaHasher>::hash_leaf(leaf).to_vec(),
+            HashAlgorithm::Keccak256 => MerkleTree::<Keccak256Hasher>::hash_leaf(leaf).to_vec(),
+            HashAlgorithm::Sha256 => MerkleTree::<Sha256Hasher>::hash_leaf(leaf).to_vec(),
+        }
     }
-    Ok(())
 }
 
 /**
- * Checks that a proof of identity is valid and returns the underlying identity.
- * For some ecosystems like EVM we use a signature verification program,
- * for others like cosmos the signature is included in the ClaimCertificate.
+ * A merkle root along with the hash algorithm of its tree.
  */
-impl IdentityCertificate {
-    pub fn checked_into_identity(
-        &self,
-        sysvar_instruction: &AccountInfo,
-        claimant: &Pubkey,
-        dispenser_guard: &Pubkey,
-    ) -> Result<Identity> {
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, PartialEq, Debug)]
+pub enum ClaimMerkleRoot {
+    Keccak160(MerkleRoot<SolanaHasher>),
+    Keccak256(MerkleRoot<Keccak256Hasher>),
+    Sha256(MerkleRoot<Sha256Hasher>),
+}
+
+impl ClaimMerkleRoot {
+    pub const LEN: usize = 1 + 32; // Sized for the largest variant, see `Config` for the layout change
+
+    pub fn hash_algorithm(&self) -> HashAlgorithm {
         match self {
-            IdentityCertificate::Discord {
-                username,
-                verification_instruction_index,
-            } => {
-                let signature_verification_instruction = load_instruction_at_checked(
-                    *verification_instruction_index as usize,
-                    sysvar_instruction,
-                )?;
+            ClaimMerkleRoot::Keccak160(_) => HashAlgorithm::Keccak160,
+            ClaimMerkleRoot::Keccak256(_) => HashAlgorithm::Keccak256,
+            ClaimMerkleRoot::Sha256(_) => HashAlgorithm::Sha256,
+        }
+    }
+
+    /**
+     * A proof only checks against a root of the same hash algorithm.
+     */
+    pub fn check(&self, proof: ClaimMerklePath, leaf: &[u8]) -> bool {
+        match (self, proof) {
+            (ClaimMerkleRoot::Keccak160(root), ClaimMerklePath::Keccak160(proof)) => {
+                root.check(proof, leaf)
+            }
+            (ClaimMerkleRoot::Keccak256(root), ClaimMerklePath::Keccak256(proof)) => {
+                root.check(proof, leaf)
+            }
+            (ClaimMerkleRoot::Sha256(root), ClaimMerklePath::Sha256(proof)) => {
+                root.check(proof, leaf)
+            }
+            _ => false,
+        }
+    }
+}
+
+/**
+ * A merkle proof along with the hash algorithm of its tree.
+ */
+#[derive(AnchorDeserialize, AnchorSerialize, Clone)]
+pub enum ClaimMerklePath {
+    Keccak160(MerklePath<SolanaHasher>),
+    Keccak256(MerklePath<Keccak256Hasher>),
+    Sha256(MerklePath<Sha256Hasher>),
+}
+
+/**
+ * The hashers that can be used for the merkle tree of a campaign.
+ */
+pub trait ClaimHasher: Hasher {
+    const HASH_ALGORITHM: HashAlgorithm;
+
+    fn into_claim_merkle_root(root: MerkleRoot<Self>) -> ClaimMerkleRoot;
+    fn into_claim_merkle_path(path: MerklePath<Self>) -> ClaimMerklePath;
+}
+
+impl ClaimHasher for SolanaHasher {
+    const HASH_ALGORITHM: HashAlgorithm = HashAlgorithm::Keccak160;
+
+    fn into_claim_merkle_root(root: MerkleRoot<Self>) -> ClaimMerkleRoot {
+        ClaimMerkleRoot::Keccak160(root)
+    }
+
+    fn into_claim_merkle_path(path: MerklePath<Self>) -> ClaimMerklePath {
+        ClaimMerklePath::Keccak160(path)
+    }
+}
+
+impl ClaimHasher for Keccak256Hasher {
+    const HASH_ALGORITHM: HashAlgorithm = HashAlgorithm::Keccak256;
+
+    fn into_claim_merkle_root(root: MerkleRoot<Self>) -> ClaimMerkleRoot {
+        ClaimMerkleRoot::Keccak256(root)
+    }
+
+    fn into_claim_merkle_path(path: MerklePath<Self>) -> ClaimMerklePath {
+        ClaimMerklePath::Keccak256(path)
+    }
+}
+
+impl ClaimHasher for Sha256Hasher {
+    const HASH_ALGORITHM: HashAlgorithm = HashAlgorithm::Sha256;
+
+    fn into_claim_merkle_root(root: MerkleRoot<Self>) -> ClaimMerkleRoot {
+        ClaimMerkleRoot::Sha256(root)
+    }
+
+    fn into_claim_merkle_path(path: MerklePath<Self>) -> ClaimMerklePath {
+        ClaimMerklePath::Sha256(path)
+    }
+}
+
+impl<H: ClaimHasher> From<MerkleRoot<H>> for ClaimMerkleRoot {
+    fn from(root: MerkleRoot<H>) -> Self {
+        H::into_claim_merkle_root(root)
+    }
+}
+
+impl<H: ClaimHasher> From<MerklePath<H>> for ClaimMerklePath {
+    fn from(path: MerklePath<H>) -> Self {
+        H::into_claim_merkle_path(path)
+    }
+}
+
+/**
+ * The layout of this account is not the one of the first release: the merkle root became a
+ * `ClaimMerkleRoot` and the campaign parameters were added after `max_transfer`. There is no
+ * instruction to migrate an existing account, so a dispenser initialized with the old layout has
+ * to be replaced by a fresh deployment of the program, initialized again.
+ */
+#[account]
+#[derive(PartialEq, Debug)]
+pub struct Config {
+    pub bump:                   u8,
+    pub merkle_root:            ClaimMerkleRoot, // Also selects the hash algorithm of the tree
+    pub dispenser_guard:        Pubkey,
+    pub mint:                   Pubkey,
+    pub address_lookup_table:   Pubkey,
+    pub max_transfer:           u64, // This is an extra safeguard to prevent the dispenser from being drained
+    pub leaf_format:            LeafFormat,
+    pub genesis_hash:           [u8; 32], // Authorization payloads v2 are only valid on this cluster
+    pub min_payload_version:    u8, // Older versions of the authorization payload are rejected
+    pub authorization_template: String, // Message signed by the identities, see `DEFAULT_AUTHORIZATION_TEMPLATE`
+    pub admin:                  Pubkey,
+    pub cosmos_chain_ids:       Vec<String>, // Bech32 prefixes of the Cosmos chains allowed to claim
+}
+
+impl Config {
+    pub const LEN: usize = 8
+        + 1
+        + ClaimMerkleRoot::LEN
+        + 32
+        + 32
+        + 32
+        + 8
+        + LeafFormat::LEN
+        + 32
+        + 1
+        + 4
+        + MAX_AUTHORIZATION_TEMPLATE_LEN
+        + 32
+        + 4
+        + MAX_COSMOS_CHAIN_IDS * (4 + MAX_COSMOS_CHAIN_ID_LEN);
+}
+
+/**
+ * Running totals of the claims, updated on every claim so that they can be read in a single
+ * account fetch instead of replaying every `ClaimEvent`.
+ */
+#[account]
+pub struct ClaimStats {
+    pub bump:          u8,
+    pub total_claimed: u64,
+    pub claim_count:   u64,
+    pub ecosystems:    EcosystemsClaimStats,
+}
+
+impl ClaimStats {
+    pub const LEN: usize = 8 + 1 + 8 + 8 + ECOSYSTEM_COUNT * EcosystemClaimStats::LEN;
+
+    pub fn record_claim(&mut self, ecosystem: Ecosystem, amount: u64) -> Result<()> {
+        self.total_claimed = self
+            .total_claimed
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        self.claim_count = self
+            .claim_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        let ecosystem_stats = &mut self.ecosystems.0[ecosystem as usize];
+        ecosystem_stats.total_claimed = ecosystem_stats
+            .total_claimed
+            .checked_add(amount)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        ecosystem_stats.claim_count = ecosystem_stats
+            .claim_count
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    pub fn get(&self, ecosystem: Ecosystem) -> &EcosystemClaimStats {
+        &self.ecosystems.0[ecosystem as usize]
+    }
+}
+
+/**
+ * The stats of every ecosystem, indexed by `Ecosystem`.
+ * `ClaimStats` accounts created before an ecosystem was added don't have its entry, it
+ * deserializes as zero and the account is reallocated to `ClaimStats::LEN` by the next claim.
+ */
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct EcosystemsClaimStats(pub [EcosystemClaimStats; ECOSYSTEM_COUNT]);
+
+impl AnchorDeserialize for EcosystemsClaimStats {
+    fn deserialize(buf: &mut &[u8]) -> std::result::Result<EcosystemsClaimStats, std::io::Error> {
+        let mut ecosystems = [EcosystemClaimStats::default(); ECOSYSTEM_COUNT];
+        for ecosystem_stats in ecosystems.iter_mut() {
+            if buf.is_empty() {
+                break;
+            }
+            *ecosystem_stats = EcosystemClaimStats::deserialize(buf)?;
+        }
+        Ok(EcosystemsClaimStats(ecosystems))
+    }
+}
+
+impl AnchorSerialize for EcosystemsClaimStats {
+    fn serialize<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> std::result::Result<(), std::io::Error> {
+        self.0.serialize(writer)
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct EcosystemClaimStats {
+    pub total_claimed: u64,
+    pub claim_count:   u64,
+}
+
+impl EcosystemClaimStats {
+    pub const LEN: usize = 8 + 8;
+}
+
+#[account]
+pub struct Receipt {}
+
+////////////////////////////////////////////////////////////////////////////////
+// Error.
+////////////////////////////////////////////////////////////////////////////////
+
+#[error_code]
+pub enum ErrorCode {
+    AlreadyClaimed,
+    InvalidInclusionProof,
+    WrongPda,
+    // Signature verification errors
+    SignatureVerificationWrongProgram,
+    SignatureVerificationWrongAccounts,
+    SignatureVerificationWrongHeader,
+    SignatureVerificationWrongPayload,
+    SignatureVerificationWrongPayloadMetadata,
+    SignatureVerificationWrongSigner,
+    UnauthorizedCosmosChainId,
+    TransferExceedsMax,
+    Forbidden,
+    ArithmeticOverflow,
+    AuthorizationExpired,
+    InvalidAuthorizationTemplate,
+    AuthorizationNotYetValid,
+    InvalidCosmosChainIds,
+    InvalidCosmosMultisig,
+    InvalidAptosMultiEd25519,
+    InvalidSuiMultisig,
+    InvalidAlgorandMultisig,
+    InvalidBip137Header,
+    InvalidSubstrateNetwork,
+    UnsupportedAuthorizationPayloadVersion,
+}
+
+pub fn check_claim_receipt_is_uninitialized(claim_receipt_account: &AccountInfo) -> Result<()> {
+    if claim_receipt_account.owner.eq(&crate::id()) {
+        return Err(ErrorCode::AlreadyClaimed.into());
+    }
+    Ok(())
+}
+
+impl IdentityCertificate {
+    /**
+     * Rejects the Solana and EVM wallets that are not allowed to claim.
+     */
+    pub fn check_not_forbidden(&self, claimant: &Pubkey) -> Result<()> {
+        match self {
+            IdentityCertificate::Solana => {
+                require!(
+                    !FORBIDDEN_SOL.iter().any(|key| claimant == key),
+                    ErrorCode::Forbidden
+                );
+            }
+            IdentityCertificate::Evm { pubkey, .. }
+            | IdentityCertificate::EvmTypedData { pubkey, .. }
+            | IdentityCertificate::EvmSiwe { pubkey, .. } => {
+                let pubkey_bytes = &pubkey.as_bytes();
+                require!(
+                    !FORBIDDEN_EVM.iter().any(|addr| *pubkey_bytes == *addr),
+                    ErrorCode::Forbidden
+                );
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/**
+ * Checks that a proof of identity is valid and returns the underlying identity.
+ * For some ecosystems like EVM we use a signature verification program,
+ * for others like cosmos the signature is included in the ClaimCertificate.
+ */
+impl IdentityCertificate {
+    pub fn checked_into_identity(
+        &self,
+        sysvar_instruction: &AccountInfo,
+        claimant: &Pubkey,
+        dispenser_guard: &Pubkey,
+        expected_payload: &str,
+        cosmos_chain_ids: &[String],
+    ) -> Result<Identity> {
+        match self {
+            IdentityCertificate::Discord {
+                username,
+                verification_instruction_index,
+            } => {
+                let signature_verification_instruction = load_instruction_at_checked(
+                    *verification_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
                 let discord_message = DiscordMessage::parse_and_check_claimant_and_username(
                     &Ed25519InstructionData::extract_message_and_check_signature(
                         &signature_verification_instruction,
@@ -500,7 +1324,7 @@ impl IdentityCertificate {
                         )?,
                     )?
                     .get_payload(),
-                    claimant,
+                    expected_payload,
                 )?;
                 Ok(Identity::Evm { pubkey: *pubkey })
             }
@@ -512,8 +1336,8 @@ impl IdentityCertificate {
                 message,
             } => {
                 secp256k1_verify_signer(signature, recovery_id, pubkey, message)?;
-                let cosmos_bech32 = pubkey.into_bech32(chain_id)?;
-                CosmosMessage::check_hashed_payload(message, &cosmos_bech32, claimant)?;
+                let cosmos_bech32 = pubkey.into_bech32(chain_id, cosmos_chain_ids)?;
+                CosmosMessage::check_hashed_payload(message, &cosmos_bech32, expected_payload)?;
                 Ok(Identity::Cosmwasm {
                     address: cosmos_bech32,
                 })
@@ -535,10 +1359,10 @@ impl IdentityCertificate {
                         )?,
                     )?
                     .get_payload(),
-                    claimant,
+                    expected_payload,
                 )?;
                 Ok(Identity::Algorand {
-                    pubkey: pubkey.clone(),
+                    address: pubkey.clone().into(),
                 })
             }
             IdentityCertificate::Aptos {
@@ -549,6 +1373,7 @@ impl IdentityCertificate {
                     *verification_instruction_index as usize,
                     sysvar_instruction,
                 )?;
+                let address = Into::<AptosAddress>::into(pubkey.clone());
                 check_payload(
                     AptosMessage::parse(
                         &Ed25519InstructionData::extract_message_and_check_signature(
@@ -556,13 +1381,12 @@ impl IdentityCertificate {
                             pubkey,
                             verification_instruction_index,
                         )?,
+                        &address,
                     )?
                     .get_payload(),
-                    claimant,
+                    expected_payload,
                 )?;
-                Ok(Identity::Aptos {
-                    address: Into::<AptosAddress>::into(pubkey.clone()),
-                })
+                Ok(Identity::Aptos { address })
             }
             IdentityCertificate::Sui {
                 pubkey,
@@ -578,7 +1402,7 @@ impl IdentityCertificate {
                         pubkey,
                         verification_instruction_index,
                     )?,
-                    claimant,
+                    expected_payload,
                 )?;
                 Ok(Identity::Sui {
                     address: Into::<SuiAddress>::into(pubkey.clone()),
@@ -605,12 +1429,467 @@ impl IdentityCertificate {
                         )?,
                     )?
                     .get_payload(),
-                    claimant,
+                    expected_payload,
                 )?;
                 Ok(Identity::Injective {
                     address: cosmos_bech32,
                 })
             }
+            IdentityCertificate::EvmTypedData {
+                pubkey,
+                chain_id,
+                verification_instruction_index,
+            } => {
+                let signature_verification_instruction = load_instruction_at_checked(
+                    *verification_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                Eip712Message::check_message(
+                    &Secp256k1InstructionData::extract_message_and_check_signature(
+                        &signature_verification_instruction,
+                        pubkey,
+                        verification_instruction_index,
+                    )?,
+                    *chain_id,
+                    claimant,
+                    expected_payload,
+                )?;
+                Ok(Identity::Evm { pubkey: *pubkey })
+            }
+            IdentityCertificate::EvmSiwe {
+                pubkey,
+                verification_instruction_index,
+            } => {
+                let signature_verification_instruction = load_instruction_at_checked(
+                    *verification_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                SiweMessage::parse(
+                    EvmPrefixedMessage::parse(
+                        &Secp256k1InstructionData::extract_message_and_check_signature(
+                            &signature_verification_instruction,
+                            pubkey,
+                            verification_instruction_index,
+                        )?,
+                    )?
+                    .get_payload(),
+                )?
+                .check(pubkey, expected_payload, Clock::get()?.unix_timestamp)?;
+                Ok(Identity::Evm { pubkey: *pubkey })
+            }
+            IdentityCertificate::Ethermint {
+                pubkey,
+                chain_id,
+                signature,
+                recovery_id,
+                message,
+            } => {
+                secp256k1_verify_uncompressed_signer(signature, recovery_id, pubkey, message)?;
+                let ethermint_bech32 = pubkey.into_ethermint_bech32(chain_id, cosmos_chain_ids)?;
+                CosmosMessage::check_keccak_hashed_payload(
+                    message,
+                    &ethermint_bech32,
+                    expected_payload,
+                )?;
+                // Injective already has its own identity, shared with the EIP-191 flow
+                if chain_id == INJECTIVE_CHAIN_ID {
+                    Ok(Identity::Injective {
+                        address: ethermint_bech32,
+                    })
+                } else {
+                    Ok(Identity::Cosmwasm {
+                        address: ethermint_bech32,
+                    })
+                }
+            }
+            IdentityCertificate::CosmwasmMultisig {
+                chain_id,
+                multisig,
+                signatures,
+                message,
+            } => {
+                multisig.check_signatures(signatures, message)?;
+                let cosmos_bech32 = multisig.to_bech32(chain_id, cosmos_chain_ids)?;
+                CosmosMessage::check_hashed_payload(message, &cosmos_bech32, expected_payload)?;
+                Ok(Identity::Cosmwasm {
+                    address: cosmos_bech32,
+                })
+            }
+            IdentityCertificate::AptosMultiEd25519 { multisig, signers } => {
+                let address = multisig.get_address();
+                let mut messages = vec![];
+                for (signer, pubkey) in signers.iter().zip(multisig.check_signers(signers)?) {
+                    let signature_verification_instruction = load_instruction_at_checked(
+                        signer.verification_instruction_index as usize,
+                        sysvar_instruction,
+                    )?;
+                    messages.push(Ed25519InstructionData::extract_message_and_check_signature(
+                        &signature_verification_instruction,
+                        pubkey,
+                        &signer.verification_instruction_index,
+                    )?);
+                }
+                // All the members sign the same message
+                require!(
+                    messages.windows(2).all(|pair| pair[0] == pair[1]),
+                    ErrorCode::SignatureVerificationWrongPayload
+                );
+                check_payload(
+                    AptosMessage::parse(&messages[0], &address)?.get_payload(),
+                    expected_payload,
+                )?;
+                Ok(Identity::Aptos { address })
+            }
+            IdentityCertificate::AptosRotated {
+                address,
+                pubkey,
+                verification_instruction_index,
+                attestation_instruction_index,
+            } => {
+                let attestation_instruction = load_instruction_at_checked(
+                    *attestation_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                AptosKeyRotationMessage::parse_and_check_address_and_pubkey(
+                    &Ed25519InstructionData::extract_message_and_check_signature(
+                        &attestation_instruction,
+                        &Ed25519Pubkey::from(*dispenser_guard),
+                        attestation_instruction_index,
+                    )?,
+                    address,
+                    pubkey,
+                )?;
+
+                let signature_verification_instruction = load_instruction_at_checked(
+                    *verification_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                check_payload(
+                    AptosMessage::parse(
+                        &Ed25519InstructionData::extract_message_and_check_signature(
+                            &signature_verification_instruction,
+                            pubkey,
+                            verification_instruction_index,
+                        )?,
+                        address,
+                    )?
+                    .get_payload(),
+                    expected_payload,
+                )?;
+                Ok(Identity::Aptos {
+                    address: address.clone(),
+                })
+            }
+            IdentityCertificate::SuiSecp256k1 {
+                signature,
+                recovery_id,
+                pubkey,
+                message,
+            } => {
+                secp256k1_verify_signer(
+                    signature,
+                    recovery_id,
+                    pubkey,
+                    &SuiMessage::get_secp256_prehash(message),
+                )?;
+                SuiMessage::check_hashed_payload(message, expected_payload)?;
+                Ok(Identity::Sui {
+                    address: Into::<SuiAddress>::into(*pubkey),
+                })
+            }
+            IdentityCertificate::SuiSecp256r1 {
+                signature,
+                pubkey,
+                message,
+            } => {
+                secp256r1_verify_signer(signature, pubkey, message)?;
+                SuiMessage::check_hashed_payload(message, expected_payload)?;
+                Ok(Identity::Sui {
+                    address: Into::<SuiAddress>::into(*pubkey),
+                })
+            }
+            IdentityCertificate::SuiMultisig { multisig, signers } => {
+                for (signer, pubkey) in signers.iter().zip(multisig.check_signers(signers)?) {
+                    let signature_verification_instruction = load_instruction_at_checked(
+                        signer.verification_instruction_index as usize,
+                        sysvar_instruction,
+                    )?;
+                    SuiMessage::check_hashed_payload(
+                        &Ed25519InstructionData::extract_message_and_check_signature(
+                            &signature_verification_instruction,
+                            pubkey,
+                            &signer.verification_instruction_index,
+                        )?,
+                        expected_payload,
+                    )?;
+                }
+                Ok(Identity::Sui {
+                    address: multisig.get_address(),
+                })
+            }
+            IdentityCertificate::AlgorandMultisig { multisig, signers } => {
+                let mut messages = vec![];
+                for (signer, pubkey) in signers.iter().zip(multisig.check_signers(signers)?) {
+                    let signature_verification_instruction = load_instruction_at_checked(
+                        signer.verification_instruction_index as usize,
+                        sysvar_instruction,
+                    )?;
+                    messages.push(Ed25519InstructionData::extract_message_and_check_signature(
+                        &signature_verification_instruction,
+                        pubkey,
+                        &signer.verification_instruction_index,
+                    )?);
+                }
+                // All the subsigs sign the same message
+                require!(
+                    messages.windows(2).all(|pair| pair[0] == pair[1]),
+                    ErrorCode::SignatureVerificationWrongPayload
+                );
+                check_payload(
+                    AlgorandMessage::parse(&messages[0])?.get_payload(),
+                    expected_payload,
+                )?;
+                Ok(Identity::Algorand {
+                    address: multisig.get_address(),
+                })
+            }
+            IdentityCertificate::AlgorandRekeyed {
+                address,
+                pubkey,
+                verification_instruction_index,
+                attestation_instruction_index,
+            } => {
+                let attestation_instruction = load_instruction_at_checked(
+                    *attestation_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                AlgorandRekeyMessage::parse_and_check_address_and_pubkey(
+                    &Ed25519InstructionData::extract_message_and_check_signature(
+                        &attestation_instruction,
+                        &Ed25519Pubkey::from(*dispenser_guard),
+                        attestation_instruction_index,
+                    )?,
+                    address,
+                    pubkey,
+                )?;
+
+                let signature_verification_instruction = load_instruction_at_checked(
+                    *verification_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                check_payload(
+                    AlgorandMessage::parse(
+                        &Ed25519InstructionData::extract_message_and_check_signature(
+                            &signature_verification_instruction,
+                            pubkey,
+                            verification_instruction_index,
+                        )?,
+                    )?
+                    .get_payload(),
+                    expected_payload,
+                )?;
+                Ok(Identity::Algorand {
+                    address: address.clone(),
+                })
+            }
+            IdentityCertificate::Bitcoin {
+                chain,
+                address,
+                signature,
+                header,
+                message,
+            } => {
+                let pubkey =
+                    secp256k1_recover_signer(signature, &parse_bip137_header(*header)?, message)?;
+                address.check_bip137_signer(&pubkey, *header)?;
+                BitcoinMessage::check_hashed_payload(
+                    message,
+                    chain.get_message_prefix(),
+                    expected_payload,
+                )?;
+                Ok(Identity::Bitcoin {
+                    address: address.clone(),
+                })
+            }
+            IdentityCertificate::BitcoinBip322 {
+                signature,
+                recovery_id,
+                pubkey,
+                message,
+            } => {
+                secp256k1_verify_signer(signature, recovery_id, pubkey, message)?;
+                let witness_program = hash160(&pubkey.as_bytes());
+                Bip322Message::check_hashed_payload(message, &witness_program, expected_payload)?;
+                Ok(Identity::Bitcoin {
+                    address: BitcoinAddress::P2wpkh { witness_program },
+                })
+            }
+            IdentityCertificate::Near {
+                pubkey,
+                message,
+                verification_instruction_index,
+            } => {
+                let signature_verification_instruction = load_instruction_at_checked(
+                    *verification_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                message.check_hashed_payload(
+                    &Ed25519InstructionData::extract_message_and_check_signature(
+                        &signature_verification_instruction,
+                        pubkey,
+                        verification_instruction_index,
+                    )?,
+                    expected_payload,
+                )?;
+                Ok(Identity::Near {
+                    account_id: pubkey.clone().into(),
+                })
+            }
+            IdentityCertificate::NearNamed {
+                account_id,
+                pubkey,
+                message,
+                verification_instruction_index,
+                attestation_instruction_index,
+            } => {
+                let attestation_instruction = load_instruction_at_checked(
+                    *attestation_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                NearKeyLinkMessage::parse_and_check_account_id_and_pubkey(
+                    &Ed25519InstructionData::extract_message_and_check_signature(
+                        &attestation_instruction,
+                        &Ed25519Pubkey::from(*dispenser_guard),
+                        attestation_instruction_index,
+                    )?,
+                    account_id,
+                    pubkey,
+                )?;
+
+                let signature_verification_instruction = load_instruction_at_checked(
+                    *verification_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                message.check_hashed_payload(
+                    &Ed25519InstructionData::extract_message_and_check_signature(
+                        &signature_verification_instruction,
+                        pubkey,
+                        verification_instruction_index,
+                    )?,
+                    expected_payload,
+                )?;
+                Ok(Identity::Near {
+                    account_id: account_id.clone(),
+                })
+            }
+            IdentityCertificate::Tron {
+                pubkey,
+                verification_instruction_index,
+            } => {
+                let signature_verification_instruction = load_instruction_at_checked(
+                    *verification_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                check_payload(
+                    TronMessage::parse(
+                        &Secp256k1InstructionData::extract_message_and_check_signature(
+                            &signature_verification_instruction,
+                            pubkey,
+                            verification_instruction_index,
+                        )?,
+                    )?
+                    .get_payload(),
+                    expected_payload,
+                )?;
+                Ok(Identity::Tron { pubkey: *pubkey })
+            }
+            IdentityCertificate::Ton {
+                address,
+                pubkey,
+                message,
+                verification_instruction_index,
+                attestation_instruction_index,
+            } => {
+                let attestation_instruction = load_instruction_at_checked(
+                    *attestation_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                TonKeyLinkMessage::parse_and_check_address_and_pubkey(
+                    &Ed25519InstructionData::extract_message_and_check_signature(
+                        &attestation_instruction,
+                        &Ed25519Pubkey::from(*dispenser_guard),
+                        attestation_instruction_index,
+                    )?,
+                    address,
+                    pubkey,
+                )?;
+
+                let signature_verification_instruction = load_instruction_at_checked(
+                    *verification_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                message.check_hashed_payload(
+                    &Ed25519InstructionData::extract_message_and_check_signature(
+                        &signature_verification_instruction,
+                        pubkey,
+                        verification_instruction_index,
+                    )?,
+                    address,
+                    expected_payload,
+                )?;
+                Ok(Identity::Ton {
+                    address: address.clone(),
+                })
+            }
+            IdentityCertificate::Substrate {
+                network,
+                pubkey,
+                verification_instruction_index,
+            } => {
+                check_ss58_network(*network)?;
+                let signature_verification_instruction = load_instruction_at_checked(
+                    *verification_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                check_payload(
+                    SubstrateMessage::parse(
+                        &Ed25519InstructionData::extract_message_and_check_signature(
+                            &signature_verification_instruction,
+                            pubkey,
+                            verification_instruction_index,
+                        )?,
+                    )?
+                    .get_payload(),
+                    expected_payload,
+                )?;
+                Ok(Identity::Substrate {
+                    network: *network,
+                    pubkey:  pubkey.clone(),
+                })
+            }
+            IdentityCertificate::Cardano {
+                pubkey,
+                verification_instruction_index,
+            } => {
+                let signature_verification_instruction = load_instruction_at_checked(
+                    *verification_instruction_index as usize,
+                    sysvar_instruction,
+                )?;
+                let key_hash = CardanoKeyHash::from(pubkey.clone());
+                check_payload(
+                    CardanoMessage::parse(
+                        &Ed25519InstructionData::extract_message_and_check_signature(
+                            &signature_verification_instruction,
+                            pubkey,
+                            verification_instruction_index,
+                        )?,
+                        &key_hash,
+                    )?
+                    .get_payload(),
+                    expected_payload,
+                )?;
+                Ok(Identity::Cardano { key_hash })
+            }
         }
     }
 }
@@ -623,19 +1902,105 @@ impl ClaimCertificate {
         &self,
         sysvar_instruction: &AccountInfo,
         claimant: &Pubkey,
-        dispenser_guard: &Pubkey,
+        config: &Config,
     ) -> Result<ClaimInfo> {
+        let expected_payload = self.authorization_payload.checked_get_expected_payload(
+            claimant,
+            self.amount,
+            config,
+        )?;
         Ok(ClaimInfo {
             identity: self.proof_of_identity.checked_into_identity(
                 sysvar_instruction,
                 claimant,
-                dispenser_guard,
+                &config.dispenser_guard,
+                &expected_payload,
+                &config.cosmos_chain_ids,
             )?,
             amount:   self.amount,
         })
     }
 }
 
+impl ClaimVerification {
+    pub fn verify(
+        claim_certificate: &ClaimCertificate,
+        claimant: &Pubkey,
+        config: &Config,
+        sysvar_instruction: &AccountInfo,
+        remaining_accounts: &[AccountInfo],
+    ) -> Self {
+        let mut verification = ClaimVerification {
+            claim_info:     None,
+            receipt:        None,
+            claimed:        false,
+            failure_reason: None,
+        };
+        if let Err(error) = verification.run_checks(
+            claim_certificate,
+            claimant,
+            config,
+            sysvar_instruction,
+            remaining_accounts,
+        ) {
+            verification.failure_reason = Some(error.into());
+        }
+        verification
+    }
+
+    /**
+     * Mirrors the checks of `claim`, in the same order so that the first failure is the one
+     * `claim` would return.
+     */
+    fn run_checks(
+        &mut self,
+        claim_certificate: &ClaimCertificate,
+        claimant: &Pubkey,
+        config: &Config,
+        sysvar_instruction: &AccountInfo,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        claim_certificate
+            .proof_of_identity
+            .check_not_forbidden(claimant)?;
+
+        let claim_info =
+            claim_certificate.checked_into_claim_info(sysvar_instruction, claimant, config)?;
+        let leaf_vector = config.leaf_format.encode_leaf(&claim_info, &config.mint)?;
+        let receipt_pubkey = get_receipt_pda(&leaf_vector, config.merkle_root.hash_algorithm()).0;
+        let claim_receipt_account = remaining_accounts.first();
+
+        self.claimed = matches!(
+            claim_receipt_account,
+            Some(account) if account.key() == receipt_pubkey && account.owner.eq(&crate::id())
+        );
+        self.receipt = Some(receipt_pubkey);
+        self.claim_info = Some(claim_info.clone());
+
+        if !config
+            .merkle_root
+            .check(claim_certificate.proof_of_inclusion.clone(), &leaf_vector)
+        {
+            return err!(ErrorCode::InvalidInclusionProof);
+        };
+
+        let claim_receipt_account = claim_receipt_account.ok_or(ErrorCode::WrongPda)?;
+        require_keys_eq!(
+            claim_receipt_account.key(),
+            receipt_pubkey,
+            ErrorCode::WrongPda
+        );
+        check_claim_receipt_is_uninitialized(claim_receipt_account)?;
+
+        require_gte!(
+            config.max_transfer,
+            claim_info.amount,
+            ErrorCode::TransferExceedsMax
+        );
+        Ok(())
+    }
+}
+
 
 /**
  * Creates a claim receipt for the claimant. This is an account that contains no data. Each leaf
@@ -647,11 +2012,12 @@ impl ClaimCertificate {
 pub fn checked_create_claim_receipt<'info>(
     index: usize,
     leaf: &[u8],
+    hash_algorithm: HashAlgorithm,
     funder: &AccountInfo<'info>,
     system_program: &AccountInfo<'info>,
     remaining_accounts: &[AccountInfo<'info>],
 ) -> Result<()> {
-    let (receipt_pubkey, bump) = get_receipt_pda(leaf);
+    let (receipt_pubkey, bump) = get_receipt_pda(leaf, hash_algorithm);
 
 
     // The claim receipt accounts should appear in remaining accounts in the same order as the claim certificates
@@ -685,11 +2051,7 @@ pub fn checked_create_claim_receipt<'info>(
     invoke_signed(
         &assign_instruction,
         &account_infos,
-        &[&[
-            RECEIPT_SEED,
-            &MerkleTree::<SolanaHasher>::hash_leaf(leaf),
-            &[bump],
-        ]],
+        &[&[RECEIPT_SEED, &hash_algorithm.hash_leaf(leaf), &[bump]]],
     )
     .map_err(|_| ErrorCode::AlreadyClaimed)?;
 
@@ -697,6 +2059,35 @@ pub fn checked_create_claim_receipt<'info>(
 }
 
 
+/**
+ * Emits an event through a self-CPI to `log_event` signed by the event authority. Unlike program
+ * logs, which RPC nodes may truncate, the event can always be read by indexers from the data of
+ * the inner instruction : the `log_event` discriminator, the length of the event and the event
+ * serialized the same way `emit!` does (event discriminator followed by the borsh-serialized
+ * event).
+ */
+pub fn emit_cpi_event<'info, T: Event>(
+    event: &T,
+    event_authority: &AccountInfo<'info>,
+    event_authority_bump: u8,
+    program: &AccountInfo<'info>,
+) -> Result<()> {
+    let log_event_instruction = Instruction {
+        program_id: crate::id(),
+        accounts:   vec![AccountMeta::new_readonly(event_authority.key(), true)],
+        data:       crate::instruction::LogEvent {
+            _event: event.data(),
+        }
+        .data(),
+    };
+    invoke_signed(
+        &log_event_instruction,
+        &[event_authority.clone(), program.clone()],
+        &[&[EVENT_AUTHORITY_SEED, &[event_authority_bump]]],
+    )?;
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Sdk.
 ////////////////////////////////////////////////////////////////////////////////
@@ -705,9 +2096,17 @@ pub fn get_config_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[CONFIG_SEED], &crate::id())
 }
 
-pub fn get_receipt_pda(leaf: &[u8]) -> (Pubkey, u8) {
+pub fn get_claim_stats_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CLAIM_STATS_SEED], &crate::id())
+}
+
+pub fn get_event_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EVENT_AUTHORITY_SEED], &crate::id())
+}
+
+pub fn get_receipt_pda(leaf: &[u8], hash_algorithm: HashAlgorithm) -> (Pubkey, u8) {
     Pubkey::find_program_address(
-        &[RECEIPT_SEED, &MerkleTree::<SolanaHasher>::hash_leaf(leaf)],
+        &[RECEIPT_SEED, &hash_algorithm.hash_leaf(leaf)],
         &crate::id(),
     )
 }
@@ -717,9 +2116,12 @@ impl crate::accounts::Initialize {
         crate::accounts::Initialize {
             payer,
             config: get_config_pda().0,
+            claim_stats: get_claim_stats_pda().0,
             mint,
             system_program: system_program::System::id(),
             address_lookup_table,
+            event_authority: get_event_authority_pda().0,
+            program: crate::id(),
         }
     }
 }
@@ -737,12 +2139,36 @@ impl crate::accounts::Claim {
             claimant,
             claimant_fund,
             config: get_config_pda().0,
+            claim_stats: get_claim_stats_pda().0,
             mint,
             treasury,
             token_program: spl_token::id(),
             system_program: system_program::System::id(),
             sysvar_instruction: SYSVAR_IX_ID,
             associated_token_program: associated_token::ID,
+            event_authority: get_event_authority_pda().0,
+            program: crate::id(),
+        }
+    }
+}
+
+impl crate::accounts::SetCosmosChainIds {
+    pub fn populate(admin: Pubkey) -> Self {
+        crate::accounts::SetCosmosChainIds {
+            admin,
+            config: get_config_pda().0,
+            event_authority: get_event_authority_pda().0,
+            program: crate::id(),
+        }
+    }
+}
+
+impl crate::accounts::VerifyClaim {
+    pub fn populate(claimant: Pubkey) -> Self {
+        crate::accounts::VerifyClaim {
+            claimant,
+            config: get_config_pda().0,
+            sysvar_instruction: SYSVAR_IX_ID,
         }
     }
 }
@@ -758,4 +2184,24 @@ pub struct ClaimEvent {
     pub remaining_balance: u64,
     pub claimant:          Pubkey,
     pub claim_info:        ClaimInfo,
+    pub ecosystem:         Ecosystem,
+    pub leaf_hash:         Vec<u8>,
+    pub receipt:           Pubkey,
+    pub funder:            Pubkey,
+    pub slot:              u64,
+}
+
+#[event]
+pub struct InitializeEvent {
+    pub config: Config,
+    pub payer:  Pubkey,
+    pub slot:   u64,
+}
+
+#[event]
+pub struct CosmosChainIdsUpdatedEvent {
+    pub admin:                Pubkey,
+    pub old_cosmos_chain_ids: Vec<String>,
+    pub new_cosmos_chain_ids: Vec<String>,
+    pub slot:                 u64,
 }
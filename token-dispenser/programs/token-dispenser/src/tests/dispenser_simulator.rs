@@ -2,17 +2,25 @@ use {
     super::test_happy_path::TestClaimCertificate,
     crate::{
         accounts,
+        ecosystems::DEFAULT_AUTHORIZATION_TEMPLATE,
+        get_claim_stats_pda,
         get_config_pda,
+        get_event_authority_pda,
         get_receipt_pda,
         instruction,
         tests::merkleize,
+        ClaimHasher,
         ClaimInfo,
+        ClaimVerification,
+        Config,
         ErrorCode,
+        LeafFormat,
         SolanaHasher,
     },
     anchor_lang::{
         prelude::{
             AccountMeta,
+            Clock,
             ProgramError,
             Pubkey,
             Rent,
@@ -29,7 +37,7 @@ use {
         },
         system_program,
         AccountDeserialize,
-        AnchorSerialize,
+        AnchorDeserialize,
         Id,
         InstructionData,
         ToAccountMetas,
@@ -51,20 +59,26 @@ use {
             TokenAccount,
         },
     },
-    pythnet_sdk::accumulators::merkle::{
-        MerklePath,
-        MerkleRoot,
-        MerkleTree,
+    cpi_caller::get_vault_pda,
+    pythnet_sdk::accumulators::{
+        merkle::{
+            MerklePath,
+            MerkleRoot,
+            MerkleTree,
+        },
+        Accumulator,
     },
     solana_program_test::{
         BanksClient,
         BanksClientError,
         ProgramTest,
         ProgramTestBanksClientExt,
+        ProgramTestContext,
     },
     solana_sdk::{
         account::{
             Account,
+            AccountSharedData,
             ReadableAccount,
         },
         instruction::InstructionError,
@@ -79,30 +93,45 @@ use {
 };
 
 pub struct DispenserSimulator {
-    banks_client:            BanksClient,
-    pub genesis_keypair:     Keypair,
-    recent_blockhash:        hash::Hash,
-    pub mint_keypair:        Keypair,
+    context:                    ProgramTestContext,
+    banks_client:               BanksClient,
+    pub genesis_keypair:        Keypair,
+    recent_blockhash:           hash::Hash,
+    pub mint_keypair:           Keypair,
     /// also the owner/authority of `pyth_treasury`
-    pub pyth_mint_authority: Keypair,
-    pub pyth_treasury:       Pubkey,
+    pub pyth_mint_authority:    Keypair,
+    pub pyth_treasury:          Pubkey,
+    /// stands in for the genesis hash of the cluster in the config
+    pub genesis_hash:           [u8; 32],
+    /// template set in the config by `initialize`
+    pub authorization_template: String,
+    /// minimum version of the authorization payload set in the config by `initialize`
+    pub min_payload_version:    u8,
 }
 
 impl DispenserSimulator {
     pub async fn new() -> Self {
         let mut program_test = ProgramTest::new("token_dispenser", crate::id(), None);
+        program_test.add_program("cpi_caller", cpi_caller::id(), None);
         program_test.set_compute_max_units(400000);
-        let (banks_client, genesis_keypair, recent_blockhash) = program_test.start().await;
+        let context = program_test.start_with_context().await;
+        let banks_client = context.banks_client.clone();
+        let genesis_keypair = copy_keypair(&context.payer);
+        let recent_blockhash = context.last_blockhash;
         let mint_keypair = Keypair::new();
         let pyth_mint_authority = Keypair::new();
         let pyth_treasury = Keypair::new();
         let mut simulator = DispenserSimulator {
+            context,
             banks_client,
             genesis_keypair,
             recent_blockhash,
             mint_keypair,
             pyth_mint_authority,
             pyth_treasury: pyth_treasury.pubkey(),
+            genesis_hash: hash::Hash::new_unique().to_bytes(),
+            authorization_template: DEFAULT_AUTHORIZATION_TEMPLATE.to_string(),
+            min_payload_version: 1,
         };
 
         simulator
@@ -138,11 +167,11 @@ impl DispenserSimulator {
                     claimant,
                     libsecp256k1::SecretKey::parse(&[
                         //secret key of 0xd3E739d874789CB4545dD745eb391BE54A5505e2
-                        0x37, 0x06, 0x41, 0xae, 0xc0, 0xcb, 0x42, 0x2f,
-                        0x36, 0x5d, 0x33, 0xe6, 0xc6, 0x1a, 0xdd, 0x34,
-                        0x3b, 0xa1, 0x55, 0x7e, 0xcb, 0xe2, 0x86, 0x17,
-                        0x8e, 0xa0, 0xb0, 0x05, 0x34, 0x87, 0x40, 0x04
-                    ]).unwrap()
+                        0x37, 0x06, 0x41, 0xae, 0xc0, 0xcb, 0x42, 0x2f, 0x36, 0x5d, 0x33, 0xe6,
+                        0xc6, 0x1a, 0xdd, 0x34, 0x3b, 0xa1, 0x55, 0x7e, 0xcb, 0xe2, 0x86, 0x17,
+                        0x8e, 0xa0, 0xb0, 0x05, 0x34, 0x87, 0x40, 0x04,
+                    ])
+                    .unwrap(),
                 )
             } else {
                 TestClaimCertificate::random_evm(claimant)
@@ -157,6 +186,12 @@ impl DispenserSimulator {
     }
 
 
+    /// Overwrites an account, e.g. to recreate the state left by an older version of the program
+    pub fn set_account(&mut self, address: &Pubkey, account: Account) {
+        self.context
+            .set_account(address, &AccountSharedData::from(account));
+    }
+
     pub async fn get_rent(&mut self) -> Rent {
         self.banks_client.get_rent().await.unwrap()
     }
@@ -250,6 +285,87 @@ impl DispenserSimulator {
         self.banks_client.process_transaction(transaction).await
     }
 
+    /// Processes the instructions and returns the program logs of the transaction
+    pub async fn process_ix_with_logs(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &Vec<&Keypair>,
+    ) -> Result<Vec<String>, BanksClientError> {
+        let mut transaction =
+            Transaction::new_with_payer(instructions, Some(&self.genesis_keypair.pubkey()));
+
+        let blockhash = self
+            .banks_client
+            .get_new_latest_blockhash(&self.recent_blockhash)
+            .await
+            .unwrap();
+        self.recent_blockhash = blockhash;
+
+        transaction.partial_sign(&[&self.genesis_keypair], self.recent_blockhash);
+        transaction.partial_sign(signers, self.recent_blockhash);
+        let result = self
+            .banks_client
+            .process_transaction_with_metadata(transaction)
+            .await?;
+        result.result?;
+        Ok(result
+            .metadata
+            .map(|metadata| metadata.log_messages)
+            .unwrap_or_default())
+    }
+
+    /// Simulates the instructions and returns the data set by the last instruction with
+    /// `set_return_data`
+    pub async fn simulate_ix(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &Vec<&Keypair>,
+    ) -> Result<Vec<u8>, BanksClientError> {
+        let transaction = self.sign_for_simulation(instructions, signers).await;
+        let simulation = self.banks_client.simulate_transaction(transaction).await?;
+        simulation.result.unwrap()?;
+        Ok(simulation
+            .simulation_details
+            .and_then(|details| details.return_data)
+            .map(|return_data| return_data.data)
+            .unwrap_or_default())
+    }
+
+    /// Simulates the instructions and returns the compute units they consumed
+    pub async fn simulate_compute_units(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &Vec<&Keypair>,
+    ) -> Result<u64, BanksClientError> {
+        let transaction = self.sign_for_simulation(instructions, signers).await;
+        let simulation = self.banks_client.simulate_transaction(transaction).await?;
+        simulation.result.unwrap()?;
+        Ok(simulation
+            .simulation_details
+            .map(|details| details.units_consumed)
+            .unwrap_or_default())
+    }
+
+    async fn sign_for_simulation(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &Vec<&Keypair>,
+    ) -> Transaction {
+        let mut transaction =
+            Transaction::new_with_payer(instructions, Some(&self.genesis_keypair.pubkey()));
+
+        let blockhash = self
+            .banks_client
+            .get_new_latest_blockhash(&self.recent_blockhash)
+            .await
+            .unwrap();
+        self.recent_blockhash = blockhash;
+
+        transaction.partial_sign(&[&self.genesis_keypair], self.recent_blockhash);
+        transaction.partial_sign(signers, self.recent_blockhash);
+        transaction
+    }
+
     pub async fn init_lookup_table(&mut self) -> Result<Pubkey, BanksClientError> {
         let recent_slot = self
             .banks_client
@@ -279,6 +395,9 @@ impl DispenserSimulator {
                 system_program::System::id(),
                 SYSVAR_IX_ID,
                 spl_associated_token_account::id(),
+                get_event_authority_pda().0,
+                get_claim_stats_pda().0,
+                crate::id(),
             ],
         );
 
@@ -288,13 +407,14 @@ impl DispenserSimulator {
         Ok(address_lookup_table)
     }
 
-    pub async fn initialize(
+    pub async fn initialize<H: ClaimHasher>(
         &mut self,
-        merkle_root: MerkleRoot<SolanaHasher>,
+        merkle_root: MerkleRoot<H>,
         dispenser_guard: Pubkey,
         address_lookup_table: Pubkey,
         mint_pubkey_override: Option<Pubkey>,
         max_transfer_override: Option<u64>,
+        leaf_format_override: Option<LeafFormat>,
     ) -> Result<(), BanksClientError> {
         let accounts = accounts::Initialize::populate(
             self.genesis_keypair.pubkey(),
@@ -303,15 +423,31 @@ impl DispenserSimulator {
         )
         .to_account_metas(None);
         let instruction_data = instruction::Initialize {
-            merkle_root,
+            merkle_root: merkle_root.into(),
             dispenser_guard,
             max_transfer: max_transfer_override.unwrap_or(u64::MAX),
+            leaf_format: leaf_format_override.unwrap_or(LeafFormat::V1),
+            genesis_hash: self.genesis_hash,
+            min_payload_version: self.min_payload_version,
+            authorization_template: self.authorization_template.clone(),
         };
         let instruction =
             Instruction::new_with_bytes(crate::id(), &instruction_data.data(), accounts);
         self.process_ix(&[instruction], &vec![]).await
     }
 
+    pub async fn set_cosmos_chain_ids(
+        &mut self,
+        admin: &Keypair,
+        cosmos_chain_ids: Vec<String>,
+    ) -> Result<(), BanksClientError> {
+        let accounts = accounts::SetCosmosChainIds::populate(admin.pubkey()).to_account_metas(None);
+        let instruction_data = instruction::SetCosmosChainIds { cosmos_chain_ids };
+        let instruction =
+            Instruction::new_with_bytes(crate::id(), &instruction_data.data(), accounts);
+        self.process_ix(&[instruction], &vec![admin]).await
+    }
+
 
     pub async fn initialize_with_claimants(
         &mut self,
@@ -337,7 +473,7 @@ impl DispenserSimulator {
                 let test_claim_certs = DispenserSimulator::generate_test_claim_certs(
                     &pubkey,
                     dispenser_guard,
-                    use_forbidden_evm
+                    use_forbidden_evm,
                 );
                 let amount = test_claim_certs.iter().map(|y| y.amount).sum::<u64>();
                 (c, test_claim_certs, amount)
@@ -363,6 +499,7 @@ impl DispenserSimulator {
             address_lookup_table,
             None,
             max_transfer_override,
+            None,
         )
         .await?;
 
@@ -420,52 +557,67 @@ impl DispenserSimulator {
     // Note: Not using versioned transaction here since
     // `BanksClient` doesn't support sending them and
     // it's already tested in the typescript tests
-    pub async fn claim(
+    pub async fn claim<H: ClaimHasher>(
         &mut self,
         claimant: &Keypair,
         off_chain_claim_certificate: &TestClaimCertificate,
-        merkle_tree: &MerkleTree<SolanaHasher>,
+        merkle_tree: &MerkleTree<H>,
         claimant_fund: Option<Pubkey>,
-        merkle_proof_override: Option<MerklePath<SolanaHasher>>,
+        merkle_proof_override: Option<MerklePath<H>>,
         claim_receipt_override: Option<Pubkey>,
     ) -> Result<(), BanksClientError> {
-        let (claim_certificate, option_instruction) =
-            off_chain_claim_certificate.as_claim_certificate(merkle_tree, 0, merkle_proof_override);
+        let instructions = self
+            .claim_instructions(
+                &claimant.pubkey(),
+                off_chain_claim_certificate,
+                merkle_tree,
+                claimant_fund,
+                merkle_proof_override,
+                claim_receipt_override,
+            )
+            .await?;
+        self.process_ix(&instructions, &vec![claimant]).await
+    }
+
+    /// The signature verification instructions (if any) followed by the claim instruction
+    pub async fn claim_instructions<H: ClaimHasher>(
+        &mut self,
+        claimant: &Pubkey,
+        off_chain_claim_certificate: &TestClaimCertificate,
+        merkle_tree: &MerkleTree<H>,
+        claimant_fund: Option<Pubkey>,
+        merkle_proof_override: Option<MerklePath<H>>,
+        claim_receipt_override: Option<Pubkey>,
+    ) -> Result<Vec<Instruction>, BanksClientError> {
         let config = self
             .get_account_data::<crate::Config>(get_config_pda().0)
             .await
             .unwrap();
+        let leaf = get_leaf(&config, off_chain_claim_certificate);
+        let (claim_certificate, verification_instructions) = off_chain_claim_certificate
+            .as_claim_certificate(
+                merkle_tree,
+                0,
+                merkle_proof_override.or_else(|| merkle_tree.prove(&leaf)),
+            );
         let mut accounts = accounts::Claim::populate(
             self.genesis_keypair.pubkey(),
-            claimant.pubkey(),
+            *claimant,
             config.mint,
-            claimant_fund
-                .unwrap_or_else(|| get_associated_token_address(&claimant.pubkey(), &config.mint)),
+            claimant_fund.unwrap_or_else(|| get_associated_token_address(claimant, &config.mint)),
             self.pyth_treasury,
         )
         .to_account_metas(None);
 
         accounts.push(AccountMeta::new(
-            claim_receipt_override.unwrap_or(
-                get_receipt_pda(
-                    &<TestClaimCertificate as Into<ClaimInfo>>::into(
-                        off_chain_claim_certificate.clone(),
-                    )
-                    .try_to_vec()?,
-                )
-                .0,
-            ),
+            claim_receipt_override.unwrap_or(get_receipt_pda(&leaf, H::HASH_ALGORITHM).0),
             false,
         ));
 
 
         let instruction_data: instruction::Claim = instruction::Claim { claim_certificate };
 
-        let mut instructions = vec![];
-
-        if let Some(verification_instruction) = option_instruction {
-            instructions.push(verification_instruction);
-        }
+        let mut instructions = verification_instructions;
 
         instructions.push(Instruction::new_with_bytes(
             crate::id(),
@@ -473,14 +625,104 @@ impl DispenserSimulator {
             accounts,
         ));
 
+        Ok(instructions)
+    }
 
-        self.process_ix(&instructions, &vec![claimant]).await
+    /// Claims for the `cpi-caller` vault PDA by calling the `cpi-caller` program, which
+    /// forwards the claim to the token dispenser with the vault signing as the claimant.
+    pub async fn claim_via_cpi<H: ClaimHasher>(
+        &mut self,
+        off_chain_claim_certificate: &TestClaimCertificate,
+        merkle_tree: &MerkleTree<H>,
+    ) -> Result<(), BanksClientError> {
+        let vault = get_vault_pda().0;
+        let config = self
+            .get_account_data::<crate::Config>(get_config_pda().0)
+            .await
+            .unwrap();
+        let leaf = get_leaf(&config, off_chain_claim_certificate);
+        let (claim_certificate, verification_instructions) = off_chain_claim_certificate
+            .as_claim_certificate(merkle_tree, 0, merkle_tree.prove(&leaf));
+
+        // The cpi caller expects the same accounts as the token dispenser, the last one being the
+        // token dispenser program itself
+        let mut accounts = accounts::Claim::populate(
+            self.genesis_keypair.pubkey(),
+            vault,
+            config.mint,
+            get_associated_token_address(&vault, &config.mint),
+            self.pyth_treasury,
+        )
+        .to_account_metas(None);
+        // The vault only becomes a signer in the CPI
+        accounts[1].is_signer = false;
+        accounts.push(AccountMeta::new(
+            get_receipt_pda(&leaf, H::HASH_ALGORITHM).0,
+            false,
+        ));
+
+        // `cpi_caller::claim` takes the same arguments as `token_dispenser::claim`, so the
+        // instruction data is identical
+        let instruction_data: instruction::Claim = instruction::Claim { claim_certificate };
+
+        let mut instructions = verification_instructions;
+
+        instructions.push(Instruction::new_with_bytes(
+            cpi_caller::id(),
+            &instruction_data.data(),
+            accounts,
+        ));
+
+        self.process_ix(&instructions, &vec![]).await
+    }
+
+    pub async fn verify_claim<H: ClaimHasher>(
+        &mut self,
+        claimant: &Pubkey,
+        off_chain_claim_certificate: &TestClaimCertificate,
+        merkle_tree: &MerkleTree<H>,
+        merkle_proof_override: Option<MerklePath<H>>,
+    ) -> Result<ClaimVerification, BanksClientError> {
+        let config = self
+            .get_account_data::<crate::Config>(get_config_pda().0)
+            .await
+            .unwrap();
+        let leaf = get_leaf(&config, off_chain_claim_certificate);
+        let (claim_certificate, verification_instructions) = off_chain_claim_certificate
+            .as_claim_certificate(
+                merkle_tree,
+                0,
+                merkle_proof_override.or_else(|| merkle_tree.prove(&leaf)),
+            );
+        let mut accounts = accounts::VerifyClaim::populate(*claimant).to_account_metas(None);
+
+        accounts.push(AccountMeta::new_readonly(
+            get_receipt_pda(&leaf, H::HASH_ALGORITHM).0,
+            false,
+        ));
+
+        let instruction_data = instruction::VerifyClaim { claim_certificate };
+
+        let mut instructions = verification_instructions;
+
+        instructions.push(Instruction::new_with_bytes(
+            crate::id(),
+            &instruction_data.data(),
+            accounts,
+        ));
+
+        let return_data = self.simulate_ix(&instructions, &vec![]).await?;
+        Ok(ClaimVerification::try_from_slice(&return_data)?)
     }
 
     pub async fn get_account(&mut self, key: Pubkey) -> Option<Account> {
         self.banks_client.get_account(key).await.ok()?
     }
 
+    pub async fn get_clock(&mut self) -> Clock {
+        self.banks_client.get_sysvar::<Clock>().await.unwrap()
+    }
+
     pub async fn get_account_data<T: AccountDeserialize>(&mut self, cart_key: Pubkey) -> Option<T> {
         self.get_account(cart_key)
             .await
@@ -550,6 +792,14 @@ impl DispenserSimulator {
     }
 }
 
+/// The leaf of a claim certificate, encoded with the leaf format of the config
+pub fn get_leaf(config: &Config, off_chain_claim_certificate: &TestClaimCertificate) -> Vec<u8> {
+    config
+        .leaf_format
+        .encode_leaf(&off_chain_claim_certificate.clone().into(), &config.mint)
+        .unwrap()
+}
+
 pub fn copy_keypair(keypair: &Keypair) -> Keypair {
     Keypair::from_bytes(&keypair.to_bytes()).unwrap()
 }
@@ -1,32 +1,63 @@
 use {
     crate::{
+        ClaimHasher,
         ClaimInfo,
-        SolanaHasher,
+        LeafFormat,
     },
-    anchor_lang::AnchorSerialize,
+    anchor_lang::prelude::Pubkey,
     pythnet_sdk::accumulators::merkle::MerkleTree,
 };
 
 mod dispenser_simulator;
+mod test_algorand;
 mod test_aptos;
+mod test_authorization_payload;
+mod test_bitcoin;
+mod test_cardano;
 mod test_claim;
+mod test_claim_stats;
 mod test_cosmos;
+mod test_cosmos_chain_ids;
+mod test_cosmos_multisig;
+mod test_cpi;
 mod test_discord;
 mod test_ed25519;
+mod test_eip712;
+mod test_ethermint;
+mod test_events;
 mod test_happy_path;
+mod test_hash_algorithm;
 mod test_initialize;
+mod test_leaf_format;
 mod test_merkle_tree;
+mod test_near;
 mod test_secp256k1;
+mod test_siwe;
 mod test_solana;
+mod test_substrate;
+mod test_sui;
+mod test_ton;
+mod test_tron;
+mod test_verify_claim;
 
-/// Merkleizes a vector of `ClaimInfo`s and returns the `MerkleTree` and the serialized `ClaimInfo`s.
-pub fn merkleize(merkle_items: Vec<ClaimInfo>) -> (MerkleTree<SolanaHasher>, Vec<Vec<u8>>) {
+/// Merkleizes a vector of `ClaimInfo`s with the hasher `H` and returns the `MerkleTree` and the
+/// serialized `ClaimInfo`s.
+pub fn merkleize<H: ClaimHasher>(merkle_items: Vec<ClaimInfo>) -> (MerkleTree<H>, Vec<Vec<u8>>) {
+    merkleize_with_leaf_format(merkle_items, &LeafFormat::V1, &Pubkey::default())
+}
+
+/// Same as `merkleize` but the `ClaimInfo`s are encoded with `leaf_format`.
+pub fn merkleize_with_leaf_format<H: ClaimHasher>(
+    merkle_items: Vec<ClaimInfo>,
+    leaf_format: &LeafFormat,
+    mint: &Pubkey,
+) -> (MerkleTree<H>, Vec<Vec<u8>>) {
     let merkle_items_serialized = merkle_items
         .iter()
-        .map(|item| item.try_to_vec().unwrap())
+        .map(|item| leaf_format.encode_leaf(item, mint).unwrap())
         .collect::<Vec<Vec<u8>>>();
 
-    let merkle_tree: MerkleTree<SolanaHasher> = MerkleTree::new(
+    let merkle_tree: MerkleTree<H> = MerkleTree::new(
         merkle_items_serialized
             .iter()
             .map(|item| item.as_slice())
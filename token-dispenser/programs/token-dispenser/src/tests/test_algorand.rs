@@ -0,0 +1,347 @@
+use {
+    super::{
+        dispenser_simulator::{
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_ed25519::Ed25519TestIdentityCertificate,
+        test_happy_path::{
+            TestClaimCertificate,
+            TestIdentityCertificate,
+        },
+    },
+    crate::{
+        ecosystems::{
+            algorand::{
+                AlgorandAddress,
+                AlgorandMessage,
+                AlgorandMultisigPubkey,
+                AlgorandMultisigSigner,
+                AlgorandRekeyMessage,
+                ALGORAND_MULTISIG_VERSION,
+            },
+            aptos::{
+                AptosAddress,
+                AptosKeyRotationMessage,
+            },
+            ed25519::{
+                Ed25519Pubkey,
+                Ed25519TestClaimantMessage,
+                Ed25519TestMessage,
+            },
+            AuthorizationPayload,
+        },
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        Identity,
+        IdentityCertificate,
+        SolanaHasher,
+    },
+    anchor_lang::{
+        error,
+        prelude::Pubkey,
+    },
+    ed25519_dalek::{
+        Keypair,
+        Signer,
+    },
+    pythnet_sdk::accumulators::merkle::MerkleTree,
+    rand_compatible::rngs::OsRng,
+    solana_program_test::tokio,
+    solana_sdk::instruction::Instruction,
+};
+
+/**
+ * The subsigs `signers` of an Algorand multisig sign the same Algorand message.
+ */
+#[derive(Clone)]
+pub struct AlgorandMultisigTestIdentityCertificate {
+    pub multisig:   AlgorandMultisigPubkey,
+    pub signatures: Vec<(u8, Ed25519TestIdentityCertificate<AlgorandMessage>)>,
+}
+
+impl AlgorandMultisigTestIdentityCertificate {
+    pub fn random(claimant: &Pubkey, threshold: u8, subsigs: usize, signers: &[u8]) -> Self {
+        let message = AlgorandMessage::for_claimant(claimant);
+        let keypairs: Vec<Keypair> = (0..subsigs)
+            .map(|_| Keypair::generate(&mut OsRng {}))
+            .collect();
+        Self {
+            multisig:   AlgorandMultisigPubkey {
+                version: ALGORAND_MULTISIG_VERSION,
+                threshold,
+                subsigs: keypairs
+                    .iter()
+                    .map(|keypair| Ed25519Pubkey::from(keypair.public.to_bytes()))
+                    .collect(),
+            },
+            signatures: signers
+                .iter()
+                .map(|member_index| {
+                    let keypair = &keypairs[*member_index as usize];
+                    (
+                        *member_index,
+                        Ed25519TestIdentityCertificate {
+                            signature:  keypair.sign(&message.get_message_with_metadata()),
+                            public_key: keypair.public,
+                            message:    message.clone(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn as_instructions(&self, instruction_index: u8) -> Vec<Instruction> {
+        self.signatures
+            .iter()
+            .enumerate()
+            .map(|(i, (_, signature))| signature.as_instruction(instruction_index + i as u8, true))
+            .collect()
+    }
+
+    pub fn as_proof_of_identity(&self, verification_instruction_index: u8) -> IdentityCertificate {
+        IdentityCertificate::AlgorandMultisig {
+            multisig: self.multisig.clone(),
+            signers:  self
+                .signatures
+                .iter()
+                .enumerate()
+                .map(|(i, (member_index, _))| AlgorandMultisigSigner {
+                    member_index:                   *member_index,
+                    verification_instruction_index: verification_instruction_index + i as u8,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<AlgorandMultisigTestIdentityCertificate> for Identity {
+    fn from(val: AlgorandMultisigTestIdentityCertificate) -> Self {
+        Identity::Algorand {
+            address: val.multisig.get_address(),
+        }
+    }
+}
+
+/**
+ * An Algorand account `address` whose authorized key signs the Algorand message, and the
+ * attestation of the dispenser guard linking that key to `address`.
+ */
+#[derive(Clone)]
+pub struct AlgorandRekeyedTestIdentityCertificate {
+    pub address:     AlgorandAddress,
+    pub signature:   Ed25519TestIdentityCertificate<AlgorandMessage>,
+    pub attestation: Ed25519TestIdentityCertificate<AlgorandRekeyMessage>,
+}
+
+impl AlgorandRekeyedTestIdentityCertificate {
+    pub fn random(claimant: &Pubkey, dispenser_guard: &Keypair) -> Self {
+        let signature = Ed25519TestIdentityCertificate::<AlgorandMessage>::random(claimant);
+        let address = AlgorandAddress::from(rand::random::<[u8; AlgorandAddress::LEN]>());
+        let message = AlgorandRekeyMessage::new(
+            address.clone(),
+            Ed25519Pubkey::from(signature.public_key.to_bytes()),
+        );
+        Self {
+            address,
+            signature,
+            attestation: Ed25519TestIdentityCertificate {
+                signature: dispenser_guard.sign(&message.get_message_with_metadata()),
+                public_key: dispenser_guard.public,
+                message,
+            },
+        }
+    }
+
+    pub fn as_instructions(&self, instruction_index: u8) -> Vec<Instruction> {
+        vec![
+            self.signature.as_instruction(instruction_index, true),
+            self.attestation.as_instruction(instruction_index + 1, true),
+        ]
+    }
+
+    pub fn as_proof_of_identity(&self, verification_instruction_index: u8) -> IdentityCertificate {
+        IdentityCertificate::AlgorandRekeyed {
+            address: self.address.clone(),
+            pubkey: self.signature.public_key.to_bytes().into(),
+            verification_instruction_index,
+            attestation_instruction_index: verification_instruction_index + 1,
+        }
+    }
+}
+
+impl From<AlgorandRekeyedTestIdentityCertificate> for Identity {
+    fn from(val: AlgorandRekeyedTestIdentityCertificate) -> Self {
+        Identity::Algorand {
+            address: val.address,
+        }
+    }
+}
+
+async fn setup(
+    simulator: &mut DispenserSimulator,
+    certificates: &[TestClaimCertificate],
+    dispenser_guard: &Keypair,
+) -> MerkleTree<SolanaHasher> {
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Pubkey::new_from_array(dispenser_guard.public.to_bytes()),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+    merkle_tree
+}
+
+fn multisig_certificate(
+    claimant: &Pubkey,
+    threshold: u8,
+    subsigs: usize,
+    signers: &[u8],
+) -> TestClaimCertificate {
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity: TestIdentityCertificate::AlgorandMultisig(
+            AlgorandMultisigTestIdentityCertificate::random(claimant, threshold, subsigs, signers),
+        ),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
+fn rekeyed_certificate(claimant: &Pubkey, dispenser_guard: &Keypair) -> TestClaimCertificate {
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity: TestIdentityCertificate::AlgorandRekeyed(
+            AlgorandRekeyedTestIdentityCertificate::random(claimant, dispenser_guard),
+        ),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
+#[test]
+pub fn test_algorand_rekey_message() {
+    let address = AlgorandAddress::from([1; AlgorandAddress::LEN]);
+    let pubkey = Ed25519Pubkey::from([2; Ed25519Pubkey::LEN]);
+    let message = AlgorandRekeyMessage::new(address.clone(), pubkey.clone());
+    assert!(AlgorandRekeyMessage::parse_and_check_address_and_pubkey(
+        &message.get_message_with_metadata(),
+        &address,
+        &pubkey
+    )
+    .is_ok());
+
+    // An Aptos key rotation attestation has the same borsh layout
+    let aptos_message =
+        AptosKeyRotationMessage::new(AptosAddress::from([1; AptosAddress::LEN]), pubkey.clone());
+    assert_eq!(
+        AlgorandRekeyMessage::parse_and_check_address_and_pubkey(
+            &aptos_message.get_message_with_metadata(),
+            &address,
+            &pubkey
+        )
+        .unwrap_err(),
+        error!(ErrorCode::SignatureVerificationWrongPayload)
+    );
+}
+
+#[tokio::test]
+pub async fn test_claim_with_algorand_accounts() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = solana_sdk::signature::Keypair::new();
+    let claimant_pubkey = solana_sdk::signer::Signer::pubkey(&claimant);
+    let dispenser_guard = Keypair::generate(&mut OsRng {});
+
+    let certificates = vec![
+        TestClaimCertificate::random_algorand(&claimant_pubkey),
+        multisig_certificate(&claimant_pubkey, 2, 3, &[0, 2]),
+        multisig_certificate(&claimant_pubkey, 1, 1, &[0]),
+        rekeyed_certificate(&claimant_pubkey, &dispenser_guard),
+    ];
+    let merkle_tree = setup(&mut simulator, &certificates, &dispenser_guard).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_invalid_algorand_multisig_or_rekeyed_accounts() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = solana_sdk::signature::Keypair::new();
+    let claimant_pubkey = solana_sdk::signer::Signer::pubkey(&claimant);
+    let dispenser_guard = Keypair::generate(&mut OsRng {});
+
+    let mut certificates_and_errors: Vec<(TestClaimCertificate, ErrorCode)> = vec![
+        (
+            multisig_certificate(&claimant_pubkey, 2, 3, &[1]),
+            ErrorCode::InvalidAlgorandMultisig,
+        ),
+        (
+            multisig_certificate(&claimant_pubkey, 2, 3, &[1, 1]),
+            ErrorCode::InvalidAlgorandMultisig,
+        ),
+        (
+            multisig_certificate(&claimant_pubkey, 1, 2, &[0]),
+            ErrorCode::InvalidAlgorandMultisig,
+        ),
+        (
+            multisig_certificate(&Pubkey::new_unique(), 2, 3, &[0, 1]),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            rekeyed_certificate(&claimant_pubkey, &Keypair::generate(&mut OsRng {})),
+            ErrorCode::SignatureVerificationWrongSigner,
+        ),
+        (
+            rekeyed_certificate(&claimant_pubkey, &dispenser_guard),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+    ];
+    // only version 1 multisigs exist
+    if let TestIdentityCertificate::AlgorandMultisig(multisig) =
+        &mut certificates_and_errors[2].0.off_chain_proof_of_identity
+    {
+        multisig.multisig.version = 2;
+    }
+    // the attestation is for another account
+    if let TestIdentityCertificate::AlgorandRekeyed(rekeyed) =
+        &mut certificates_and_errors[5].0.off_chain_proof_of_identity
+    {
+        rekeyed.address = AlgorandAddress::from([1; AlgorandAddress::LEN]);
+    }
+    let certificates: Vec<TestClaimCertificate> = certificates_and_errors
+        .iter()
+        .map(|(certificate, _)| certificate.clone())
+        .collect();
+    let merkle_tree = setup(&mut simulator, &certificates, &dispenser_guard).await;
+
+    for (certificate, error) in &certificates_and_errors {
+        let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            (*error).into_transaction_error(ix_index_error)
+        );
+    }
+}
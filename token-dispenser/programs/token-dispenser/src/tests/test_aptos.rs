@@ -1,20 +1,453 @@
 use {
-    crate::ecosystems::{
-        aptos::AptosMessage,
-        ed25519::Ed25519TestMessage,
-        get_expected_payload,
+    super::{
+        dispenser_simulator::{
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_ed25519::Ed25519TestIdentityCertificate,
+        test_happy_path::{
+            TestClaimCertificate,
+            TestIdentityCertificate,
+        },
     },
-    anchor_lang::prelude::Pubkey,
+    crate::{
+        ecosystems::{
+            algorand::{
+                AlgorandAddress,
+                AlgorandRekeyMessage,
+            },
+            aptos::{
+                AptosAddress,
+                AptosKeyRotationMessage,
+                AptosMessage,
+                AptosMultiEd25519Pubkey,
+                AptosMultiEd25519Signer,
+            },
+            ed25519::{
+                Ed25519Pubkey,
+                Ed25519TestClaimantMessage,
+                Ed25519TestMessage,
+            },
+            get_expected_payload,
+            AuthorizationPayload,
+            DEFAULT_AUTHORIZATION_TEMPLATE,
+        },
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        Identity,
+        IdentityCertificate,
+        SolanaHasher,
+    },
+    anchor_lang::{
+        error,
+        prelude::Pubkey,
+        AnchorSerialize,
+    },
+    ed25519_dalek::{
+        Keypair,
+        Signer,
+    },
+    pythnet_sdk::accumulators::merkle::MerkleTree,
+    rand_compatible::rngs::OsRng,
     solana_program_test::tokio,
+    solana_sdk::instruction::Instruction,
 };
 
+/**
+ * The members `signers` of a MultiEd25519 account sign the same Aptos message.
+ */
+#[derive(Clone)]
+pub struct AptosMultiEd25519TestIdentityCertificate {
+    pub multisig:   AptosMultiEd25519Pubkey,
+    pub signatures: Vec<(u8, Ed25519TestIdentityCertificate<AptosMessage>)>,
+}
+
+impl AptosMultiEd25519TestIdentityCertificate {
+    pub fn random(claimant: &Pubkey, threshold: u8, members: usize, signers: &[u8]) -> Self {
+        Self::random_with_messages(
+            threshold,
+            members,
+            signers
+                .iter()
+                .map(|member_index| (*member_index, AptosMessage::for_claimant(claimant)))
+                .collect(),
+        )
+    }
+
+    /// Each signer `member_index` signs its own message
+    pub fn random_with_messages(
+        threshold: u8,
+        members: usize,
+        messages: Vec<(u8, AptosMessage)>,
+    ) -> Self {
+        let keypairs: Vec<Keypair> = (0..members)
+            .map(|_| Keypair::generate(&mut OsRng {}))
+            .collect();
+        Self {
+            multisig:   AptosMultiEd25519Pubkey {
+                pubkeys: keypairs
+                    .iter()
+                    .map(|keypair| Ed25519Pubkey::from(keypair.public.to_bytes()))
+                    .collect(),
+                threshold,
+            },
+            signatures: messages
+                .into_iter()
+                .map(|(member_index, message)| {
+                    let keypair = &keypairs[member_index as usize];
+                    (
+                        member_index,
+                        Ed25519TestIdentityCertificate {
+                            signature: keypair.sign(&message.get_message_with_metadata()),
+                            public_key: keypair.public,
+                            message,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn as_instructions(&self, instruction_index: u8) -> Vec<Instruction> {
+        self.signatures
+            .iter()
+            .enumerate()
+            .map(|(i, (_, signature))| signature.as_instruction(instruction_index + i as u8, true))
+            .collect()
+    }
+
+    pub fn as_proof_of_identity(&self, verification_instruction_index: u8) -> IdentityCertificate {
+        IdentityCertificate::AptosMultiEd25519 {
+            multisig: self.multisig.clone(),
+            signers:  self
+                .signatures
+                .iter()
+                .enumerate()
+                .map(|(i, (member_index, _))| AptosMultiEd25519Signer {
+                    member_index:                   *member_index,
+                    verification_instruction_index: verification_instruction_index + i as u8,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<AptosMultiEd25519TestIdentityCertificate> for Identity {
+    fn from(val: AptosMultiEd25519TestIdentityCertificate) -> Self {
+        Identity::Aptos {
+            address: val.multisig.get_address(),
+        }
+    }
+}
+
+/**
+ * An Aptos account `address` whose current key signs the Aptos message, and the attestation of
+ * the dispenser guard linking that key to `address`.
+ */
+#[derive(Clone)]
+pub struct AptosRotatedTestIdentityCertificate {
+    pub address:     AptosAddress,
+    pub signature:   Ed25519TestIdentityCertificate<AptosMessage>,
+    pub attestation: Ed25519TestIdentityCertificate<AptosKeyRotationMessage>,
+}
+
+impl AptosRotatedTestIdentityCertificate {
+    pub fn random(claimant: &Pubkey, dispenser_guard: &Keypair) -> Self {
+        let signature = Ed25519TestIdentityCertificate::<AptosMessage>::random(claimant);
+        let address = AptosAddress::from(rand::random::<[u8; AptosAddress::LEN]>());
+        let message = AptosKeyRotationMessage::new(
+            address.clone(),
+            Ed25519Pubkey::from(signature.public_key.to_bytes()),
+        );
+        Self {
+            address,
+            signature,
+            attestation: Ed25519TestIdentityCertificate {
+                signature: dispenser_guard.sign(&message.get_message_with_metadata()),
+                public_key: dispenser_guard.public,
+                message,
+            },
+        }
+    }
+
+    pub fn as_instructions(&self, instruction_index: u8) -> Vec<Instruction> {
+        vec![
+            self.signature.as_instruction(instruction_index, true),
+            self.attestation.as_instruction(instruction_index + 1, true),
+        ]
+    }
+
+    pub fn as_proof_of_identity(&self, verification_instruction_index: u8) -> IdentityCertificate {
+        IdentityCertificate::AptosRotated {
+            address: self.address.clone(),
+            pubkey: self.signature.public_key.to_bytes().into(),
+            verification_instruction_index,
+            attestation_instruction_index: verification_instruction_index + 1,
+        }
+    }
+}
+
+impl From<AptosRotatedTestIdentityCertificate> for Identity {
+    fn from(val: AptosRotatedTestIdentityCertificate) -> Self {
+        Identity::Aptos {
+            address: val.address,
+        }
+    }
+}
+
+async fn setup(
+    simulator: &mut DispenserSimulator,
+    certificates: &[TestClaimCertificate],
+    dispenser_guard: &Keypair,
+) -> MerkleTree<SolanaHasher> {
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Pubkey::new_from_array(dispenser_guard.public.to_bytes()),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+    merkle_tree
+}
+
+fn multi_ed25519_certificate(
+    claimant: &Pubkey,
+    threshold: u8,
+    members: usize,
+    signers: &[u8],
+) -> TestClaimCertificate {
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity: TestIdentityCertificate::AptosMultiEd25519(
+            AptosMultiEd25519TestIdentityCertificate::random(claimant, threshold, members, signers),
+        ),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
+fn rotated_certificate(claimant: &Pubkey, dispenser_guard: &Keypair) -> TestClaimCertificate {
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity: TestIdentityCertificate::AptosRotated(
+            AptosRotatedTestIdentityCertificate::random(claimant, dispenser_guard),
+        ),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
 #[tokio::test]
 pub async fn test_aptos_message() {
     let claimant = Pubkey::new_unique();
+    let address = AptosAddress::from(Ed25519Pubkey::from([1; 32]));
     assert_eq!(
-        AptosMessage::parse(&AptosMessage::for_claimant(&claimant).get_message_with_metadata())
-            .unwrap()
-            .get_payload(),
-        get_expected_payload(&claimant).as_bytes()
+        AptosMessage::parse(
+            &AptosMessage::for_claimant(&claimant).get_message_with_metadata(),
+            &address
+        )
+        .unwrap()
+        .get_payload(),
+        get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, &claimant).as_bytes()
     );
 }
+
+#[test]
+pub fn test_aptos_key_rotation_message() {
+    let address = AptosAddress::from([1; AptosAddress::LEN]);
+    let pubkey = Ed25519Pubkey::from([2; Ed25519Pubkey::LEN]);
+    let message = AptosKeyRotationMessage::new(address.clone(), pubkey.clone());
+    assert!(AptosKeyRotationMessage::parse_and_check_address_and_pubkey(
+        &message.get_message_with_metadata(),
+        &address,
+        &pubkey
+    )
+    .is_ok());
+
+    // An Algorand rekey attestation has the same borsh layout
+    let algorand_message = AlgorandRekeyMessage::new(
+        AlgorandAddress::from([1; AlgorandAddress::LEN]),
+        pubkey.clone(),
+    );
+    for data in [
+        algorand_message.get_message_with_metadata(),
+        algorand_message.try_to_vec().unwrap(),
+    ] {
+        assert_eq!(
+            AptosKeyRotationMessage::parse_and_check_address_and_pubkey(&data, &address, &pubkey)
+                .unwrap_err(),
+            error!(ErrorCode::SignatureVerificationWrongPayload)
+        );
+    }
+}
+
+#[test]
+pub fn test_aptos_full_message() {
+    let claimant = Pubkey::new_unique();
+    let payload = get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, &claimant);
+    let mut address_bytes = [0u8; AptosAddress::LEN];
+    address_bytes[1] = 0xab;
+    address_bytes[31] = 0x0c;
+    let address = AptosAddress::from(address_bytes);
+    let hex_address = "0x00ab00000000000000000000000000000000000000000000000000000000000c";
+
+    // The fields each wallet might include
+    for header in [
+        format!("address: {}\n", hex_address),
+        format!("address: {}\n", &hex_address.replacen("0x00", "0x", 1)),
+        "application: https://airdrop.example.com\n".to_string(),
+        "chainId: 1\n".to_string(),
+        format!(
+            "address: {}\napplication: https://airdrop.example.com\nchainId: 1\n",
+            hex_address.to_uppercase().replacen("0X", "0x", 1)
+        ),
+    ] {
+        let message = format!("APTOS\n{}message: {}\nnonce: 1699999999", header, payload);
+        assert_eq!(
+            AptosMessage::parse(message.as_bytes(), &address)
+                .unwrap()
+                .get_payload(),
+            payload.as_bytes()
+        );
+    }
+
+    // Address of another account
+    let message = format!(
+        "APTOS\naddress: 0x1\nmessage: {}\nnonce: 1699999999",
+        payload
+    );
+    assert_eq!(
+        AptosMessage::parse(message.as_bytes(), &address).unwrap_err(),
+        error!(ErrorCode::SignatureVerificationWrongSigner)
+    );
+
+    for message in [
+        // wrong order
+        format!(
+            "APTOS\nchainId: 1\naddress: {}\nmessage: {}\nnonce: 1",
+            hex_address, payload
+        ),
+        // invalid fields
+        format!(
+            "APTOS\naddress: {}0\nmessage: {}\nnonce: 1",
+            hex_address, payload
+        ),
+        format!("APTOS\naddress: 0xzz\nmessage: {}\nnonce: 1", payload),
+        format!("APTOS\nchainId: mainnet\nmessage: {}\nnonce: 1", payload),
+        // missing nonce
+        format!("APTOS\nmessage: {}\nnonce: ", payload),
+        format!("APTOS\nmessage: {}", payload),
+        format!("message: {}\nnonce: 1", payload),
+    ] {
+        assert_eq!(
+            AptosMessage::parse(message.as_bytes(), &address).unwrap_err(),
+            error!(ErrorCode::SignatureVerificationWrongPayloadMetadata)
+        );
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_with_aptos_multi_ed25519_and_rotated_accounts() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = solana_sdk::signature::Keypair::new();
+    let claimant_pubkey = solana_sdk::signer::Signer::pubkey(&claimant);
+    let dispenser_guard = Keypair::generate(&mut OsRng {});
+
+    let certificates = vec![
+        multi_ed25519_certificate(&claimant_pubkey, 2, 3, &[0, 2]),
+        multi_ed25519_certificate(&claimant_pubkey, 1, 1, &[0]),
+        rotated_certificate(&claimant_pubkey, &dispenser_guard),
+    ];
+    let merkle_tree = setup(&mut simulator, &certificates, &dispenser_guard).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_invalid_aptos_multi_ed25519_or_rotated_accounts() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = solana_sdk::signature::Keypair::new();
+    let claimant_pubkey = solana_sdk::signer::Signer::pubkey(&claimant);
+    let dispenser_guard = Keypair::generate(&mut OsRng {});
+
+    let mut certificates_and_errors: Vec<(TestClaimCertificate, ErrorCode)> = vec![
+        (
+            multi_ed25519_certificate(&claimant_pubkey, 2, 3, &[1]),
+            ErrorCode::InvalidAptosMultiEd25519,
+        ),
+        (
+            multi_ed25519_certificate(&claimant_pubkey, 2, 3, &[1, 1]),
+            ErrorCode::InvalidAptosMultiEd25519,
+        ),
+        (
+            multi_ed25519_certificate(&Pubkey::new_unique(), 2, 3, &[0, 1]),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            // the members sign different messages
+            TestClaimCertificate {
+                amount:                      TestClaimCertificate::random_amount(),
+                off_chain_proof_of_identity: TestIdentityCertificate::AptosMultiEd25519(
+                    AptosMultiEd25519TestIdentityCertificate::random_with_messages(
+                        2,
+                        3,
+                        vec![
+                            (0, AptosMessage::for_claimant(&claimant_pubkey)),
+                            (1, AptosMessage::for_claimant(&Pubkey::new_unique())),
+                        ],
+                    ),
+                ),
+                authorization_payload:       AuthorizationPayload::V1,
+            },
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            rotated_certificate(&claimant_pubkey, &Keypair::generate(&mut OsRng {})),
+            ErrorCode::SignatureVerificationWrongSigner,
+        ),
+        (
+            rotated_certificate(&claimant_pubkey, &dispenser_guard),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+    ];
+    // the attestation is for another account
+    if let TestIdentityCertificate::AptosRotated(rotated) =
+        &mut certificates_and_errors[5].0.off_chain_proof_of_identity
+    {
+        rotated.address = AptosAddress::from([1; AptosAddress::LEN]);
+    }
+    let certificates: Vec<TestClaimCertificate> = certificates_and_errors
+        .iter()
+        .map(|(certificate, _)| certificate.clone())
+        .collect();
+    let merkle_tree = setup(&mut simulator, &certificates, &dispenser_guard).await;
+
+    for (certificate, error) in &certificates_and_errors {
+        let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            (*error).into_transaction_error(ix_index_error)
+        );
+    }
+}
@@ -0,0 +1,412 @@
+use {
+    super::{
+        dispenser_simulator::{
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_cosmos::Sha256,
+        test_ed25519::Ed25519TestIdentityCertificate,
+        test_happy_path::{
+            TestClaimCertificate,
+            TestIdentityCertificate,
+        },
+        test_secp256k1::Secp256k1TestIdentityCertificate,
+    },
+    crate::{
+        ecosystems::{
+            aptos::AptosMessage,
+            cosmos::CosmosMessage,
+            evm::EvmPrefixedMessage,
+            get_expected_payload,
+            get_expected_payload_v2,
+            sui::SuiMessage,
+            AuthorizationPayload,
+            DEFAULT_AUTHORIZATION_TEMPLATE,
+            DEFAULT_AUTHORIZATION_TEMPLATE_V2,
+        },
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        SolanaHasher,
+    },
+    anchor_lang::prelude::Pubkey,
+    pythnet_sdk::{
+        accumulators::merkle::MerkleTree,
+        hashers::keccak256::Keccak256,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+const NONCE: u64 = 7;
+
+/// Builds one certificate per ecosystem, each signing `signed_payload`
+fn generate_claim_certs(
+    signed_payload: impl Fn(u64) -> String,
+    authorization_payload: AuthorizationPayload,
+) -> Vec<TestClaimCertificate> {
+    let mut certificates = vec![];
+    for ecosystem in 0..4 {
+        let amount = TestClaimCertificate::random_amount();
+        let payload = signed_payload(amount);
+        let off_chain_proof_of_identity = match ecosystem {
+            0 => TestIdentityCertificate::Evm(Secp256k1TestIdentityCertificate::<
+                EvmPrefixedMessage,
+                Keccak256,
+            >::random_with_payload(&payload)),
+            1 => TestIdentityCertificate::Cosmos(Secp256k1TestIdentityCertificate::<
+                CosmosMessage,
+                Sha256,
+            >::random_with_payload(&payload)),
+            2 => {
+                TestIdentityCertificate::Aptos(Ed25519TestIdentityCertificate::random_with_message(
+                    AptosMessage::from(payload.as_str()),
+                ))
+            }
+            _ => TestIdentityCertificate::Sui(Ed25519TestIdentityCertificate::random_with_message(
+                SuiMessage::from(payload.as_str()),
+            )),
+        };
+        certificates.push(TestClaimCertificate {
+            amount,
+            off_chain_proof_of_identity,
+            authorization_payload: authorization_payload.clone(),
+        });
+    }
+    certificates
+}
+
+async fn setup(
+    simulator: &mut DispenserSimulator,
+    certificates: &[TestClaimCertificate],
+) -> MerkleTree<SolanaHasher> {
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Keypair::new().pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+    merkle_tree
+}
+
+/// A campaign whose template has the placeholders of the version 2 of the authorization payload
+async fn new_simulator_v2() -> DispenserSimulator {
+    let mut simulator = DispenserSimulator::new().await;
+    simulator.authorization_template = DEFAULT_AUTHORIZATION_TEMPLATE_V2.to_string();
+    simulator
+}
+
+#[tokio::test]
+pub async fn test_claim_with_authorization_payload_v2() {
+    let mut simulator = new_simulator_v2().await;
+    let claimant = Keypair::new();
+    let genesis_hash = simulator.genesis_hash;
+    let expiry = simulator.get_clock().await.unix_timestamp + 3600;
+
+    let certificates = generate_claim_certs(
+        |amount| {
+            get_expected_payload_v2(
+                DEFAULT_AUTHORIZATION_TEMPLATE_V2,
+                &claimant.pubkey(),
+                amount,
+                &genesis_hash,
+                expiry,
+                NONCE,
+            )
+        },
+        AuthorizationPayload::V2 {
+            expiry,
+            nonce: NONCE,
+        },
+    );
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_expired_authorization_payload() {
+    let mut simulator = new_simulator_v2().await;
+    let claimant = Keypair::new();
+    let genesis_hash = simulator.genesis_hash;
+    let expiry = simulator.get_clock().await.unix_timestamp - 1;
+
+    let certificates = generate_claim_certs(
+        |amount| {
+            get_expected_payload_v2(
+                DEFAULT_AUTHORIZATION_TEMPLATE_V2,
+                &claimant.pubkey(),
+                amount,
+                &genesis_hash,
+                expiry,
+                NONCE,
+            )
+        },
+        AuthorizationPayload::V2 {
+            expiry,
+            nonce: NONCE,
+        },
+    );
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            ErrorCode::AuthorizationExpired.into_transaction_error(ix_index_error)
+        );
+    }
+}
+
+/** Builds a V2 payload from the amount, the genesis hash and the expiry of the claim */
+type PayloadBuilder<'a> = Box<dyn Fn(u64, &[u8; 32], i64) -> String + 'a>;
+
+#[tokio::test]
+pub async fn test_claim_fails_with_wrong_authorization_payload_v2() {
+    let claimant = Keypair::new();
+
+    // Signed for another cluster, for another amount, for another claimant, with another nonce
+    // and for another campaign
+    let wrong_payloads: [PayloadBuilder; 5] = [
+        Box::new(|amount, _, expiry| {
+            get_expected_payload_v2(
+                DEFAULT_AUTHORIZATION_TEMPLATE_V2,
+                &claimant.pubkey(),
+                amount,
+                &[0u8; 32],
+                expiry,
+                NONCE,
+            )
+        }),
+        Box::new(|amount, genesis_hash, expiry| {
+            get_expected_payload_v2(
+                DEFAULT_AUTHORIZATION_TEMPLATE_V2,
+                &claimant.pubkey(),
+                amount + 1,
+                genesis_hash,
+                expiry,
+                NONCE,
+            )
+        }),
+        Box::new(|amount, genesis_hash, expiry| {
+            get_expected_payload_v2(
+                DEFAULT_AUTHORIZATION_TEMPLATE_V2,
+                &Pubkey::new_unique(),
+                amount,
+                genesis_hash,
+                expiry,
+                NONCE,
+            )
+        }),
+        Box::new(|amount, genesis_hash, expiry| {
+            get_expected_payload_v2(
+                DEFAULT_AUTHORIZATION_TEMPLATE_V2,
+                &claimant.pubkey(),
+                amount,
+                genesis_hash,
+                expiry,
+                NONCE + 1,
+            )
+        }),
+        Box::new(|amount, genesis_hash, expiry| {
+            get_expected_payload_v2(
+                &DEFAULT_AUTHORIZATION_TEMPLATE_V2.replace("W tokens", "Acme tokens"),
+                &claimant.pubkey(),
+                amount,
+                genesis_hash,
+                expiry,
+                NONCE,
+            )
+        }),
+    ];
+
+    for wrong_payload in wrong_payloads.iter() {
+        let mut simulator = new_simulator_v2().await;
+        let genesis_hash = simulator.genesis_hash;
+        let expiry = simulator.get_clock().await.unix_timestamp + 3600;
+
+        let certificates = generate_claim_certs(
+            |amount| wrong_payload(amount, &genesis_hash, expiry),
+            AuthorizationPayload::V2 {
+                expiry,
+                nonce: NONCE,
+            },
+        );
+        let merkle_tree = setup(&mut simulator, &certificates).await;
+
+        for certificate in &certificates {
+            let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+            assert_eq!(
+                simulator
+                    .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                    .await
+                    .unwrap_err()
+                    .unwrap(),
+                ErrorCode::SignatureVerificationWrongPayload.into_transaction_error(ix_index_error)
+            );
+        }
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_with_custom_authorization_template() {
+    let custom_template = "Acme Airdrop\nProgram: {program_id}\nRecipient: {claimant}\n";
+    let claimant = Keypair::new();
+
+    let mut simulator = DispenserSimulator::new().await;
+    simulator.authorization_template = custom_template.to_string();
+    let certificates = generate_claim_certs(
+        |_| get_expected_payload(custom_template, &claimant.pubkey()),
+        AuthorizationPayload::V1,
+    );
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+
+    // Signatures of the default template aren't valid for this campaign
+    let mut simulator = DispenserSimulator::new().await;
+    simulator.authorization_template = custom_template.to_string();
+    let certificates = generate_claim_certs(
+        |_| get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, &claimant.pubkey()),
+        AuthorizationPayload::V1,
+    );
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            ErrorCode::SignatureVerificationWrongPayload.into_transaction_error(ix_index_error)
+        );
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_v1_authorization_payload_when_v2_is_required() {
+    let claimant = Keypair::new();
+
+    let mut simulator = new_simulator_v2().await;
+    simulator.min_payload_version = 2;
+    let certificates = generate_claim_certs(
+        |_| get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE_V2, &claimant.pubkey()),
+        AuthorizationPayload::V1,
+    );
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            ErrorCode::UnsupportedAuthorizationPayloadVersion
+                .into_transaction_error(ix_index_error)
+        );
+    }
+
+    // The same campaign accepts V2 payloads
+    let mut simulator = new_simulator_v2().await;
+    simulator.min_payload_version = 2;
+    let genesis_hash = simulator.genesis_hash;
+    let expiry = simulator.get_clock().await.unix_timestamp + 3600;
+    let certificates = generate_claim_certs(
+        |amount| {
+            get_expected_payload_v2(
+                DEFAULT_AUTHORIZATION_TEMPLATE_V2,
+                &claimant.pubkey(),
+                amount,
+                &genesis_hash,
+                expiry,
+                NONCE,
+            )
+        },
+        AuthorizationPayload::V2 {
+            expiry,
+            nonce: NONCE,
+        },
+    );
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_v2_authorization_payload_when_the_template_is_v1() {
+    let claimant = Keypair::new();
+
+    let mut simulator = DispenserSimulator::new().await;
+    let genesis_hash = simulator.genesis_hash;
+    let expiry = simulator.get_clock().await.unix_timestamp + 3600;
+    let certificates = generate_claim_certs(
+        |amount| {
+            get_expected_payload_v2(
+                DEFAULT_AUTHORIZATION_TEMPLATE,
+                &claimant.pubkey(),
+                amount,
+                &genesis_hash,
+                expiry,
+                NONCE,
+            )
+        },
+        AuthorizationPayload::V2 {
+            expiry,
+            nonce: NONCE,
+        },
+    );
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            ErrorCode::UnsupportedAuthorizationPayloadVersion
+                .into_transaction_error(ix_index_error)
+        );
+    }
+}
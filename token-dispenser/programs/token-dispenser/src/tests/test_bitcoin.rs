@@ -0,0 +1,311 @@
+use {
+    super::{
+        dispenser_simulator::{
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_happy_path::{
+            TestClaimCertificate,
+            TestIdentityCertificate,
+        },
+    },
+    crate::{
+        ecosystems::{
+            bitcoin::{
+                hash160,
+                Bip322Message,
+                BitcoinAddress,
+                BitcoinChain,
+                BitcoinMessage,
+            },
+            get_expected_payload,
+            AuthorizationPayload,
+            DEFAULT_AUTHORIZATION_TEMPLATE,
+        },
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        Identity,
+        IdentityCertificate,
+        SolanaHasher,
+    },
+    anchor_lang::prelude::Pubkey,
+    pythnet_sdk::accumulators::merkle::MerkleTree,
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+/// The first BIP-137 header of each address type
+pub const P2PKH_UNCOMPRESSED_HEADER: u8 = 27;
+pub const P2PKH_COMPRESSED_HEADER: u8 = 31;
+pub const P2SH_P2WPKH_HEADER: u8 = 35;
+pub const P2WPKH_HEADER: u8 = 39;
+
+/**
+ * A Bitcoin address signing the hash `message`, either with `signmessage` (BIP-137) or with
+ * a simple BIP-322 signature.
+ */
+#[derive(Clone)]
+pub enum BitcoinTestIdentityCertificate {
+    Bip137 {
+        chain:     BitcoinChain,
+        address:   BitcoinAddress,
+        signature: libsecp256k1::Signature,
+        header:    u8,
+        message:   [u8; 32],
+    },
+    Bip322 {
+        signature:   libsecp256k1::Signature,
+        recovery_id: libsecp256k1::RecoveryId,
+        pubkey:      libsecp256k1::PublicKey,
+        message:     [u8; 32],
+    },
+}
+
+impl BitcoinTestIdentityCertificate {
+    /// `header_base` is the first BIP-137 header of the address type
+    pub fn random_bip137(claimant: &Pubkey, chain: BitcoinChain, header_base: u8) -> Self {
+        let secret = libsecp256k1::SecretKey::random(&mut rand::thread_rng());
+        let pubkey = libsecp256k1::PublicKey::from_secret_key(&secret);
+        let pubkey_hash = hash160(&pubkey.serialize_compressed());
+        let address = match header_base {
+            P2PKH_UNCOMPRESSED_HEADER => BitcoinAddress::P2pkh {
+                pubkey_hash: hash160(&pubkey.serialize()),
+            },
+            P2PKH_COMPRESSED_HEADER => BitcoinAddress::P2pkh { pubkey_hash },
+            P2SH_P2WPKH_HEADER => BitcoinAddress::P2shP2wpkh {
+                script_hash: hash160(&[&[0x00, 0x14], &pubkey_hash[..]].concat()),
+            },
+            _ => BitcoinAddress::P2wpkh {
+                witness_program: pubkey_hash,
+            },
+        };
+        let message = BitcoinMessage::get_expected_hash(
+            chain.get_message_prefix(),
+            &get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant),
+        );
+        let (signature, recovery_id) =
+            libsecp256k1::sign(&libsecp256k1::Message::parse(&message), &secret);
+        Self::Bip137 {
+            chain,
+            address,
+            signature,
+            header: header_base + recovery_id.serialize(),
+            message,
+        }
+    }
+
+    pub fn random_bip322(claimant: &Pubkey) -> Self {
+        let secret = libsecp256k1::SecretKey::random(&mut rand::thread_rng());
+        let pubkey = libsecp256k1::PublicKey::from_secret_key(&secret);
+        let message = Bip322Message::get_expected_hash(
+            &hash160(&pubkey.serialize_compressed()),
+            &get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant),
+        );
+        let (signature, recovery_id) =
+            libsecp256k1::sign(&libsecp256k1::Message::parse(&message), &secret);
+        Self::Bip322 {
+            signature,
+            recovery_id,
+            pubkey,
+            message,
+        }
+    }
+}
+
+impl From<BitcoinTestIdentityCertificate> for Identity {
+    fn from(val: BitcoinTestIdentityCertificate) -> Self {
+        match val {
+            BitcoinTestIdentityCertificate::Bip137 { address, .. } => Identity::Bitcoin { address },
+            BitcoinTestIdentityCertificate::Bip322 { pubkey, .. } => Identity::Bitcoin {
+                address: BitcoinAddress::P2wpkh {
+                    witness_program: hash160(&pubkey.serialize_compressed()),
+                },
+            },
+        }
+    }
+}
+
+impl From<BitcoinTestIdentityCertificate> for IdentityCertificate {
+    fn from(val: BitcoinTestIdentityCertificate) -> Self {
+        match val {
+            BitcoinTestIdentityCertificate::Bip137 {
+                chain,
+                address,
+                signature,
+                header,
+                message,
+            } => IdentityCertificate::Bitcoin {
+                chain,
+                address,
+                signature: signature.serialize().into(),
+                header,
+                message: message.to_vec(),
+            },
+            BitcoinTestIdentityCertificate::Bip322 {
+                signature,
+                recovery_id,
+                pubkey,
+                message,
+            } => IdentityCertificate::BitcoinBip322 {
+                signature:   signature.serialize().into(),
+                recovery_id: recovery_id.into(),
+                pubkey:      pubkey.serialize_compressed().into(),
+                message:     message.to_vec(),
+            },
+        }
+    }
+}
+
+async fn setup(
+    simulator: &mut DispenserSimulator,
+    certificates: &[TestClaimCertificate],
+) -> MerkleTree<SolanaHasher> {
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Keypair::new().pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+    merkle_tree
+}
+
+fn bitcoin_certificate(certificate: BitcoinTestIdentityCertificate) -> TestClaimCertificate {
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity: TestIdentityCertificate::Bitcoin(certificate),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_with_bitcoin() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let mut certificates: Vec<TestClaimCertificate> = [
+        P2PKH_UNCOMPRESSED_HEADER,
+        P2PKH_COMPRESSED_HEADER,
+        P2SH_P2WPKH_HEADER,
+        P2WPKH_HEADER,
+    ]
+    .iter()
+    .map(|header_base| {
+        bitcoin_certificate(BitcoinTestIdentityCertificate::random_bip137(
+            &claimant.pubkey(),
+            BitcoinChain::Bitcoin,
+            *header_base,
+        ))
+    })
+    .collect();
+    certificates.push(bitcoin_certificate(
+        BitcoinTestIdentityCertificate::random_bip137(
+            &claimant.pubkey(),
+            BitcoinChain::Litecoin,
+            P2WPKH_HEADER,
+        ),
+    ));
+    certificates.push(bitcoin_certificate(
+        BitcoinTestIdentityCertificate::random_bip137(
+            &claimant.pubkey(),
+            BitcoinChain::Dogecoin,
+            P2PKH_COMPRESSED_HEADER,
+        ),
+    ));
+    certificates.push(bitcoin_certificate(
+        BitcoinTestIdentityCertificate::random_bip322(&claimant.pubkey()),
+    ));
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_wrong_bitcoin_signature() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let mut certificates_and_errors = vec![
+        (
+            bitcoin_certificate(BitcoinTestIdentityCertificate::random_bip137(
+                &Pubkey::new_unique(),
+                BitcoinChain::Bitcoin,
+                P2WPKH_HEADER,
+            )),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            bitcoin_certificate(BitcoinTestIdentityCertificate::random_bip322(
+                &Pubkey::new_unique(),
+            )),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            bitcoin_certificate(BitcoinTestIdentityCertificate::random_bip137(
+                &claimant.pubkey(),
+                BitcoinChain::Bitcoin,
+                P2WPKH_HEADER,
+            )),
+            ErrorCode::SignatureVerificationWrongSigner,
+        ),
+        (
+            bitcoin_certificate(BitcoinTestIdentityCertificate::random_bip137(
+                &claimant.pubkey(),
+                BitcoinChain::Bitcoin,
+                P2SH_P2WPKH_HEADER,
+            )),
+            ErrorCode::InvalidBip137Header,
+        ),
+    ];
+    // segwit addresses can't have uncompressed keys
+    if let TestIdentityCertificate::Bitcoin(BitcoinTestIdentityCertificate::Bip137 {
+        header, ..
+    }) = &mut certificates_and_errors[2].0.off_chain_proof_of_identity
+    {
+        *header -= P2WPKH_HEADER - P2PKH_UNCOMPRESSED_HEADER;
+    }
+    if let TestIdentityCertificate::Bitcoin(BitcoinTestIdentityCertificate::Bip137 {
+        header, ..
+    }) = &mut certificates_and_errors[3].0.off_chain_proof_of_identity
+    {
+        *header += 8;
+    }
+    let certificates: Vec<TestClaimCertificate> = certificates_and_errors
+        .iter()
+        .map(|(certificate, _)| certificate.clone())
+        .collect();
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for (certificate, error) in &certificates_and_errors {
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            (*error).into_transaction_error(0)
+        );
+    }
+}
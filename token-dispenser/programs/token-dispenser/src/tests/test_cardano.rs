@@ -0,0 +1,179 @@
+use {
+    super::{
+        dispenser_simulator::{
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_ed25519::Ed25519TestIdentityCertificate,
+        test_happy_path::{
+            TestClaimCertificate,
+            TestIdentityCertificate,
+        },
+    },
+    crate::{
+        ecosystems::{
+            cardano::{
+                CardanoKeyHash,
+                CardanoTestMessage,
+                CARDANO_BASE_ADDRESS,
+                CARDANO_ENTERPRISE_ADDRESS,
+                CARDANO_REWARD_ADDRESS,
+            },
+            ed25519::{
+                Ed25519Pubkey,
+                Ed25519TestMessage,
+            },
+            AuthorizationPayload,
+        },
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        SolanaHasher,
+    },
+    anchor_lang::prelude::Pubkey,
+    ed25519_dalek::{
+        Keypair,
+        Signer,
+    },
+    pythnet_sdk::accumulators::merkle::MerkleTree,
+    rand_compatible::rngs::OsRng,
+    solana_program_test::tokio,
+};
+
+/// Enterprise address whose payment credential is a script
+pub const CARDANO_ENTERPRISE_SCRIPT_ADDRESS: u8 = 7;
+pub const CARDANO_MAINNET: u8 = 1;
+
+/**
+ * A CIP-30 signature by the key whose hash is the signing credential of a mainnet address of type
+ * `address_type`.
+ */
+pub fn random_cardano_signature(
+    claimant: &Pubkey,
+    address_type: u8,
+) -> Ed25519TestIdentityCertificate<CardanoTestMessage> {
+    let keypair = Keypair::generate(&mut OsRng {});
+    let key_hash = CardanoKeyHash::from(Ed25519Pubkey::from(keypair.public.to_bytes()));
+    let mut address = vec![(address_type << 4) | CARDANO_MAINNET];
+    address.extend_from_slice(&key_hash.to_bytes());
+    if address_type == CARDANO_BASE_ADDRESS {
+        address.extend_from_slice(&rand::random::<[u8; CardanoKeyHash::LEN]>());
+    }
+    let message = CardanoTestMessage::new(address, claimant);
+    Ed25519TestIdentityCertificate {
+        signature: keypair.sign(&message.get_message_with_metadata()),
+        public_key: keypair.public,
+        message,
+    }
+}
+
+async fn setup(
+    simulator: &mut DispenserSimulator,
+    certificates: &[TestClaimCertificate],
+) -> MerkleTree<SolanaHasher> {
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Pubkey::new_unique(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+    merkle_tree
+}
+
+fn cardano_certificate(
+    signature: Ed25519TestIdentityCertificate<CardanoTestMessage>,
+) -> TestClaimCertificate {
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity: TestIdentityCertificate::Cardano(signature),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_with_cardano() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = solana_sdk::signature::Keypair::new();
+    let claimant_pubkey = solana_sdk::signer::Signer::pubkey(&claimant);
+
+    let certificates: Vec<TestClaimCertificate> = [
+        CARDANO_BASE_ADDRESS,
+        CARDANO_ENTERPRISE_ADDRESS,
+        CARDANO_REWARD_ADDRESS,
+    ]
+    .iter()
+    .map(|address_type| {
+        cardano_certificate(random_cardano_signature(&claimant_pubkey, *address_type))
+    })
+    .collect();
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_invalid_cardano_signature() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = solana_sdk::signature::Keypair::new();
+    let claimant_pubkey = solana_sdk::signer::Signer::pubkey(&claimant);
+
+    let certificates_and_errors = vec![
+        (
+            cardano_certificate(random_cardano_signature(
+                &Pubkey::new_unique(),
+                CARDANO_BASE_ADDRESS,
+            )),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            cardano_certificate(random_cardano_signature(
+                &claimant_pubkey,
+                CARDANO_ENTERPRISE_SCRIPT_ADDRESS,
+            )),
+            ErrorCode::SignatureVerificationWrongSigner,
+        ),
+        // the address belongs to another key
+        (
+            cardano_certificate(
+                Ed25519TestIdentityCertificate::<CardanoTestMessage>::random(&claimant_pubkey),
+            ),
+            ErrorCode::SignatureVerificationWrongSigner,
+        ),
+    ];
+    let certificates: Vec<TestClaimCertificate> = certificates_and_errors
+        .iter()
+        .map(|(certificate, _)| certificate.clone())
+        .collect();
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for (certificate, error) in &certificates_and_errors {
+        let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            (*error).into_transaction_error(ix_index_error)
+        );
+    }
+}
@@ -1,3 +1,5 @@
+#[allow(unused_imports)] //used by commented out test at the bottom
+use crate::tests::test_happy_path::TestIdentityCertificate;
 use {
     super::dispenser_simulator::DispenserSimulator,
     crate::{
@@ -12,6 +14,7 @@ use {
         },
         ClaimInfo,
         ErrorCode,
+        HashAlgorithm,
         SolanaHasher,
     },
     anchor_lang::{
@@ -40,9 +43,6 @@ use {
     },
 };
 
-#[allow(unused_imports)] //used by commented out test at the bottom
-use crate::tests::test_happy_path::TestIdentityCertificate;
-
 #[tokio::test]
 pub async fn test_claim_fails_with_wrong_accounts() {
     let dispenser_guard: Keypair = Keypair::new();
@@ -58,7 +58,7 @@ pub async fn test_claim_fails_with_wrong_accounts() {
             ],
             &dispenser_guard,
             None,
-            false
+            false,
         )
         .await
         .unwrap();
@@ -523,9 +523,7 @@ pub async fn test_claim_works_if_receipt_has_balance() {
 
     let (merkle_tree, mock_offchain_certificates_and_claimants) = simulator
         .initialize_with_claimants(
-            vec![
-                copy_keypair(&claimant_1)
-            ],
+            vec![copy_keypair(&claimant_1)],
             &dispenser_guard,
             None,
             false,
@@ -539,6 +537,7 @@ pub async fn test_claim_works_if_receipt_has_balance() {
             &<TestClaimCertificate as Into<ClaimInfo>>::into(offchain_claim_certificate.clone())
                 .try_to_vec()
                 .unwrap(),
+            HashAlgorithm::Keccak160,
         )
         .0;
 
@@ -615,6 +614,7 @@ pub async fn test_claim_fails_if_exceeds_max_transfer() {
             &<TestClaimCertificate as Into<ClaimInfo>>::into(offchain_claim_certificate.clone())
                 .try_to_vec()
                 .unwrap(),
+            HashAlgorithm::Keccak160,
         )
         .0;
         assert!(simulator.get_account(receipt_pda).await.is_none());
@@ -0,0 +1,178 @@
+use {
+    super::dispenser_simulator::DispenserSimulator,
+    crate::{
+        get_claim_stats_pda,
+        get_config_pda,
+        tests::{
+            dispenser_simulator::copy_keypair,
+            merkleize,
+            test_happy_path::TestClaimCertificate,
+        },
+        ClaimInfo,
+        ClaimStats,
+        Ecosystem,
+        EcosystemClaimStats,
+        EcosystemsClaimStats,
+        SolanaHasher,
+        ECOSYSTEM_COUNT,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+/// Number of ecosystems when `ClaimStats` was introduced
+const FIRST_ECOSYSTEM_COUNT: usize = 8;
+
+#[tokio::test]
+pub async fn test_claim_stats() {
+    let dispenser_guard: Keypair = Keypair::new();
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant_1 = Keypair::new();
+
+    let (merkle_tree, mock_offchain_certificates_and_claimants) = simulator
+        .initialize_with_claimants(
+            vec![
+                copy_keypair(&simulator.genesis_keypair),
+                copy_keypair(&claimant_1),
+            ],
+            &dispenser_guard,
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+
+    let claim_stats = simulator
+        .get_account_data::<ClaimStats>(get_claim_stats_pda().0)
+        .await
+        .unwrap();
+    assert_eq!(claim_stats.bump, get_claim_stats_pda().1);
+    assert_eq!(claim_stats.total_claimed, 0);
+    assert_eq!(claim_stats.claim_count, 0);
+    assert_eq!(claim_stats.ecosystems, EcosystemsClaimStats::default());
+
+    let total_claim_sum = mock_offchain_certificates_and_claimants
+        .iter()
+        .map(|(_, _, amount)| amount)
+        .sum::<u64>();
+    simulator
+        .approve_treasury_delegate(get_config_pda().0, total_claim_sum)
+        .await
+        .unwrap();
+
+    let mut expected_ecosystems = [EcosystemClaimStats::default(); ECOSYSTEM_COUNT];
+    let mut expected_claim_count = 0;
+    for (claimant, offchain_claim_certificates, _) in &mock_offchain_certificates_and_claimants {
+        for offchain_claim_certificate in offchain_claim_certificates {
+            simulator
+                .claim(
+                    &copy_keypair(claimant),
+                    offchain_claim_certificate,
+                    &merkle_tree,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+
+            let claim_info: ClaimInfo = offchain_claim_certificate.clone().into();
+            let expected = &mut expected_ecosystems[claim_info.identity.ecosystem() as usize];
+            expected.total_claimed += claim_info.amount;
+            expected.claim_count += 1;
+            expected_claim_count += 1;
+
+            let claim_stats = simulator
+                .get_account_data::<ClaimStats>(get_claim_stats_pda().0)
+                .await
+                .unwrap();
+            assert_eq!(
+                claim_stats.get(claim_info.identity.ecosystem()),
+                &expected_ecosystems[claim_info.identity.ecosystem() as usize]
+            );
+        }
+    }
+
+    let claim_stats = simulator
+        .get_account_data::<ClaimStats>(get_claim_stats_pda().0)
+        .await
+        .unwrap();
+    assert_eq!(claim_stats.total_claimed, total_claim_sum);
+    assert_eq!(claim_stats.claim_count, expected_claim_count);
+    assert_eq!(claim_stats.ecosystems.0, expected_ecosystems);
+}
+
+#[tokio::test]
+pub async fn test_claim_stats_created_with_fewer_ecosystems() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let certificates = vec![
+        TestClaimCertificate::random_evm(&claimant.pubkey()),
+        TestClaimCertificate::random_tron(&claimant.pubkey()),
+    ];
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Keypair::new().pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+
+    // The account as created by the version of the program that had fewer ecosystems
+    let old_len =
+        ClaimStats::LEN - (ECOSYSTEM_COUNT - FIRST_ECOSYSTEM_COUNT) * EcosystemClaimStats::LEN;
+    let rent = simulator.get_rent().await;
+    let mut account = simulator
+        .get_account(get_claim_stats_pda().0)
+        .await
+        .unwrap();
+    account.data.truncate(old_len);
+    account.lamports = rent.minimum_balance(old_len);
+    simulator.set_account(&get_claim_stats_pda().0, account);
+
+    let claim_stats = simulator
+        .get_account_data::<ClaimStats>(get_claim_stats_pda().0)
+        .await
+        .unwrap();
+    assert_eq!(claim_stats.ecosystems, EcosystemsClaimStats::default());
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+
+    let account = simulator
+        .get_account(get_claim_stats_pda().0)
+        .await
+        .unwrap();
+    assert_eq!(account.data.len(), ClaimStats::LEN);
+    assert!(rent.is_exempt(account.lamports, ClaimStats::LEN));
+
+    let claim_stats = simulator
+        .get_account_data::<ClaimStats>(get_claim_stats_pda().0)
+        .await
+        .unwrap();
+    assert_eq!(claim_stats.total_claimed, total_claim_sum);
+    assert_eq!(claim_stats.claim_count, 2);
+    assert_eq!(claim_stats.get(Ecosystem::Evm).claim_count, 1);
+    assert_eq!(claim_stats.get(Ecosystem::Tron).claim_count, 1);
+}
@@ -3,11 +3,17 @@ use {
     crate::{
         ecosystems::{
             cosmos::{
+                get_default_cosmos_chain_ids,
+                CompressedSecp256k1Pubkey,
                 CosmosMessage,
+                CosmosMultisigSignature,
+                LegacyAminoPubKey,
                 UncompressedSecp256k1Pubkey,
-                ADMISSIBLE_CHAIN_IDS,
+                DEFAULT_COSMOS_CHAIN_IDS,
+                INJECTIVE_CHAIN_ID,
             },
             get_expected_payload,
+            DEFAULT_AUTHORIZATION_TEMPLATE,
         },
         ErrorCode,
         Identity,
@@ -17,7 +23,10 @@ use {
         error,
         prelude::Pubkey,
     },
-    pythnet_sdk::hashers::Hasher,
+    pythnet_sdk::hashers::{
+        keccak256::Keccak256,
+        Hasher,
+    },
     rand::seq::SliceRandom,
     solana_sdk::hash::hashv,
     std::marker::PhantomData,
@@ -51,7 +60,7 @@ impl From<Secp256k1TestIdentityCertificate<CosmosMessage, Sha256>> for IdentityC
             chain_id:    val.message.extract_chain_id(),
             signature:   val.signature.serialize().into(),
             recovery_id: val.recovery_id.into(),
-            pubkey:      val.recover().serialize().into(),
+            pubkey:      val.recover().serialize_compressed().into(),
             message:     Secp256k1TestIdentityCertificate::<CosmosMessage, Sha256>::hash_message(
                 &val.message,
             )
@@ -63,17 +72,80 @@ impl From<Secp256k1TestIdentityCertificate<CosmosMessage, Sha256>> for IdentityC
 
 impl Secp256k1TestIdentityCertificate<CosmosMessage, Sha256> {
     pub fn random(claimant: &Pubkey) -> Self {
+        Self::random_with_payload(&get_expected_payload(
+            DEFAULT_AUTHORIZATION_TEMPLATE,
+            claimant,
+        ))
+    }
+
+    pub fn random_with_payload(payload: &str) -> Self {
+        let chain_id = DEFAULT_COSMOS_CHAIN_IDS
+            .choose(&mut rand::thread_rng())
+            .unwrap();
+        Self::random_with_payload_and_chain_id(payload, chain_id)
+    }
+
+    pub fn random_with_payload_and_chain_id(payload: &str, chain_id: &str) -> Self {
         let secret = libsecp256k1::SecretKey::random(&mut rand::thread_rng());
         let public_key = libsecp256k1::PublicKey::from_secret_key(&secret);
-        let chain_id = ADMISSIBLE_CHAIN_IDS
-            .choose(&mut rand::thread_rng())
-            .unwrap()
-            .to_string();
 
         let message = CosmosMessage::from((
-            get_expected_payload(claimant).as_bytes(),
+            payload.as_bytes(),
+            &CompressedSecp256k1Pubkey::from(public_key.serialize_compressed())
+                .into_bech32(chain_id, &[chain_id.to_string()])
+                .unwrap(),
+        ));
+        let (signature, recovery_id) = libsecp256k1::sign(&Self::hash_message(&message), &secret);
+        Self {
+            message,
+            signature,
+            recovery_id,
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl From<Secp256k1TestIdentityCertificate<CosmosMessage, Keccak256>> for Identity {
+    fn from(val: Secp256k1TestIdentityCertificate<CosmosMessage, Keccak256>) -> Self {
+        if val.message.extract_chain_id() == INJECTIVE_CHAIN_ID {
+            Identity::Injective {
+                address: val.message.get_signer(),
+            }
+        } else {
+            Identity::Cosmwasm {
+                address: val.message.get_signer(),
+            }
+        }
+    }
+}
+
+impl From<Secp256k1TestIdentityCertificate<CosmosMessage, Keccak256>> for IdentityCertificate {
+    fn from(val: Secp256k1TestIdentityCertificate<CosmosMessage, Keccak256>) -> Self {
+        IdentityCertificate::Ethermint {
+            chain_id:    val.message.extract_chain_id(),
+            signature:   val.signature.serialize().into(),
+            recovery_id: val.recovery_id.into(),
+            pubkey:      val.recover().serialize().into(),
+            message:
+                Secp256k1TestIdentityCertificate::<CosmosMessage, Keccak256>::hash_message(
+                    &val.message,
+                )
+                .serialize()
+                .to_vec(),
+        }
+    }
+}
+
+impl Secp256k1TestIdentityCertificate<CosmosMessage, Keccak256> {
+    /// An `ethsecp256k1` key of the Ethermint chain `chain_id` signing the default payload
+    pub fn random(claimant: &Pubkey, chain_id: &str) -> Self {
+        let secret = libsecp256k1::SecretKey::random(&mut rand::thread_rng());
+        let public_key = libsecp256k1::PublicKey::from_secret_key(&secret);
+
+        let message = CosmosMessage::from((
+            get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant).as_bytes(),
             &UncompressedSecp256k1Pubkey::from(public_key.serialize())
-                .into_bech32(chain_id.as_str())
+                .into_ethermint_bech32(chain_id, &[chain_id.to_string()])
                 .unwrap(),
         ));
         let (signature, recovery_id) = libsecp256k1::sign(&Self::hash_message(&message), &secret);
@@ -86,21 +158,114 @@ impl Secp256k1TestIdentityCertificate<CosmosMessage, Sha256> {
     }
 }
 
+/**
+ * A Cosmos legacy multisig whose members `signers` signed the ADR036 message.
+ */
+#[derive(Clone)]
+pub struct CosmosMultisigTestIdentityCertificate {
+    pub multisig:   LegacyAminoPubKey,
+    pub signatures: Vec<CosmosMultisigSignature>,
+    pub message:    CosmosMessage,
+}
+
+impl CosmosMultisigTestIdentityCertificate {
+    pub fn random_with_payload(
+        payload: &str,
+        chain_id: &str,
+        threshold: u32,
+        members: usize,
+        signers: &[u8],
+    ) -> Self {
+        let secrets: Vec<libsecp256k1::SecretKey> = (0..members)
+            .map(|_| libsecp256k1::SecretKey::random(&mut rand::thread_rng()))
+            .collect();
+        let multisig = LegacyAminoPubKey {
+            threshold,
+            pubkeys: secrets
+                .iter()
+                .map(|secret| {
+                    CompressedSecp256k1Pubkey::from(
+                        libsecp256k1::PublicKey::from_secret_key(secret).serialize_compressed(),
+                    )
+                })
+                .collect(),
+        };
+        let message = CosmosMessage::from((
+            payload.as_bytes(),
+            &multisig
+                .to_bech32(chain_id, &[chain_id.to_string()])
+                .unwrap(),
+        ));
+        let hashed_message =
+            Secp256k1TestIdentityCertificate::<CosmosMessage, Sha256>::hash_message(&message);
+        let signatures = signers
+            .iter()
+            .map(|member_index| {
+                let (signature, recovery_id) =
+                    libsecp256k1::sign(&hashed_message, &secrets[*member_index as usize]);
+                CosmosMultisigSignature {
+                    member_index: *member_index,
+                    signature:    signature.serialize().into(),
+                    recovery_id:  recovery_id.into(),
+                }
+            })
+            .collect();
+        Self {
+            multisig,
+            signatures,
+            message,
+        }
+    }
+}
+
+impl From<CosmosMultisigTestIdentityCertificate> for Identity {
+    fn from(val: CosmosMultisigTestIdentityCertificate) -> Self {
+        Identity::Cosmwasm {
+            address: val.message.get_signer(),
+        }
+    }
+}
+
+impl From<CosmosMultisigTestIdentityCertificate> for IdentityCertificate {
+    fn from(val: CosmosMultisigTestIdentityCertificate) -> Self {
+        IdentityCertificate::CosmwasmMultisig {
+            chain_id:   val.message.extract_chain_id(),
+            multisig:   val.multisig,
+            signatures: val.signatures,
+            message:    Secp256k1TestIdentityCertificate::<CosmosMessage, Sha256>::hash_message(
+                &val.message,
+            )
+            .serialize()
+            .to_vec(),
+        }
+    }
+}
+
 
 #[test]
 pub fn test_authorized_cosmos_chain_ids() {
     let secret = libsecp256k1::SecretKey::random(&mut rand::thread_rng());
     let public_key = libsecp256k1::PublicKey::from_secret_key(&secret);
-    assert!(UncompressedSecp256k1Pubkey::from(public_key.serialize())
-        .into_bech32("terra")
-        .is_ok());
-    assert!(UncompressedSecp256k1Pubkey::from(public_key.serialize())
-        .into_bech32("osmo")
-        .is_ok());
+    let chain_ids = get_default_cosmos_chain_ids();
+    assert!(
+        CompressedSecp256k1Pubkey::from(public_key.serialize_compressed())
+            .into_bech32("terra", &chain_ids)
+            .is_ok()
+    );
+    assert!(
+        CompressedSecp256k1Pubkey::from(public_key.serialize_compressed())
+            .into_bech32("osmo", &chain_ids)
+            .is_ok()
+    );
     assert_eq!(
-        UncompressedSecp256k1Pubkey::from(public_key.serialize())
-            .into_bech32("cosmos")
+        CompressedSecp256k1Pubkey::from(public_key.serialize_compressed())
+            .into_bech32("cosmos", &chain_ids)
             .unwrap_err(),
         error!(ErrorCode::UnauthorizedCosmosChainId)
     );
+    assert!(
+        CompressedSecp256k1Pubkey::from(public_key.serialize_compressed())
+            .into_bech32("cosmos", &["cosmos".to_string()])
+            .is_ok()
+    );
 }
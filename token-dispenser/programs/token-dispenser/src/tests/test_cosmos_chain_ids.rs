@@ -0,0 +1,144 @@
+use {
+    super::{
+        dispenser_simulator::{
+            copy_keypair,
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_cosmos::Sha256,
+        test_happy_path::{
+            TestClaimCertificate,
+            TestIdentityCertificate,
+        },
+        test_secp256k1::Secp256k1TestIdentityCertificate,
+    },
+    crate::{
+        ecosystems::{
+            cosmos::{
+                get_default_cosmos_chain_ids,
+                CosmosMessage,
+            },
+            get_expected_payload,
+            AuthorizationPayload,
+            DEFAULT_AUTHORIZATION_TEMPLATE,
+        },
+        get_config_pda,
+        tests::merkleize,
+        ClaimInfo,
+        Config,
+        ErrorCode,
+        SolanaHasher,
+    },
+    anchor_lang::prelude::Pubkey,
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+fn random_cosmos_certificate(claimant: &Pubkey, chain_id: &str) -> TestClaimCertificate {
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity: TestIdentityCertificate::Cosmos(
+            Secp256k1TestIdentityCertificate::<CosmosMessage, Sha256>::random_with_payload_and_chain_id(
+                &get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant),
+                chain_id,
+            ),
+        ),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
+#[tokio::test]
+pub async fn test_set_cosmos_chain_ids() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let certificates = vec![
+        random_cosmos_certificate(&claimant.pubkey(), "cosmos"),
+        random_cosmos_certificate(&claimant.pubkey(), "celestia"),
+    ];
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Keypair::new().pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+
+    // Not in the default allowlist
+    for certificate in &certificates {
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            ErrorCode::UnauthorizedCosmosChainId.into_transaction_error(0)
+        );
+    }
+
+    // Only the admin can edit the allowlist, and only with valid prefixes
+    let chain_ids = vec!["cosmos".to_string(), "celestia".to_string()];
+    assert_eq!(
+        simulator
+            .set_cosmos_chain_ids(&Keypair::new(), chain_ids.clone())
+            .await
+            .unwrap_err()
+            .unwrap(),
+        anchor_lang::error::ErrorCode::ConstraintHasOne.into_transaction_error(0)
+    );
+    let admin = copy_keypair(&simulator.genesis_keypair);
+    assert_eq!(
+        simulator
+            .set_cosmos_chain_ids(&admin, vec!["Cosmos".to_string()])
+            .await
+            .unwrap_err()
+            .unwrap(),
+        ErrorCode::InvalidCosmosChainIds.into_transaction_error(0)
+    );
+    simulator
+        .set_cosmos_chain_ids(&admin, chain_ids.clone())
+        .await
+        .unwrap();
+
+    let config: Config = simulator
+        .get_account_data(get_config_pda().0)
+        .await
+        .unwrap();
+    assert_eq!(config.cosmos_chain_ids, chain_ids);
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+
+    // Chains can be removed too
+    simulator
+        .set_cosmos_chain_ids(&admin, get_default_cosmos_chain_ids())
+        .await
+        .unwrap();
+    assert_eq!(
+        simulator
+            .claim(&claimant, &certificates[0], &merkle_tree, None, None, None)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        ErrorCode::UnauthorizedCosmosChainId.into_transaction_error(0)
+    );
+}
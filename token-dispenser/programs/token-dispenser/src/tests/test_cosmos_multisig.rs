@@ -0,0 +1,158 @@
+use {
+    super::{
+        dispenser_simulator::{
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_cosmos::CosmosMultisigTestIdentityCertificate,
+        test_happy_path::{
+            TestClaimCertificate,
+            TestIdentityCertificate,
+        },
+    },
+    crate::{
+        ecosystems::{
+            get_expected_payload,
+            AuthorizationPayload,
+            DEFAULT_AUTHORIZATION_TEMPLATE,
+        },
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        SolanaHasher,
+    },
+    anchor_lang::prelude::Pubkey,
+    pythnet_sdk::accumulators::merkle::MerkleTree,
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+/// A `threshold`-of-`members` multisig on Osmosis whose members `signers` authorize `claimant`
+fn random_multisig_certificate(
+    claimant: &Pubkey,
+    threshold: u32,
+    members: usize,
+    signers: &[u8],
+) -> TestClaimCertificate {
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity: TestIdentityCertificate::CosmosMultisig(
+            CosmosMultisigTestIdentityCertificate::random_with_payload(
+                &get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant),
+                "osmo",
+                threshold,
+                members,
+                signers,
+            ),
+        ),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
+async fn setup(
+    simulator: &mut DispenserSimulator,
+    certificates: &[TestClaimCertificate],
+) -> MerkleTree<SolanaHasher> {
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Keypair::new().pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+    merkle_tree
+}
+
+#[tokio::test]
+pub async fn test_claim_with_cosmos_multisig() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let certificates = vec![
+        random_multisig_certificate(&claimant.pubkey(), 2, 3, &[0, 2]),
+        random_multisig_certificate(&claimant.pubkey(), 1, 1, &[0]),
+        // more signatures than needed
+        random_multisig_certificate(&claimant.pubkey(), 2, 3, &[0, 1, 2]),
+    ];
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_invalid_cosmos_multisig() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let mut certificates_and_errors: Vec<(TestClaimCertificate, ErrorCode)> = vec![
+        (
+            random_multisig_certificate(&claimant.pubkey(), 2, 3, &[1]),
+            ErrorCode::InvalidCosmosMultisig,
+        ),
+        (
+            random_multisig_certificate(&claimant.pubkey(), 2, 3, &[1, 1]),
+            ErrorCode::InvalidCosmosMultisig,
+        ),
+        (
+            random_multisig_certificate(&claimant.pubkey(), 2, 3, &[2, 0]),
+            ErrorCode::InvalidCosmosMultisig,
+        ),
+        (
+            random_multisig_certificate(&claimant.pubkey(), 0, 3, &[]),
+            ErrorCode::InvalidCosmosMultisig,
+        ),
+        (
+            random_multisig_certificate(&Pubkey::new_unique(), 2, 3, &[0, 1]),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            random_multisig_certificate(&claimant.pubkey(), 2, 3, &[0, 1]),
+            ErrorCode::SignatureVerificationWrongSigner,
+        ),
+    ];
+    // the signature of member 0 attributed to member 2
+    if let TestIdentityCertificate::CosmosMultisig(multisig) =
+        &mut certificates_and_errors[5].0.off_chain_proof_of_identity
+    {
+        multisig.signatures[1].member_index = 2;
+        multisig.signatures[1].signature = multisig.signatures[0].signature.clone();
+        multisig.signatures[1].recovery_id = multisig.signatures[0].recovery_id;
+    }
+    let certificates: Vec<TestClaimCertificate> = certificates_and_errors
+        .iter()
+        .map(|(certificate, _)| certificate.clone())
+        .collect();
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for (certificate, error) in &certificates_and_errors {
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            (*error).into_transaction_error(0)
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use {
+    super::dispenser_simulator::DispenserSimulator,
+    crate::{
+        get_receipt_pda,
+        tests::{
+            dispenser_simulator::IntoTransactionError,
+            merkleize,
+            test_happy_path::{
+                assert_claim_receipts_exist,
+                TestClaimCertificate,
+            },
+        },
+        ClaimInfo,
+        ErrorCode,
+        HashAlgorithm,
+        SolanaHasher,
+    },
+    anchor_lang::{
+        error::ErrorCode as AnchorErrorCode,
+        solana_program::program_option::COption,
+    },
+    anchor_spl::associated_token::get_associated_token_address,
+    cpi_caller::get_vault_pda,
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+#[tokio::test]
+pub async fn test_claim_via_cpi_with_pda_claimant() {
+    let dispenser_guard: Keypair = Keypair::new();
+
+    let mut simulator = DispenserSimulator::new().await;
+    let vault = get_vault_pda().0;
+
+    // The vault can only authorize claims by signing through the cpi caller, or by being
+    // named as the claimant in a message signed by another identity
+    let mock_offchain_certificates = vec![
+        TestClaimCertificate::random_solana(&vault),
+        TestClaimCertificate::random_evm(&vault),
+    ];
+
+    let merkle_items: Vec<ClaimInfo> = mock_offchain_certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, merkle_items_serialized) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            dispenser_guard.pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+
+    for serialized_item in &merkle_items_serialized {
+        assert!(simulator
+            .get_account(get_receipt_pda(serialized_item, HashAlgorithm::Keccak160).0)
+            .await
+            .is_none());
+    }
+
+    // Another claimant can't use the allocations of the vault
+    assert_eq!(
+        simulator
+            .claim(
+                &Keypair::new(),
+                &mock_offchain_certificates[0],
+                &merkle_tree,
+                None,
+                None,
+                None
+            )
+            .await
+            .unwrap_err()
+            .unwrap(),
+        ErrorCode::InvalidInclusionProof.into_transaction_error(0)
+    );
+
+    // Only the cpi caller can sign for the vault, so it can't claim directly
+    let mut instructions = simulator
+        .claim_instructions(
+            &vault,
+            &mock_offchain_certificates[0],
+            &merkle_tree,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    let claim_ix_index = instructions.len() - 1;
+    instructions[claim_ix_index].accounts[1].is_signer = false;
+    assert_eq!(
+        simulator
+            .process_ix(&instructions, &vec![])
+            .await
+            .unwrap_err()
+            .unwrap(),
+        AnchorErrorCode::AccountNotSigner.into_transaction_error(claim_ix_index as u8)
+    );
+
+    for offchain_claim_certificate in &mock_offchain_certificates {
+        simulator
+            .claim_via_cpi(offchain_claim_certificate, &merkle_tree)
+            .await
+            .unwrap();
+    }
+
+    assert_claim_receipts_exist(&merkle_items_serialized, &mut simulator).await;
+
+    simulator
+        .verify_token_account_data(
+            get_associated_token_address(&vault, &simulator.mint_keypair.pubkey()),
+            total_claim_sum,
+            COption::None,
+            0,
+        )
+        .await
+        .unwrap();
+
+    // Can't claim twice
+    for offchain_claim_certificate in &mock_offchain_certificates {
+        let ix_index_error = offchain_claim_certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim_via_cpi(offchain_claim_certificate, &merkle_tree)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            ErrorCode::AlreadyClaimed.into_transaction_error(ix_index_error)
+        );
+    }
+}
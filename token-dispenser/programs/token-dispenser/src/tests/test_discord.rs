@@ -12,6 +12,7 @@ use {
         },
         ClaimInfo,
         ErrorCode,
+        SolanaHasher,
     },
     solana_program_test::tokio,
     solana_sdk::signer::{
@@ -44,7 +45,7 @@ pub async fn test_discord() {
         .collect();
 
     let total_claim_sum = merkle_items.iter().fold(0, |acc, item| acc + item.amount);
-    let (merkle_tree, _) = merkleize(merkle_items);
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
     let address_lookup_table = simulator.init_lookup_table().await.unwrap();
     simulator
         .initialize(
@@ -53,6 +54,7 @@ pub async fn test_discord() {
             address_lookup_table,
             None,
             None,
+            None,
         )
         .await
         .unwrap();
@@ -1,14 +1,18 @@
 use {
     crate::{
         ecosystems::{
+            algorand::AlgorandMessage,
             aptos::AptosMessage,
+            cardano::CardanoTestMessage,
             discord::DiscordMessage,
             ed25519::{
                 Ed25519InstructionData,
                 Ed25519InstructionHeader,
                 Ed25519Pubkey,
+                Ed25519TestClaimantMessage,
                 Ed25519TestMessage,
             },
+            near::NearTestMessage,
             sui::SuiMessage,
         },
         tests::dispenser_simulator::DispenserSimulator,
@@ -36,9 +40,12 @@ pub struct Ed25519TestIdentityCertificate<T: Ed25519TestMessage> {
     pub public_key: ed25519_dalek::PublicKey,
 }
 
-impl<T: Ed25519TestMessage> Ed25519TestIdentityCertificate<T> {
+impl<T: Ed25519TestClaimantMessage> Ed25519TestIdentityCertificate<T> {
     pub fn random(claimant: &Pubkey) -> Self {
-        let message = T::for_claimant(claimant);
+        Self::random_with_message(T::for_claimant(claimant))
+    }
+
+    pub fn random_with_message(message: T) -> Self {
         let mut csprng = OsRng {};
         let keypair: Keypair = Keypair::generate(&mut csprng);
         let signature = keypair.sign(&message.get_message_with_metadata());
@@ -60,7 +67,9 @@ impl<T: Ed25519TestMessage> Ed25519TestIdentityCertificate<T> {
             public_key,
         }
     }
+}
 
+impl<T: Ed25519TestMessage> Ed25519TestIdentityCertificate<T> {
     pub fn as_instruction(&self, instruction_index: u8, valid_signature: bool) -> Instruction {
         let header = Ed25519InstructionHeader::expected_header(
             self.message.get_message_length().try_into().unwrap(),
@@ -124,6 +133,58 @@ impl Ed25519TestIdentityCertificate<SuiMessage> {
     }
 }
 
+impl From<Ed25519TestIdentityCertificate<AlgorandMessage>> for Identity {
+    fn from(val: Ed25519TestIdentityCertificate<AlgorandMessage>) -> Self {
+        Identity::Algorand {
+            address: Ed25519Pubkey::from(val.public_key.to_bytes()).into(),
+        }
+    }
+}
+
+impl Ed25519TestIdentityCertificate<AlgorandMessage> {
+    pub fn as_proof_of_identity(&self, verification_instruction_index: u8) -> IdentityCertificate {
+        IdentityCertificate::Algorand {
+            pubkey: Ed25519Pubkey::from(self.public_key.to_bytes()),
+            verification_instruction_index,
+        }
+    }
+}
+
+impl From<Ed25519TestIdentityCertificate<NearTestMessage>> for Identity {
+    fn from(val: Ed25519TestIdentityCertificate<NearTestMessage>) -> Self {
+        Identity::Near {
+            account_id: Ed25519Pubkey::from(val.public_key.to_bytes()).into(),
+        }
+    }
+}
+
+impl Ed25519TestIdentityCertificate<NearTestMessage> {
+    pub fn as_proof_of_identity(&self, verification_instruction_index: u8) -> IdentityCertificate {
+        IdentityCertificate::Near {
+            pubkey: Ed25519Pubkey::from(self.public_key.to_bytes()),
+            message: self.message.message.clone(),
+            verification_instruction_index,
+        }
+    }
+}
+
+impl From<Ed25519TestIdentityCertificate<CardanoTestMessage>> for Identity {
+    fn from(val: Ed25519TestIdentityCertificate<CardanoTestMessage>) -> Self {
+        Identity::Cardano {
+            key_hash: Ed25519Pubkey::from(val.public_key.to_bytes()).into(),
+        }
+    }
+}
+
+impl Ed25519TestIdentityCertificate<CardanoTestMessage> {
+    pub fn as_proof_of_identity(&self, verification_instruction_index: u8) -> IdentityCertificate {
+        IdentityCertificate::Cardano {
+            pubkey: Ed25519Pubkey::from(self.public_key.to_bytes()),
+            verification_instruction_index,
+        }
+    }
+}
+
 impl From<Ed25519TestIdentityCertificate<DiscordMessage>> for Identity {
     fn from(val: Ed25519TestIdentityCertificate<DiscordMessage>) -> Self {
         Identity::Discord {
@@ -0,0 +1,110 @@
+use {
+    super::{
+        dispenser_simulator::{
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_happy_path::{
+            TestClaimCertificate,
+            TestIdentityCertificate,
+        },
+    },
+    crate::{
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        Identity,
+        SolanaHasher,
+    },
+    pythnet_sdk::accumulators::merkle::MerkleTree,
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+const ETHEREUM_CHAIN_ID: u64 = 1;
+const OPTIMISM_CHAIN_ID: u64 = 10;
+
+async fn setup(
+    simulator: &mut DispenserSimulator,
+    certificates: &[TestClaimCertificate],
+) -> MerkleTree<SolanaHasher> {
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Keypair::new().pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+    merkle_tree
+}
+
+#[tokio::test]
+pub async fn test_claim_with_eip712_signature() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let certificates = vec![
+        TestClaimCertificate::random_evm_typed_data(&claimant.pubkey(), ETHEREUM_CHAIN_ID),
+        TestClaimCertificate::random_evm_typed_data(&claimant.pubkey(), OPTIMISM_CHAIN_ID),
+    ];
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        // Typed data and personal_sign signatures prove the same identity
+        assert!(matches!(
+            ClaimInfo::from(certificate.clone()).identity,
+            Identity::Evm { .. }
+        ));
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_wrong_eip712_signature() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let mut certificates = vec![
+        // signed for Ethereum, submitted for Optimism
+        TestClaimCertificate::random_evm_typed_data(&claimant.pubkey(), ETHEREUM_CHAIN_ID),
+        // signed for another claimant
+        TestClaimCertificate::random_evm_typed_data(&Keypair::new().pubkey(), ETHEREUM_CHAIN_ID),
+    ];
+    if let TestIdentityCertificate::EvmTypedData(_, chain_id) =
+        &mut certificates[0].off_chain_proof_of_identity
+    {
+        *chain_id = OPTIMISM_CHAIN_ID;
+    }
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            ErrorCode::SignatureVerificationWrongPayload.into_transaction_error(ix_index_error)
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use {
+    super::{
+        dispenser_simulator::{
+            copy_keypair,
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_happy_path::TestClaimCertificate,
+    },
+    crate::{
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        Identity,
+        SolanaHasher,
+    },
+    pythnet_sdk::accumulators::merkle::MerkleTree,
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+async fn setup(
+    simulator: &mut DispenserSimulator,
+    certificates: &[TestClaimCertificate],
+) -> MerkleTree<SolanaHasher> {
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Keypair::new().pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+    merkle_tree
+}
+
+#[tokio::test]
+pub async fn test_claim_with_ethermint_signature() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let certificates = vec![
+        TestClaimCertificate::random_ethermint(&claimant.pubkey(), "inj"),
+        TestClaimCertificate::random_ethermint(&claimant.pubkey(), "evmos"),
+    ];
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    // Injective addresses are always accepted and map to the Injective identity
+    assert!(matches!(
+        ClaimInfo::from(certificates[0].clone()).identity,
+        Identity::Injective { .. }
+    ));
+    simulator
+        .claim(&claimant, &certificates[0], &merkle_tree, None, None, None)
+        .await
+        .unwrap();
+
+    // Other Ethermint chains need to be in the allowlist
+    assert!(matches!(
+        ClaimInfo::from(certificates[1].clone()).identity,
+        Identity::Cosmwasm { .. }
+    ));
+    assert_eq!(
+        simulator
+            .claim(&claimant, &certificates[1], &merkle_tree, None, None, None)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        ErrorCode::UnauthorizedCosmosChainId.into_transaction_error(0)
+    );
+    let admin = copy_keypair(&simulator.genesis_keypair);
+    simulator
+        .set_cosmos_chain_ids(&admin, vec!["evmos".to_string()])
+        .await
+        .unwrap();
+    simulator
+        .claim(&claimant, &certificates[1], &merkle_tree, None, None, None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_wrong_ethermint_payload() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    // signed for another claimant
+    let certificates = vec![TestClaimCertificate::random_ethermint(
+        &Keypair::new().pubkey(),
+        "inj",
+    )];
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    assert_eq!(
+        simulator
+            .claim(&claimant, &certificates[0], &merkle_tree, None, None, None)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        ErrorCode::SignatureVerificationWrongPayload.into_transaction_error(0)
+    );
+}
@@ -0,0 +1,152 @@
+use {
+    super::dispenser_simulator::DispenserSimulator,
+    crate::{
+        accounts,
+        get_config_pda,
+        get_event_authority_pda,
+        instruction,
+        tests::dispenser_simulator::{
+            copy_keypair,
+            IntoTransactionError,
+        },
+    },
+    anchor_lang::{
+        error::ErrorCode as AnchorErrorCode,
+        prelude::AccountMeta,
+        solana_program::instruction::Instruction,
+        InstructionData,
+        ToAccountMetas,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+const LOG_EVENT_LOG: &str = "Program log: Instruction: LogEvent";
+
+#[tokio::test]
+pub async fn test_claim_emits_event_through_self_cpi() {
+    let dispenser_guard: Keypair = Keypair::new();
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant_1 = Keypair::new();
+
+    let (merkle_tree, mock_offchain_certificates_and_claimants) = simulator
+        .initialize_with_claimants(
+            vec![copy_keypair(&claimant_1)],
+            &dispenser_guard,
+            None,
+            false,
+        )
+        .await
+        .unwrap();
+
+    let total_claim_sum = mock_offchain_certificates_and_claimants
+        .iter()
+        .map(|(_, _, amount)| amount)
+        .sum::<u64>();
+    simulator
+        .approve_treasury_delegate(get_config_pda().0, total_claim_sum)
+        .await
+        .unwrap();
+
+    for (claimant, offchain_claim_certificates, _) in &mock_offchain_certificates_and_claimants {
+        for offchain_claim_certificate in offchain_claim_certificates {
+            let instructions = simulator
+                .claim_instructions(
+                    &claimant.pubkey(),
+                    offchain_claim_certificate,
+                    &merkle_tree,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+            let logs = simulator
+                .process_ix_with_logs(&instructions, &vec![&copy_keypair(claimant)])
+                .await
+                .unwrap();
+            assert_eq!(
+                logs.iter()
+                    .filter(|log| log.as_str() == LOG_EVENT_LOG)
+                    .count(),
+                1
+            );
+        }
+    }
+}
+
+#[tokio::test]
+pub async fn test_log_event_requires_event_authority() {
+    let mut simulator = DispenserSimulator::new().await;
+    let data = instruction::LogEvent {
+        _event: vec![1, 2, 3],
+    }
+    .data();
+
+    // The event authority PDA can't sign outside of the program
+    let instruction = Instruction::new_with_bytes(
+        crate::id(),
+        &data,
+        vec![AccountMeta::new_readonly(
+            get_event_authority_pda().0,
+            false,
+        )],
+    );
+    assert_eq!(
+        simulator
+            .process_ix(&[instruction], &vec![])
+            .await
+            .unwrap_err()
+            .unwrap(),
+        AnchorErrorCode::AccountNotSigner.into_transaction_error(0)
+    );
+
+    // Any other signer is rejected
+    let fake_authority = Keypair::new();
+    let instruction = Instruction::new_with_bytes(
+        crate::id(),
+        &data,
+        vec![AccountMeta::new_readonly(fake_authority.pubkey(), true)],
+    );
+    assert_eq!(
+        simulator
+            .process_ix(&[instruction], &vec![&fake_authority])
+            .await
+            .unwrap_err()
+            .unwrap(),
+        AnchorErrorCode::ConstraintSeeds.into_transaction_error(0)
+    );
+}
+
+#[tokio::test]
+pub async fn test_set_cosmos_chain_ids_emits_event_through_self_cpi() {
+    let dispenser_guard: Keypair = Keypair::new();
+    let mut simulator = DispenserSimulator::new().await;
+    simulator
+        .initialize_with_claimants(vec![Keypair::new()], &dispenser_guard, None, false)
+        .await
+        .unwrap();
+
+    let admin = copy_keypair(&simulator.genesis_keypair);
+    let instruction = Instruction::new_with_bytes(
+        crate::id(),
+        &instruction::SetCosmosChainIds {
+            cosmos_chain_ids: vec!["cosmos".to_string()],
+        }
+        .data(),
+        accounts::SetCosmosChainIds::populate(admin.pubkey()).to_account_metas(None),
+    );
+    let logs = simulator
+        .process_ix_with_logs(&[instruction], &vec![&admin])
+        .await
+        .unwrap();
+    assert_eq!(
+        logs.iter()
+            .filter(|log| log.as_str() == LOG_EVENT_LOG)
+            .count(),
+        1
+    );
+}
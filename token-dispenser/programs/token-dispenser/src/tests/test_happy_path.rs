@@ -1,18 +1,49 @@
 use {
     super::{
         dispenser_simulator::DispenserSimulator,
-        test_cosmos::Sha256,
+        test_algorand::{
+            AlgorandMultisigTestIdentityCertificate,
+            AlgorandRekeyedTestIdentityCertificate,
+        },
+        test_aptos::{
+            AptosMultiEd25519TestIdentityCertificate,
+            AptosRotatedTestIdentityCertificate,
+        },
+        test_bitcoin::BitcoinTestIdentityCertificate,
+        test_cosmos::{
+            CosmosMultisigTestIdentityCertificate,
+            Sha256,
+        },
         test_ed25519::Ed25519TestIdentityCertificate,
+        test_near::NearNamedTestIdentityCertificate,
         test_secp256k1::Secp256k1TestIdentityCertificate,
         test_solana::SolanaTestIdentityCertificate,
+        test_substrate::SubstrateTestIdentityCertificate,
+        test_sui::{
+            SuiMultisigTestIdentityCertificate,
+            SuiSecp256TestIdentityCertificate,
+        },
+        test_ton::TonTestIdentityCertificate,
     },
     crate::{
         ecosystems::{
+            algorand::AlgorandMessage,
             aptos::AptosMessage,
-            cosmos::CosmosMessage,
+            cardano::CardanoTestMessage,
+            cosmos::{
+                get_default_cosmos_chain_ids,
+                CosmosMessage,
+            },
             discord::DiscordMessage,
-            evm::EvmPrefixedMessage,
+            evm::{
+                Eip712Message,
+                EvmPrefixedMessage,
+            },
+            near::NearTestMessage,
             sui::SuiMessage,
+            tron::TronMessage,
+            AuthorizationPayload,
+            DEFAULT_AUTHORIZATION_TEMPLATE,
         },
         get_config_pda,
         get_receipt_pda,
@@ -24,11 +55,14 @@ use {
             merkleize,
         },
         ClaimCertificate,
+        ClaimHasher,
         ClaimInfo,
         Config,
         ErrorCode,
+        HashAlgorithm,
         Identity,
         IdentityCertificate,
+        LeafFormat,
         SolanaHasher,
     },
     anchor_lang::{
@@ -75,6 +109,7 @@ use {
 pub struct TestClaimCertificate {
     pub amount:                      u64,
     pub off_chain_proof_of_identity: TestIdentityCertificate,
+    pub authorization_payload:       AuthorizationPayload,
 }
 
 pub const MAX_AMOUNT: u64 = 1000;
@@ -88,10 +123,10 @@ impl TestClaimCertificate {
             amount:                      Self::random_amount(),
             off_chain_proof_of_identity: TestIdentityCertificate::Evm(
                 Secp256k1TestIdentityCertificate::<EvmPrefixedMessage, Keccak256>::from_secret(
-                    claimant,
-                    secret,
+                    claimant, secret,
                 ),
             ),
+            authorization_payload:       AuthorizationPayload::V1,
         }
     }
 
@@ -101,6 +136,7 @@ impl TestClaimCertificate {
             off_chain_proof_of_identity: TestIdentityCertificate::Evm(
                 Secp256k1TestIdentityCertificate::<EvmPrefixedMessage, Keccak256>::random(claimant),
             ),
+            authorization_payload:       AuthorizationPayload::V1,
         }
     }
 
@@ -110,6 +146,7 @@ impl TestClaimCertificate {
             off_chain_proof_of_identity: TestIdentityCertificate::Cosmos(
                 Secp256k1TestIdentityCertificate::<CosmosMessage, Sha256>::random(claimant),
             ),
+            authorization_payload:       AuthorizationPayload::V1,
         }
     }
 
@@ -119,6 +156,7 @@ impl TestClaimCertificate {
             off_chain_proof_of_identity: TestIdentityCertificate::Discord(
                 Ed25519TestIdentityCertificate::<DiscordMessage>::new(claimant, signer),
             ),
+            authorization_payload:       AuthorizationPayload::V1,
         }
     }
 
@@ -128,6 +166,7 @@ impl TestClaimCertificate {
             off_chain_proof_of_identity: TestIdentityCertificate::Aptos(
                 Ed25519TestIdentityCertificate::<AptosMessage>::random(claimant),
             ),
+            authorization_payload:       AuthorizationPayload::V1,
         }
     }
 
@@ -137,6 +176,7 @@ impl TestClaimCertificate {
             off_chain_proof_of_identity: TestIdentityCertificate::Sui(
                 Ed25519TestIdentityCertificate::<SuiMessage>::random(claimant),
             ),
+            authorization_payload:       AuthorizationPayload::V1,
         }
     }
 
@@ -146,6 +186,32 @@ impl TestClaimCertificate {
             off_chain_proof_of_identity: TestIdentityCertificate::Solana(
                 SolanaTestIdentityCertificate::new(claimant),
             ),
+            authorization_payload:       AuthorizationPayload::V1,
+        }
+    }
+
+    pub fn random_evm_typed_data(claimant: &Pubkey, chain_id: u64) -> Self {
+        Self {
+            amount:                      Self::random_amount(),
+            off_chain_proof_of_identity: TestIdentityCertificate::EvmTypedData(
+                Secp256k1TestIdentityCertificate::<Eip712Message, Keccak256>::random(
+                    claimant, chain_id,
+                ),
+                chain_id,
+            ),
+            authorization_payload:       AuthorizationPayload::V1,
+        }
+    }
+
+    pub fn random_ethermint(claimant: &Pubkey, chain_id: &str) -> Self {
+        Self {
+            amount:                      Self::random_amount(),
+            off_chain_proof_of_identity: TestIdentityCertificate::Ethermint(
+                Secp256k1TestIdentityCertificate::<CosmosMessage, Keccak256>::random(
+                    claimant, chain_id,
+                ),
+            ),
+            authorization_payload:       AuthorizationPayload::V1,
         }
     }
 
@@ -155,6 +221,37 @@ impl TestClaimCertificate {
             off_chain_proof_of_identity: TestIdentityCertificate::Injective(
                 Secp256k1TestIdentityCertificate::<EvmPrefixedMessage, Keccak256>::random(claimant),
             ),
+            authorization_payload:       AuthorizationPayload::V1,
+        }
+    }
+
+    pub fn random_near(claimant: &Pubkey) -> Self {
+        Self {
+            amount:                      Self::random_amount(),
+            off_chain_proof_of_identity: TestIdentityCertificate::Near(
+                Ed25519TestIdentityCertificate::<NearTestMessage>::random(claimant),
+            ),
+            authorization_payload:       AuthorizationPayload::V1,
+        }
+    }
+
+    pub fn random_tron(claimant: &Pubkey) -> Self {
+        Self {
+            amount:                      Self::random_amount(),
+            off_chain_proof_of_identity: TestIdentityCertificate::Tron(
+                Secp256k1TestIdentityCertificate::<TronMessage, Keccak256>::random(claimant),
+            ),
+            authorization_payload:       AuthorizationPayload::V1,
+        }
+    }
+
+    pub fn random_algorand(claimant: &Pubkey) -> Self {
+        Self {
+            amount:                      Self::random_amount(),
+            off_chain_proof_of_identity: TestIdentityCertificate::Algorand(
+                Ed25519TestIdentityCertificate::<AlgorandMessage>::random(claimant),
+            ),
+            authorization_payload:       AuthorizationPayload::V1,
         }
     }
 }
@@ -169,34 +266,59 @@ impl From<TestClaimCertificate> for ClaimInfo {
 }
 
 impl TestClaimCertificate {
-    pub fn as_claim_certificate(
+    pub fn as_claim_certificate<H: ClaimHasher>(
         &self,
-        merkle_tree: &MerkleTree<SolanaHasher>,
+        merkle_tree: &MerkleTree<H>,
         index: u8,
-        proof_of_inclusion_override: Option<MerklePath<SolanaHasher>>,
-    ) -> (ClaimCertificate, Option<Instruction>) {
-        let option_instruction = match &self.off_chain_proof_of_identity {
-            TestIdentityCertificate::Evm(evm) => Some(evm.as_instruction(index, true)),
-            TestIdentityCertificate::Discord(discord) => Some(discord.as_instruction(index, true)),
-            TestIdentityCertificate::Cosmos(_) => None,
-            TestIdentityCertificate::Aptos(aptos) => Some(aptos.as_instruction(index, true)),
-            TestIdentityCertificate::Sui(sui) => Some(sui.as_instruction(index, true)),
-            TestIdentityCertificate::Solana(_) => None,
+        proof_of_inclusion_override: Option<MerklePath<H>>,
+    ) -> (ClaimCertificate, Vec<Instruction>) {
+        let verification_instructions = match &self.off_chain_proof_of_identity {
+            TestIdentityCertificate::Evm(evm) => vec![evm.as_instruction(index, true)],
+            TestIdentityCertificate::Discord(discord) => vec![discord.as_instruction(index, true)],
+            TestIdentityCertificate::Cosmos(_) => vec![],
+            TestIdentityCertificate::Aptos(aptos) => vec![aptos.as_instruction(index, true)],
+            TestIdentityCertificate::Sui(sui) => vec![sui.as_instruction(index, true)],
+            TestIdentityCertificate::Solana(_) => vec![],
             TestIdentityCertificate::Injective(injective) => {
-                Some(injective.as_instruction(index, true))
+                vec![injective.as_instruction(index, true)]
+            }
+            TestIdentityCertificate::EvmTypedData(evm, _) => vec![evm.as_instruction(index, true)],
+            TestIdentityCertificate::EvmSiwe(evm) => vec![evm.as_instruction(index, true)],
+            TestIdentityCertificate::Ethermint(_) => vec![],
+            TestIdentityCertificate::CosmosMultisig(_) => vec![],
+            TestIdentityCertificate::AptosMultiEd25519(aptos) => aptos.as_instructions(index),
+            TestIdentityCertificate::AptosRotated(aptos) => aptos.as_instructions(index),
+            TestIdentityCertificate::SuiSecp256(_) => vec![],
+            TestIdentityCertificate::SuiMultisig(sui) => sui.as_instructions(index),
+            TestIdentityCertificate::Algorand(algorand) => {
+                vec![algorand.as_instruction(index, true)]
+            }
+            TestIdentityCertificate::AlgorandMultisig(algorand) => algorand.as_instructions(index),
+            TestIdentityCertificate::AlgorandRekeyed(algorand) => algorand.as_instructions(index),
+            TestIdentityCertificate::Bitcoin(_) => vec![],
+            TestIdentityCertificate::Near(near) => vec![near.as_instruction(index, true)],
+            TestIdentityCertificate::NearNamed(near) => near.as_instructions(index),
+            TestIdentityCertificate::Tron(tron) => vec![tron.as_instruction(index, true)],
+            TestIdentityCertificate::Ton(ton) => ton.as_instructions(index),
+            TestIdentityCertificate::Substrate(substrate) => {
+                vec![substrate.signature.as_instruction(index, true)]
             }
+            TestIdentityCertificate::Cardano(cardano) => vec![cardano.as_instruction(index, true)],
         };
         (
             ClaimCertificate {
-                amount:             self.amount,
-                proof_of_identity:  self.off_chain_proof_of_identity.as_claim_certificate(index),
-                proof_of_inclusion: proof_of_inclusion_override.unwrap_or(
-                    merkle_tree
-                        .prove(&Into::<ClaimInfo>::into(self.clone()).try_to_vec().unwrap())
-                        .unwrap(),
-                ),
+                amount:                self.amount,
+                proof_of_identity:     self.off_chain_proof_of_identity.as_claim_certificate(index),
+                proof_of_inclusion:    proof_of_inclusion_override
+                    .unwrap_or_else(|| {
+                        merkle_tree
+                            .prove(&Into::<ClaimInfo>::into(self.clone()).try_to_vec().unwrap())
+                            .unwrap()
+                    })
+                    .into(),
+                authorization_payload: self.authorization_payload.clone(),
             },
-            option_instruction,
+            verification_instructions,
         )
     }
 }
@@ -211,6 +333,24 @@ impl From<TestIdentityCertificate> for Identity {
             TestIdentityCertificate::Sui(sui) => sui.into(),
             TestIdentityCertificate::Solana(solana) => solana.into(),
             TestIdentityCertificate::Injective(injective) => injective.into(),
+            TestIdentityCertificate::EvmTypedData(evm, _) => evm.into(),
+            TestIdentityCertificate::EvmSiwe(evm) => evm.into(),
+            TestIdentityCertificate::Ethermint(ethermint) => ethermint.into(),
+            TestIdentityCertificate::CosmosMultisig(multisig) => multisig.into(),
+            TestIdentityCertificate::AptosMultiEd25519(aptos) => aptos.into(),
+            TestIdentityCertificate::AptosRotated(aptos) => aptos.into(),
+            TestIdentityCertificate::SuiSecp256(sui) => sui.into(),
+            TestIdentityCertificate::SuiMultisig(sui) => sui.into(),
+            TestIdentityCertificate::Algorand(algorand) => algorand.into(),
+            TestIdentityCertificate::AlgorandMultisig(algorand) => algorand.into(),
+            TestIdentityCertificate::AlgorandRekeyed(algorand) => algorand.into(),
+            TestIdentityCertificate::Bitcoin(bitcoin) => bitcoin.into(),
+            TestIdentityCertificate::Near(near) => near.into(),
+            TestIdentityCertificate::NearNamed(near) => near.into(),
+            TestIdentityCertificate::Tron(tron) => tron.into(),
+            TestIdentityCertificate::Ton(ton) => ton.into(),
+            TestIdentityCertificate::Substrate(substrate) => substrate.into(),
+            TestIdentityCertificate::Cardano(cardano) => cardano.into(),
         }
     }
 }
@@ -227,16 +367,46 @@ impl TestIdentityCertificate {
             Self::Injective(injective) => {
                 injective.as_proof_of_identity(verification_instruction_index)
             }
+            Self::EvmTypedData(evm, chain_id) => {
+                evm.as_proof_of_identity(*chain_id, verification_instruction_index)
+            }
+            Self::EvmSiwe(evm) => evm.as_siwe_proof_of_identity(verification_instruction_index),
+            Self::Ethermint(ethermint) => ethermint.clone().into(),
+            Self::CosmosMultisig(multisig) => multisig.clone().into(),
+            Self::AptosMultiEd25519(aptos) => {
+                aptos.as_proof_of_identity(verification_instruction_index)
+            }
+            Self::AptosRotated(aptos) => aptos.as_proof_of_identity(verification_instruction_index),
+            Self::SuiSecp256(sui) => sui.clone().into(),
+            Self::SuiMultisig(sui) => sui.as_proof_of_identity(verification_instruction_index),
+            Self::Algorand(algorand) => {
+                algorand.as_proof_of_identity(verification_instruction_index)
+            }
+            Self::AlgorandMultisig(algorand) => {
+                algorand.as_proof_of_identity(verification_instruction_index)
+            }
+            Self::AlgorandRekeyed(algorand) => {
+                algorand.as_proof_of_identity(verification_instruction_index)
+            }
+            Self::Bitcoin(bitcoin) => bitcoin.clone().into(),
+            Self::Near(near) => near.as_proof_of_identity(verification_instruction_index),
+            Self::NearNamed(near) => near.as_proof_of_identity(verification_instruction_index),
+            Self::Tron(tron) => tron.as_proof_of_identity(verification_instruction_index),
+            Self::Ton(ton) => ton.as_proof_of_identity(verification_instruction_index),
+            Self::Substrate(substrate) => {
+                substrate.as_proof_of_identity(verification_instruction_index)
+            }
+            Self::Cardano(cardano) => cardano.as_proof_of_identity(verification_instruction_index),
         }
     }
 }
 
 impl TestClaimCertificate {
-    pub fn as_instruction_error_index(&self, merkle_tree: &MerkleTree<SolanaHasher>) -> u8 {
-        match self.as_claim_certificate(merkle_tree, 0, None).1 {
-            Some(_) => 1,
-            None => 0,
-        }
+    pub fn as_instruction_error_index<H: ClaimHasher>(&self, merkle_tree: &MerkleTree<H>) -> u8 {
+        // The proof doesn't matter here, and the leaf might not be encoded with the V1 format
+        self.as_claim_certificate(merkle_tree, 0, Some(MerklePath::new(vec![])))
+            .1
+            .len() as u8
     }
 }
 
@@ -249,6 +419,29 @@ pub enum TestIdentityCertificate {
     Sui(Ed25519TestIdentityCertificate<SuiMessage>),
     Solana(SolanaTestIdentityCertificate),
     Injective(Secp256k1TestIdentityCertificate<EvmPrefixedMessage, Keccak256>),
+    /// The certificate and the chain id of the typed data
+    EvmTypedData(
+        Secp256k1TestIdentityCertificate<Eip712Message, Keccak256>,
+        u64,
+    ),
+    /// Signs a Sign-In with Ethereum message
+    EvmSiwe(Secp256k1TestIdentityCertificate<EvmPrefixedMessage, Keccak256>),
+    Ethermint(Secp256k1TestIdentityCertificate<CosmosMessage, Keccak256>),
+    CosmosMultisig(CosmosMultisigTestIdentityCertificate),
+    AptosMultiEd25519(AptosMultiEd25519TestIdentityCertificate),
+    AptosRotated(AptosRotatedTestIdentityCertificate),
+    SuiSecp256(SuiSecp256TestIdentityCertificate),
+    SuiMultisig(SuiMultisigTestIdentityCertificate),
+    Algorand(Ed25519TestIdentityCertificate<AlgorandMessage>),
+    AlgorandMultisig(AlgorandMultisigTestIdentityCertificate),
+    AlgorandRekeyed(AlgorandRekeyedTestIdentityCertificate),
+    Bitcoin(BitcoinTestIdentityCertificate),
+    Near(Ed25519TestIdentityCertificate<NearTestMessage>),
+    NearNamed(NearNamedTestIdentityCertificate),
+    Tron(Secp256k1TestIdentityCertificate<TronMessage, Keccak256>),
+    Ton(TonTestIdentityCertificate),
+    Substrate(SubstrateTestIdentityCertificate),
+    Cardano(Ed25519TestIdentityCertificate<CardanoTestMessage>),
 }
 
 #[tokio::test]
@@ -268,7 +461,7 @@ pub async fn test_happy_path() {
         .map(|item: &TestClaimCertificate| item.clone().into())
         .collect();
 
-    let (merkle_tree, merkle_items_serialized) = merkleize(merkle_items);
+    let (merkle_tree, merkle_items_serialized) = merkleize::<SolanaHasher>(merkle_items);
 
     let (config_pubkey, config_bump) = get_config_pda();
     let treasury = simulator.pyth_treasury;
@@ -290,22 +483,30 @@ pub async fn test_happy_path() {
             address_lookup_table,
             None,
             None,
+            None,
         )
         .await
         .unwrap();
 
     let expected_target_config = Config {
         bump: config_bump,
-        merkle_root: merkle_tree.root.clone(),
+        merkle_root: merkle_tree.root.clone().into(),
         dispenser_guard: dispenser_guard.pubkey(),
         mint: simulator.mint_keypair.pubkey(),
         address_lookup_table,
         max_transfer: u64::MAX,
+        leaf_format: LeafFormat::V1,
+        genesis_hash: simulator.genesis_hash,
+        min_payload_version: 1,
+        authorization_template: DEFAULT_AUTHORIZATION_TEMPLATE.to_string(),
+        admin: simulator.genesis_keypair.pubkey(),
+        cosmos_chain_ids: get_default_cosmos_chain_ids(),
     };
 
 
     let config_account: Account = simulator.get_account(config_pubkey).await.unwrap();
-    let config_data: Config = Config::try_from_slice(&config_account.data[8..]).unwrap();
+    // `Config::LEN` fits the widest merkle root, so there can be trailing bytes
+    let config_data: Config = Config::deserialize(&mut &config_account.data[8..]).unwrap();
     assert_eq!(expected_target_config, config_data);
     let claim_sum = mock_offchain_certificates
         .iter()
@@ -320,7 +521,7 @@ pub async fn test_happy_path() {
     // verify receipt pdas don't exist
     for serialized_item in &merkle_items_serialized {
         assert!(simulator
-            .get_account(get_receipt_pda(serialized_item).0)
+            .get_account(get_receipt_pda(serialized_item, HashAlgorithm::Keccak160).0)
             .await
             .is_none());
     }
@@ -448,7 +649,7 @@ pub async fn assert_claim_receipts_exist(
 ) {
     for serialized_item in claimed_items_serialized {
         let receipt_account: Account = simulator
-            .get_account(get_receipt_pda(serialized_item).0)
+            .get_account(get_receipt_pda(serialized_item, HashAlgorithm::Keccak160).0)
             .await
             .unwrap();
 
@@ -0,0 +1,150 @@
+use {
+    super::dispenser_simulator::DispenserSimulator,
+    crate::{
+        get_config_pda,
+        get_receipt_pda,
+        tests::{
+            merkleize,
+            test_happy_path::TestClaimCertificate,
+        },
+        ClaimHasher,
+        ClaimInfo,
+        ClaimMerklePath,
+        ClaimMerkleRoot,
+        Config,
+        HashAlgorithm,
+        Identity,
+        Keccak256Hasher,
+        Sha256Hasher,
+        SolanaHasher,
+    },
+    pythnet_sdk::accumulators::Accumulator,
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+const HASH_ALGORITHMS: [HashAlgorithm; 3] = [
+    HashAlgorithm::Keccak160,
+    HashAlgorithm::Keccak256,
+    HashAlgorithm::Sha256,
+];
+
+async fn test_claim_with_hasher<H: ClaimHasher>() {
+    let dispenser_guard: Keypair = Keypair::new();
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let mock_offchain_certificates =
+        DispenserSimulator::generate_test_claim_certs(&claimant.pubkey(), &dispenser_guard, false);
+    let merkle_items: Vec<ClaimInfo> = mock_offchain_certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, merkle_items_serialized) = merkleize::<H>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            dispenser_guard.pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+
+    let config = simulator
+        .get_account_data::<Config>(get_config_pda().0)
+        .await
+        .unwrap();
+    assert_eq!(config.merkle_root.hash_algorithm(), H::HASH_ALGORITHM);
+
+    for offchain_claim_certificate in &mock_offchain_certificates {
+        simulator
+            .claim(
+                &claimant,
+                offchain_claim_certificate,
+                &merkle_tree,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    // Receipts are derived from the leaf hash of the configured hash algorithm
+    for serialized_item in &merkle_items_serialized {
+        for hash_algorithm in HASH_ALGORITHMS {
+            assert_eq!(
+                simulator
+                    .get_account(get_receipt_pda(serialized_item, hash_algorithm).0)
+                    .await
+                    .is_some(),
+                hash_algorithm == H::HASH_ALGORITHM
+            );
+        }
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_with_keccak160() {
+    test_claim_with_hasher::<SolanaHasher>().await;
+}
+
+#[tokio::test]
+pub async fn test_claim_with_keccak256() {
+    test_claim_with_hasher::<Keccak256Hasher>().await;
+}
+
+#[tokio::test]
+pub async fn test_claim_with_sha256() {
+    test_claim_with_hasher::<Sha256Hasher>().await;
+}
+
+#[test]
+pub fn test_hash_leaf() {
+    let leaf = b"leaf";
+    assert_eq!(HashAlgorithm::Keccak160.hash_leaf(leaf).len(), 20);
+    assert_eq!(HashAlgorithm::Keccak256.hash_leaf(leaf).len(), 32);
+    assert_eq!(HashAlgorithm::Sha256.hash_leaf(leaf).len(), 32);
+    assert_eq!(
+        HashAlgorithm::Keccak160.hash_leaf(leaf),
+        HashAlgorithm::Keccak256.hash_leaf(leaf)[..20]
+    );
+    assert_ne!(
+        HashAlgorithm::Keccak256.hash_leaf(leaf),
+        HashAlgorithm::Sha256.hash_leaf(leaf)
+    );
+}
+
+#[test]
+pub fn test_proof_must_match_hash_algorithm() {
+    let merkle_items: Vec<ClaimInfo> = ["one", "two", "three"]
+        .iter()
+        .map(|username| ClaimInfo {
+            identity: Identity::Discord {
+                username: username.to_string(),
+            },
+            amount:   1000,
+        })
+        .collect();
+    let (keccak256_tree, merkle_items_serialized) =
+        merkleize::<Keccak256Hasher>(merkle_items.clone());
+    let (sha256_tree, _) = merkleize::<Sha256Hasher>(merkle_items);
+    let leaf = &merkle_items_serialized[0];
+
+    let keccak256_root: ClaimMerkleRoot = keccak256_tree.root.clone().into();
+    let keccak256_proof: ClaimMerklePath = keccak256_tree.prove(leaf).unwrap().into();
+    let sha256_proof: ClaimMerklePath = sha256_tree.prove(leaf).unwrap().into();
+
+    assert!(keccak256_root.check(keccak256_proof, leaf));
+    assert!(!keccak256_root.check(sha256_proof, leaf));
+}
@@ -1,12 +1,15 @@
 use {
     super::dispenser_simulator::DispenserSimulator,
     crate::{
+        ecosystems::AuthorizationPayload,
         tests::{
             dispenser_simulator::IntoTransactionError,
             merkleize,
             test_happy_path::TestClaimCertificate,
         },
         ClaimInfo,
+        ErrorCode,
+        SolanaHasher,
     },
     solana_program_test::tokio,
     solana_sdk::{
@@ -31,7 +34,7 @@ pub async fn test_initialize_fails_with_incorrect_accounts() {
         .map(|item: &TestClaimCertificate| item.clone().into())
         .collect();
 
-    let (merkle_tree, _) = merkleize(merkle_items);
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
 
     let address_lookup_table = simulator.init_lookup_table().await.unwrap();
 
@@ -42,6 +45,7 @@ pub async fn test_initialize_fails_with_incorrect_accounts() {
                 dispenser_guard.pubkey(),
                 address_lookup_table,
                 Some(Keypair::new().pubkey()), //invalid mint
+                None,
                 None
             )
             .await
@@ -50,3 +54,108 @@ pub async fn test_initialize_fails_with_incorrect_accounts() {
         anchor_lang::error::ErrorCode::AccountNotInitialized.into_transaction_error(0)
     );
 }
+
+#[tokio::test]
+pub async fn test_initialize_fails_with_invalid_authorization_template() {
+    let dispenser_guard: Keypair = Keypair::new();
+
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = simulator.genesis_keypair.pubkey();
+
+    let merkle_items: Vec<ClaimInfo> =
+        DispenserSimulator::generate_test_claim_certs(&claimant, &dispenser_guard, false)
+            .into_iter()
+            .map(|item: TestClaimCertificate| item.into())
+            .collect();
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+
+    // doesn't commit to the claimant
+    simulator.authorization_template = "Acme Airdrop PID:\n{program_id}\n".to_string();
+
+    assert_eq!(
+        simulator
+            .initialize(
+                merkle_tree.root.clone(),
+                dispenser_guard.pubkey(),
+                address_lookup_table,
+                None,
+                None,
+                None
+            )
+            .await
+            .unwrap_err()
+            .unwrap(),
+        ErrorCode::InvalidAuthorizationTemplate.into_transaction_error(0)
+    );
+}
+
+#[tokio::test]
+pub async fn test_initialize_fails_with_unsupported_min_payload_version() {
+    let dispenser_guard: Keypair = Keypair::new();
+
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = simulator.genesis_keypair.pubkey();
+
+    let merkle_items: Vec<ClaimInfo> =
+        DispenserSimulator::generate_test_claim_certs(&claimant, &dispenser_guard, false)
+            .into_iter()
+            .map(|item: TestClaimCertificate| item.into())
+            .collect();
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+
+    // no identity could sign a payload of this version
+    simulator.min_payload_version = AuthorizationPayload::LATEST_VERSION + 1;
+
+    assert_eq!(
+        simulator
+            .initialize(
+                merkle_tree.root.clone(),
+                dispenser_guard.pubkey(),
+                address_lookup_table,
+                None,
+                None,
+                None
+            )
+            .await
+            .unwrap_err()
+            .unwrap(),
+        ErrorCode::UnsupportedAuthorizationPayloadVersion.into_transaction_error(0)
+    );
+}
+
+#[tokio::test]
+pub async fn test_initialize_fails_with_v1_template_when_v2_is_required() {
+    let dispenser_guard: Keypair = Keypair::new();
+
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = simulator.genesis_keypair.pubkey();
+
+    let merkle_items: Vec<ClaimInfo> =
+        DispenserSimulator::generate_test_claim_certs(&claimant, &dispenser_guard, false)
+            .into_iter()
+            .map(|item: TestClaimCertificate| item.into())
+            .collect();
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+
+    // the default template doesn't commit to the amount, the cluster, the expiry and the nonce
+    simulator.min_payload_version = 2;
+
+    assert_eq!(
+        simulator
+            .initialize(
+                merkle_tree.root.clone(),
+                dispenser_guard.pubkey(),
+                address_lookup_table,
+                None,
+                None,
+                None
+            )
+            .await
+            .unwrap_err()
+            .unwrap(),
+        ErrorCode::InvalidAuthorizationTemplate.into_transaction_error(0)
+    );
+}
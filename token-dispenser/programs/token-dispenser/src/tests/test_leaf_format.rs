@@ -0,0 +1,175 @@
+use {
+    super::dispenser_simulator::DispenserSimulator,
+    crate::{
+        get_receipt_pda,
+        tests::{
+            dispenser_simulator::IntoTransactionError,
+            merkleize,
+            merkleize_with_leaf_format,
+            test_happy_path::TestClaimCertificate,
+        },
+        ClaimInfo,
+        ErrorCode,
+        HashAlgorithm,
+        LeafFormat,
+        SolanaHasher,
+    },
+    pythnet_sdk::accumulators::{
+        merkle::MerkleTree,
+        Accumulator,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+const CAMPAIGN_ID: u64 = 7;
+
+/// Initializes the dispenser with `leaf_format` and a tree built by `build_tree`
+async fn setup(
+    leaf_format: LeafFormat,
+    build_tree: impl FnOnce(Vec<ClaimInfo>, &LeafFormat, &Keypair) -> MerkleTree<SolanaHasher>,
+) -> (
+    DispenserSimulator,
+    Keypair,
+    Vec<TestClaimCertificate>,
+    MerkleTree<SolanaHasher>,
+) {
+    let dispenser_guard: Keypair = Keypair::new();
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let mock_offchain_certificates =
+        DispenserSimulator::generate_test_claim_certs(&claimant.pubkey(), &dispenser_guard, false);
+    let merkle_items: Vec<ClaimInfo> = mock_offchain_certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let merkle_tree = build_tree(merkle_items, &leaf_format, &simulator.mint_keypair);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            dispenser_guard.pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            Some(leaf_format),
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+
+    (simulator, claimant, mock_offchain_certificates, merkle_tree)
+}
+
+#[tokio::test]
+pub async fn test_claim_with_leaf_format_v2() {
+    let leaf_format = LeafFormat::V2 {
+        campaign_id: CAMPAIGN_ID,
+    };
+    let (mut simulator, claimant, mock_offchain_certificates, merkle_tree) =
+        setup(leaf_format.clone(), |merkle_items, leaf_format, mint| {
+            merkleize_with_leaf_format(merkle_items, leaf_format, &mint.pubkey()).0
+        })
+        .await;
+
+    for offchain_claim_certificate in &mock_offchain_certificates {
+        simulator
+            .claim(
+                &claimant,
+                offchain_claim_certificate,
+                &merkle_tree,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let leaf = leaf_format
+            .encode_leaf(
+                &offchain_claim_certificate.clone().into(),
+                &simulator.mint_keypair.pubkey(),
+            )
+            .unwrap();
+        assert!(simulator
+            .get_account(get_receipt_pda(&leaf, HashAlgorithm::Keccak160).0)
+            .await
+            .is_some());
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_wrong_leaf_format() {
+    // The tree commits to another campaign
+    let other_campaign = LeafFormat::V2 {
+        campaign_id: CAMPAIGN_ID + 1,
+    };
+    let (mut simulator, claimant, mock_offchain_certificates, merkle_tree) = setup(
+        LeafFormat::V2 {
+            campaign_id: CAMPAIGN_ID,
+        },
+        |merkle_items, _, mint| {
+            merkleize_with_leaf_format(merkle_items, &other_campaign, &mint.pubkey()).0
+        },
+    )
+    .await;
+
+    for offchain_claim_certificate in &mock_offchain_certificates {
+        let ix_index_error = offchain_claim_certificate.as_instruction_error_index(&merkle_tree);
+        let other_campaign_leaf = other_campaign
+            .encode_leaf(
+                &offchain_claim_certificate.clone().into(),
+                &simulator.mint_keypair.pubkey(),
+            )
+            .unwrap();
+        assert_eq!(
+            simulator
+                .claim(
+                    &claimant,
+                    offchain_claim_certificate,
+                    &merkle_tree,
+                    None,
+                    merkle_tree.prove(&other_campaign_leaf),
+                    None,
+                )
+                .await
+                .unwrap_err()
+                .unwrap(),
+            ErrorCode::InvalidInclusionProof.into_transaction_error(ix_index_error)
+        );
+    }
+
+    // The tree uses the V1 leaf format
+    let (mut simulator, claimant, mock_offchain_certificates, merkle_tree) = setup(
+        LeafFormat::V2 {
+            campaign_id: CAMPAIGN_ID,
+        },
+        |merkle_items, _, _| merkleize(merkle_items).0,
+    )
+    .await;
+
+    for offchain_claim_certificate in &mock_offchain_certificates {
+        let ix_index_error = offchain_claim_certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(
+                    &claimant,
+                    offchain_claim_certificate,
+                    &merkle_tree,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap_err()
+                .unwrap(),
+            ErrorCode::InvalidInclusionProof.into_transaction_error(ix_index_error)
+        );
+    }
+}
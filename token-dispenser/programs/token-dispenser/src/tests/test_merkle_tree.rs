@@ -1,7 +1,9 @@
 use {
     crate::{
         ClaimInfo,
+        HashAlgorithm,
         Identity,
+        LeafFormat,
         SolanaHasher,
     },
     anchor_lang::AnchorSerialize,
@@ -101,3 +103,48 @@ fn test_merkle_tree() {
         );
     }
 }
+
+/**
+ * Pins the V2 leaf encoding. The same vectors are checked against the TS SDK in
+ * `ts/tests/leaf-format.ts`.
+ */
+#[test]
+fn test_leaf_format_v2_vectors() {
+    let mut evm_pubkey: [u8; 20] = [0u8; 20];
+    evm_pubkey.copy_from_slice(&hex::decode("f3f9225A2166861e745742509CED164183a626d7").unwrap());
+
+    let mint = pubkey!("3kzAHeiucNConBwKQVHyLcG3soaMzSZkvs4y14fmMgKL");
+    let leaf_format = LeafFormat::V2 { campaign_id: 1 };
+
+    let vectors: Vec<(ClaimInfo, &str, &str)> = vec![
+        (
+            ClaimInfo {
+                amount:   1000,
+                identity: Identity::Discord {
+                    username: "pepito".to_string(),
+                },
+            },
+            "02079416ce881d9a7c80ac9c7758ff6548817e4a910e0cc3ebd9d28bc54ceeff0d010000000000000028fbfad41a14ea06b29beb7f24b3d71b14410ecd233c81e8848f045cea4a1d03000600000070657069746fe803000000000000",
+            "3c9325e9b4f38a26e4f6cd0cf513bb99aab0492b",
+        ),
+        (
+            ClaimInfo {
+                amount:   2000,
+                identity: Identity::Evm {
+                    pubkey: evm_pubkey.into(),
+                },
+            },
+            "02079416ce881d9a7c80ac9c7758ff6548817e4a910e0cc3ebd9d28bc54ceeff0d010000000000000028fbfad41a14ea06b29beb7f24b3d71b14410ecd233c81e8848f045cea4a1d0302f3f9225a2166861e745742509ced164183a626d7d007000000000000",
+            "2256022e87b163d1788c1e408c9a2542f86004c4",
+        ),
+    ];
+
+    for (claim_info, expected_leaf, expected_leaf_hash) in vectors {
+        let leaf = leaf_format.encode_leaf(&claim_info, &mint).unwrap();
+        assert_eq!(hex::encode(&leaf), expected_leaf);
+        assert_eq!(
+            hex::encode(HashAlgorithm::Keccak160.hash_leaf(&leaf)),
+            expected_leaf_hash
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use {
+    super::{
+        dispenser_simulator::{
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_ed25519::Ed25519TestIdentityCertificate,
+        test_happy_path::{
+            TestClaimCertificate,
+            TestIdentityCertificate,
+        },
+    },
+    crate::{
+        ecosystems::{
+            discord::DiscordMessage,
+            ed25519::{
+                Ed25519Pubkey,
+                Ed25519TestClaimantMessage,
+                Ed25519TestMessage,
+            },
+            near::{
+                NearAccountId,
+                NearKeyLinkMessage,
+                NearTestMessage,
+            },
+            AuthorizationPayload,
+        },
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        Identity,
+        IdentityCertificate,
+        SolanaHasher,
+    },
+    anchor_lang::{
+        error,
+        prelude::Pubkey,
+    },
+    ed25519_dalek::{
+        Keypair,
+        Signer,
+    },
+    pythnet_sdk::accumulators::merkle::MerkleTree,
+    rand_compatible::rngs::OsRng,
+    solana_program_test::tokio,
+    solana_sdk::instruction::Instruction,
+};
+
+/**
+ * A named NEAR account `account_id` whose key signs the NEP-413 message, and the attestation of
+ * the dispenser guard linking that key to `account_id`.
+ */
+#[derive(Clone)]
+pub struct NearNamedTestIdentityCertificate {
+    pub account_id:  NearAccountId,
+    pub signature:   Ed25519TestIdentityCertificate<NearTestMessage>,
+    pub attestation: Ed25519TestIdentityCertificate<NearKeyLinkMessage>,
+}
+
+impl NearNamedTestIdentityCertificate {
+    pub fn random(claimant: &Pubkey, account_id: &str, dispenser_guard: &Keypair) -> Self {
+        let signature = Ed25519TestIdentityCertificate::<NearTestMessage>::random(claimant);
+        let account_id = NearAccountId::from(account_id);
+        let message = NearKeyLinkMessage::new(
+            account_id.clone(),
+            Ed25519Pubkey::from(signature.public_key.to_bytes()),
+        );
+        Self {
+            account_id,
+            signature,
+            attestation: Ed25519TestIdentityCertificate {
+                signature: dispenser_guard.sign(&message.get_message_with_metadata()),
+                public_key: dispenser_guard.public,
+                message,
+            },
+        }
+    }
+
+    pub fn as_instructions(&self, instruction_index: u8) -> Vec<Instruction> {
+        vec![
+            self.signature.as_instruction(instruction_index, true),
+            self.attestation.as_instruction(instruction_index + 1, true),
+        ]
+    }
+
+    pub fn as_proof_of_identity(&self, verification_instruction_index: u8) -> IdentityCertificate {
+        IdentityCertificate::NearNamed {
+            account_id: self.account_id.clone(),
+            pubkey: self.signature.public_key.to_bytes().into(),
+            message: self.signature.message.message.clone(),
+            verification_instruction_index,
+            attestation_instruction_index: verification_instruction_index + 1,
+        }
+    }
+}
+
+impl From<NearNamedTestIdentityCertificate> for Identity {
+    fn from(val: NearNamedTestIdentityCertificate) -> Self {
+        Identity::Near {
+            account_id: val.account_id,
+        }
+    }
+}
+
+async fn setup(
+    simulator: &mut DispenserSimulator,
+    certificates: &[TestClaimCertificate],
+    dispenser_guard: &Keypair,
+) -> MerkleTree<SolanaHasher> {
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Pubkey::new_from_array(dispenser_guard.public.to_bytes()),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+    merkle_tree
+}
+
+fn named_certificate(
+    claimant: &Pubkey,
+    account_id: &str,
+    dispenser_guard: &Keypair,
+) -> TestClaimCertificate {
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity: TestIdentityCertificate::NearNamed(
+            NearNamedTestIdentityCertificate::random(claimant, account_id, dispenser_guard),
+        ),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
+#[test]
+pub fn test_near_key_link_message() {
+    let claimant = Pubkey::new_unique();
+    let account_id = NearAccountId::from(claimant.to_string().as_str());
+    let pubkey = Ed25519Pubkey::from(claimant);
+    let message = NearKeyLinkMessage::new(account_id.clone(), pubkey.clone());
+    assert!(NearKeyLinkMessage::parse_and_check_account_id_and_pubkey(
+        &message.get_message_with_metadata(),
+        &account_id,
+        &pubkey
+    )
+    .is_ok());
+
+    // A Discord attestation has the same borsh layout
+    assert_eq!(
+        NearKeyLinkMessage::parse_and_check_account_id_and_pubkey(
+            &DiscordMessage::for_claimant(&claimant).get_message_with_metadata(),
+            &account_id,
+            &pubkey
+        )
+        .unwrap_err(),
+        error!(ErrorCode::SignatureVerificationWrongPayload)
+    );
+}
+
+#[tokio::test]
+pub async fn test_claim_with_near_implicit_and_named_accounts() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = solana_sdk::signature::Keypair::new();
+    let claimant_pubkey = solana_sdk::signer::Signer::pubkey(&claimant);
+    let dispenser_guard = Keypair::generate(&mut OsRng {});
+
+    let certificates = vec![
+        TestClaimCertificate::random_near(&claimant_pubkey),
+        named_certificate(&claimant_pubkey, "alice.near", &dispenser_guard),
+    ];
+    let merkle_tree = setup(&mut simulator, &certificates, &dispenser_guard).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_invalid_near_signature_or_attestation() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = solana_sdk::signature::Keypair::new();
+    let claimant_pubkey = solana_sdk::signer::Signer::pubkey(&claimant);
+    let dispenser_guard = Keypair::generate(&mut OsRng {});
+
+    let mut certificates_and_errors: Vec<(TestClaimCertificate, ErrorCode)> = vec![
+        (
+            TestClaimCertificate::random_near(&Pubkey::new_unique()),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            named_certificate(
+                &claimant_pubkey,
+                "alice.near",
+                &Keypair::generate(&mut OsRng {}),
+            ),
+            ErrorCode::SignatureVerificationWrongSigner,
+        ),
+        (
+            named_certificate(&claimant_pubkey, "alice.near", &dispenser_guard),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+    ];
+    // the attestation is for another account
+    if let TestIdentityCertificate::NearNamed(near) =
+        &mut certificates_and_errors[2].0.off_chain_proof_of_identity
+    {
+        near.account_id = NearAccountId::from("bob.near");
+    }
+    let certificates: Vec<TestClaimCertificate> = certificates_and_errors
+        .iter()
+        .map(|(certificate, _)| certificate.clone())
+        .collect();
+    let merkle_tree = setup(&mut simulator, &certificates, &dispenser_guard).await;
+
+    for (certificate, error) in &certificates_and_errors {
+        let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            (*error).into_transaction_error(ix_index_error)
+        );
+    }
+}
@@ -2,7 +2,10 @@ use {
     super::dispenser_simulator::DispenserSimulator,
     crate::{
         ecosystems::{
-            evm::EvmPrefixedMessage,
+            evm::{
+                Eip712Message,
+                EvmPrefixedMessage,
+            },
             get_expected_payload,
             secp256k1::{
                 EvmPubkey,
@@ -10,6 +13,8 @@ use {
                 Secp256k1InstructionHeader,
                 Secp256k1TestMessage,
             },
+            tron::TronMessage,
+            DEFAULT_AUTHORIZATION_TEMPLATE,
         },
         Identity,
         IdentityCertificate,
@@ -111,10 +116,78 @@ impl Secp256k1TestIdentityCertificate<EvmPrefixedMessage, Keccak256> {
             verification_instruction_index,
         }
     }
+
+    pub fn as_siwe_proof_of_identity(
+        &self,
+        verification_instruction_index: u8,
+    ) -> IdentityCertificate {
+        IdentityCertificate::EvmSiwe {
+            pubkey: self.recover_as_evm_address(),
+            verification_instruction_index,
+        }
+    }
 }
 impl Secp256k1TestIdentityCertificate<EvmPrefixedMessage, Keccak256> {
     pub fn random(claimant: &Pubkey) -> Self {
-        let message = EvmPrefixedMessage::from(get_expected_payload(claimant).as_str());
+        Self::random_with_payload(&get_expected_payload(
+            DEFAULT_AUTHORIZATION_TEMPLATE,
+            claimant,
+        ))
+    }
+
+    pub fn random_with_payload(payload: &str) -> Self {
+        Self::from_secret_with_payload(
+            libsecp256k1::SecretKey::random(&mut rand::thread_rng()),
+            payload,
+        )
+    }
+
+    pub fn from_secret(claimant: &Pubkey, secret: libsecp256k1::SecretKey) -> Self {
+        Self::from_secret_with_payload(
+            secret,
+            &get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant),
+        )
+    }
+
+    pub fn from_secret_with_payload(secret: libsecp256k1::SecretKey, payload: &str) -> Self {
+        let message = EvmPrefixedMessage::from(payload);
+        let (signature, recovery_id) = libsecp256k1::sign(&Self::hash_message(&message), &secret);
+        Self {
+            message,
+            signature,
+            recovery_id,
+            _hasher: PhantomData,
+        }
+    }
+}
+
+impl From<Secp256k1TestIdentityCertificate<Eip712Message, Keccak256>> for Identity {
+    fn from(val: Secp256k1TestIdentityCertificate<Eip712Message, Keccak256>) -> Self {
+        Identity::Evm {
+            pubkey: val.recover_as_evm_address(),
+        }
+    }
+}
+
+impl Secp256k1TestIdentityCertificate<Eip712Message, Keccak256> {
+    pub fn as_proof_of_identity(
+        &self,
+        chain_id: u64,
+        verification_instruction_index: u8,
+    ) -> IdentityCertificate {
+        IdentityCertificate::EvmTypedData {
+            pubkey: self.recover_as_evm_address(),
+            chain_id,
+            verification_instruction_index,
+        }
+    }
+
+    pub fn random(claimant: &Pubkey, chain_id: u64) -> Self {
+        let message = Eip712Message::new(
+            chain_id,
+            claimant,
+            &get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant),
+        );
         let secret = libsecp256k1::SecretKey::random(&mut rand::thread_rng());
         let (signature, recovery_id) = libsecp256k1::sign(&Self::hash_message(&message), &secret);
         Self {
@@ -124,9 +197,29 @@ impl Secp256k1TestIdentityCertificate<EvmPrefixedMessage, Keccak256> {
             _hasher: PhantomData,
         }
     }
+}
 
-    pub fn from_secret(claimant: &Pubkey, secret: libsecp256k1::SecretKey) -> Self {
-        let message = EvmPrefixedMessage::from(get_expected_payload(claimant).as_str());
+impl From<Secp256k1TestIdentityCertificate<TronMessage, Keccak256>> for Identity {
+    fn from(val: Secp256k1TestIdentityCertificate<TronMessage, Keccak256>) -> Self {
+        Identity::Tron {
+            pubkey: val.recover_as_evm_address(),
+        }
+    }
+}
+
+impl Secp256k1TestIdentityCertificate<TronMessage, Keccak256> {
+    pub fn as_proof_of_identity(&self, verification_instruction_index: u8) -> IdentityCertificate {
+        IdentityCertificate::Tron {
+            pubkey: self.recover_as_evm_address(),
+            verification_instruction_index,
+        }
+    }
+
+    pub fn random(claimant: &Pubkey) -> Self {
+        let message = TronMessage::from(
+            get_expected_payload(DEFAULT_AUTHORIZATION_TEMPLATE, claimant).as_str(),
+        );
+        let secret = libsecp256k1::SecretKey::random(&mut rand::thread_rng());
         let (signature, recovery_id) = libsecp256k1::sign(&Self::hash_message(&message), &secret);
         Self {
             message,
@@ -0,0 +1,167 @@
+use {
+    super::{
+        dispenser_simulator::{
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_happy_path::{
+            TestClaimCertificate,
+            TestIdentityCertificate,
+        },
+        test_secp256k1::{
+            construct_evm_pubkey,
+            Secp256k1TestIdentityCertificate,
+        },
+    },
+    crate::{
+        ecosystems::{
+            evm::{
+                get_checksum_address,
+                get_siwe_statement,
+                EvmPrefixedMessage,
+            },
+            get_expected_payload,
+            AuthorizationPayload,
+            DEFAULT_AUTHORIZATION_TEMPLATE,
+        },
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        SolanaHasher,
+    },
+    anchor_lang::prelude::Pubkey,
+    pythnet_sdk::{
+        accumulators::merkle::MerkleTree,
+        hashers::keccak256::Keccak256,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+/// Signs a Sign-In with Ethereum message authorizing `claimant`, followed by `extra_fields`
+fn random_siwe_certificate(claimant: &Pubkey, extra_fields: &str) -> TestClaimCertificate {
+    let secret = libsecp256k1::SecretKey::random(&mut rand::thread_rng());
+    let address = get_checksum_address(&construct_evm_pubkey(
+        &libsecp256k1::PublicKey::from_secret_key(&secret),
+    ));
+    let statement = get_siwe_statement(&get_expected_payload(
+        DEFAULT_AUTHORIZATION_TEMPLATE,
+        claimant,
+    ));
+    let message = format!(
+        "airdrop.example.com wants you to sign in with your Ethereum account:\n{}\n\n{}\n\nURI: \
+         https://airdrop.example.com\nVersion: 1\nChain ID: 1\nNonce: kq3Jv7mYx2Lw\nIssued At: \
+         2023-11-01T12:00:00.000Z{}",
+        address, statement, extra_fields
+    );
+
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity:
+            TestIdentityCertificate::EvmSiwe(Secp256k1TestIdentityCertificate::<
+                EvmPrefixedMessage,
+                Keccak256,
+            >::from_secret_with_payload(
+                secret, &message
+            )),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
+async fn setup(
+    simulator: &mut DispenserSimulator,
+    certificates: &[TestClaimCertificate],
+) -> MerkleTree<SolanaHasher> {
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Keypair::new().pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+    merkle_tree
+}
+
+#[tokio::test]
+pub async fn test_claim_with_siwe_message() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let certificates = vec![
+        random_siwe_certificate(&claimant.pubkey(), ""),
+        random_siwe_certificate(
+            &claimant.pubkey(),
+            "\nExpiration Time: 2100-01-01T00:00:00Z\nRequest ID: 1\nResources:\n- \
+             https://airdrop.example.com/terms",
+        ),
+    ];
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_invalid_siwe_message() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let certificates_and_errors: Vec<(TestClaimCertificate, ErrorCode)> = vec![
+        (
+            random_siwe_certificate(&Pubkey::new_unique(), ""),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            random_siwe_certificate(
+                &claimant.pubkey(),
+                "\nExpiration Time: 2023-11-02T12:00:00Z",
+            ),
+            ErrorCode::AuthorizationExpired,
+        ),
+        (
+            random_siwe_certificate(&claimant.pubkey(), "\nNot Before: 2100-01-01T00:00:00Z"),
+            ErrorCode::AuthorizationNotYetValid,
+        ),
+        (
+            random_siwe_certificate(&claimant.pubkey(), "\nIssued At: 2023-11-01T12:00:00Z"),
+            ErrorCode::SignatureVerificationWrongPayloadMetadata,
+        ),
+    ];
+    let certificates: Vec<TestClaimCertificate> = certificates_and_errors
+        .iter()
+        .map(|(certificate, _)| certificate.clone())
+        .collect();
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for (certificate, error) in &certificates_and_errors {
+        let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            (*error).into_transaction_error(ix_index_error)
+        );
+    }
+}
@@ -0,0 +1,167 @@
+use {
+    super::{
+        dispenser_simulator::{
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_ed25519::Ed25519TestIdentityCertificate,
+        test_happy_path::{
+            TestClaimCertificate,
+            TestIdentityCertificate,
+        },
+    },
+    crate::{
+        ecosystems::{
+            ed25519::Ed25519Pubkey,
+            substrate::{
+                SubstrateMessage,
+                SUBSTRATE_MAX_SS58_NETWORK,
+            },
+            AuthorizationPayload,
+        },
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        Identity,
+        IdentityCertificate,
+        SolanaHasher,
+    },
+    anchor_lang::prelude::Pubkey,
+    pythnet_sdk::accumulators::merkle::MerkleTree,
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+/// SS58 prefixes of Polkadot, Kusama and generic Substrate addresses
+pub const POLKADOT_NETWORK: u16 = 0;
+pub const KUSAMA_NETWORK: u16 = 2;
+pub const SUBSTRATE_NETWORK: u16 = 42;
+
+/**
+ * An ed25519 Substrate account of the network `network` signing the wrapped payload.
+ */
+#[derive(Clone)]
+pub struct SubstrateTestIdentityCertificate {
+    pub network:   u16,
+    pub signature: Ed25519TestIdentityCertificate<SubstrateMessage>,
+}
+
+impl SubstrateTestIdentityCertificate {
+    pub fn random(claimant: &Pubkey, network: u16) -> Self {
+        Self {
+            network,
+            signature: Ed25519TestIdentityCertificate::<SubstrateMessage>::random(claimant),
+        }
+    }
+
+    pub fn as_proof_of_identity(&self, verification_instruction_index: u8) -> IdentityCertificate {
+        IdentityCertificate::Substrate {
+            network: self.network,
+            pubkey: Ed25519Pubkey::from(self.signature.public_key.to_bytes()),
+            verification_instruction_index,
+        }
+    }
+}
+
+impl From<SubstrateTestIdentityCertificate> for Identity {
+    fn from(val: SubstrateTestIdentityCertificate) -> Self {
+        Identity::Substrate {
+            network: val.network,
+            pubkey:  Ed25519Pubkey::from(val.signature.public_key.to_bytes()),
+        }
+    }
+}
+
+async fn setup(
+    simulator: &mut DispenserSimulator,
+    certificates: &[TestClaimCertificate],
+) -> MerkleTree<SolanaHasher> {
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Keypair::new().pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+    merkle_tree
+}
+
+fn substrate_certificate(claimant: &Pubkey, network: u16) -> TestClaimCertificate {
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity: TestIdentityCertificate::Substrate(
+            SubstrateTestIdentityCertificate::random(claimant, network),
+        ),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_with_substrate() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let certificates: Vec<TestClaimCertificate> =
+        [POLKADOT_NETWORK, KUSAMA_NETWORK, SUBSTRATE_NETWORK]
+            .iter()
+            .map(|network| substrate_certificate(&claimant.pubkey(), *network))
+            .collect();
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_invalid_substrate_signature_or_network() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let certificates_and_errors = vec![
+        (
+            substrate_certificate(&Pubkey::new_unique(), POLKADOT_NETWORK),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            substrate_certificate(&claimant.pubkey(), SUBSTRATE_MAX_SS58_NETWORK + 1),
+            ErrorCode::InvalidSubstrateNetwork,
+        ),
+    ];
+    let certificates: Vec<TestClaimCertificate> = certificates_and_errors
+        .iter()
+        .map(|(certificate, _)| certificate.clone())
+        .collect();
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for (certificate, error) in &certificates_and_errors {
+        let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            (*error).into_transaction_error(ix_index_error)
+        );
+    }
+}
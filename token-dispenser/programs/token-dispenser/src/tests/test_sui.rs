@@ -0,0 +1,484 @@
+use {
+    super::{
+        dispenser_simulator::{
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_ed25519::Ed25519TestIdentityCertificate,
+        test_happy_path::{
+            TestClaimCertificate,
+            TestIdentityCertificate,
+        },
+    },
+    crate::{
+        ecosystems::{
+            cosmos::CompressedSecp256k1Pubkey,
+            ed25519::{
+                Ed25519Pubkey,
+                Ed25519TestClaimantMessage,
+                Ed25519TestMessage,
+            },
+            secp256r1::Secp256r1Pubkey,
+            sui::{
+                SuiAddress,
+                SuiMessage,
+                SuiMultisigMember,
+                SuiMultisigMemberPubkey,
+                SuiMultisigPubkey,
+                SuiMultisigSigner,
+            },
+            AuthorizationPayload,
+        },
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        Identity,
+        IdentityCertificate,
+        SolanaHasher,
+    },
+    anchor_lang::prelude::Pubkey,
+    p256::ecdsa::{
+        signature::Signer as _,
+        SigningKey,
+    },
+    pythnet_sdk::accumulators::merkle::MerkleTree,
+    rand_compatible::rngs::OsRng,
+    solana_program_test::tokio,
+    solana_sdk::{
+        hash::hashv,
+        instruction::Instruction,
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+/**
+ * A Sui account using the Secp256k1 or the Secp256r1 signature scheme, signing `message`.
+ */
+#[derive(Clone)]
+pub enum SuiSecp256TestIdentityCertificate {
+    Secp256k1 {
+        message:     SuiMessage,
+        signature:   libsecp256k1::Signature,
+        recovery_id: libsecp256k1::RecoveryId,
+        pubkey:      libsecp256k1::PublicKey,
+    },
+    Secp256r1 {
+        message:   SuiMessage,
+        signature: p256::ecdsa::Signature,
+        pubkey:    p256::ecdsa::VerifyingKey,
+    },
+}
+
+impl SuiSecp256TestIdentityCertificate {
+    pub fn random_secp256k1(claimant: &Pubkey) -> Self {
+        let message = SuiMessage::for_claimant(claimant);
+        let secret = libsecp256k1::SecretKey::random(&mut rand::thread_rng());
+        let (signature, recovery_id) = libsecp256k1::sign(
+            &libsecp256k1::Message::parse(
+                &hashv(&[&message.get_message_with_metadata()]).to_bytes(),
+            ),
+            &secret,
+        );
+        Self::Secp256k1 {
+            message,
+            signature,
+            recovery_id,
+            pubkey: libsecp256k1::PublicKey::from_secret_key(&secret),
+        }
+    }
+
+    pub fn random_secp256r1(claimant: &Pubkey) -> Self {
+        let message = SuiMessage::for_claimant(claimant);
+        let signing_key = SigningKey::random(&mut rand::thread_rng());
+        Self::Secp256r1 {
+            signature: signing_key.sign(&message.get_message_with_metadata()),
+            pubkey: signing_key.verifying_key(),
+            message,
+        }
+    }
+}
+
+fn as_secp256r1_pubkey(pubkey: &p256::ecdsa::VerifyingKey) -> Secp256r1Pubkey {
+    let mut bytes = [0u8; Secp256r1Pubkey::LEN];
+    bytes.copy_from_slice(pubkey.to_encoded_point(true).as_bytes());
+    bytes.into()
+}
+
+impl From<SuiSecp256TestIdentityCertificate> for Identity {
+    fn from(val: SuiSecp256TestIdentityCertificate) -> Self {
+        match val {
+            SuiSecp256TestIdentityCertificate::Secp256k1 { pubkey, .. } => Identity::Sui {
+                address: SuiAddress::from(CompressedSecp256k1Pubkey::from(
+                    pubkey.serialize_compressed(),
+                )),
+            },
+            SuiSecp256TestIdentityCertificate::Secp256r1 { pubkey, .. } => Identity::Sui {
+                address: SuiAddress::from(as_secp256r1_pubkey(&pubkey)),
+            },
+        }
+    }
+}
+
+impl From<SuiSecp256TestIdentityCertificate> for IdentityCertificate {
+    fn from(val: SuiSecp256TestIdentityCertificate) -> Self {
+        match val {
+            SuiSecp256TestIdentityCertificate::Secp256k1 {
+                message,
+                signature,
+                recovery_id,
+                pubkey,
+            } => IdentityCertificate::SuiSecp256k1 {
+                signature:   signature.serialize().into(),
+                recovery_id: recovery_id.into(),
+                pubkey:      pubkey.serialize_compressed().into(),
+                message:     message.get_message_with_metadata(),
+            },
+            SuiSecp256TestIdentityCertificate::Secp256r1 {
+                message,
+                signature,
+                pubkey,
+            } => {
+                let mut signature_bytes = [0u8; 64];
+                signature_bytes.copy_from_slice(signature.as_ref());
+                IdentityCertificate::SuiSecp256r1 {
+                    signature: signature_bytes.into(),
+                    pubkey:    as_secp256r1_pubkey(&pubkey),
+                    message:   message.get_message_with_metadata(),
+                }
+            }
+        }
+    }
+}
+
+/**
+ * A Sui multisig whose members are all Ed25519 keys, each signer `member_index` signs `message`.
+ */
+#[derive(Clone)]
+pub struct SuiMultisigTestIdentityCertificate {
+    pub multisig:   SuiMultisigPubkey,
+    pub signatures: Vec<(u8, Ed25519TestIdentityCertificate<SuiMessage>)>,
+}
+
+impl SuiMultisigTestIdentityCertificate {
+    pub fn random(claimant: &Pubkey, threshold: u16, weights: &[u8], signers: &[u8]) -> Self {
+        let message = SuiMessage::for_claimant(claimant);
+        let keypairs: Vec<ed25519_dalek::Keypair> = weights
+            .iter()
+            .map(|_| ed25519_dalek::Keypair::generate(&mut OsRng {}))
+            .collect();
+        Self {
+            multisig:   SuiMultisigPubkey {
+                members: keypairs
+                    .iter()
+                    .zip(weights)
+                    .map(|(keypair, weight)| SuiMultisigMember {
+                        pubkey: SuiMultisigMemberPubkey::Ed25519(Ed25519Pubkey::from(
+                            keypair.public.to_bytes(),
+                        )),
+                        weight: *weight,
+                    })
+                    .collect(),
+                threshold,
+            },
+            signatures: signers
+                .iter()
+                .map(|member_index| {
+                    let keypair = &keypairs[*member_index as usize];
+                    (
+                        *member_index,
+                        Ed25519TestIdentityCertificate {
+                            signature:  ed25519_dalek::Signer::sign(
+                                keypair,
+                                &message.get_message_with_metadata(),
+                            ),
+                            public_key: keypair.public,
+                            message:    message.clone(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn as_instructions(&self, instruction_index: u8) -> Vec<Instruction> {
+        self.signatures
+            .iter()
+            .enumerate()
+            .map(|(i, (_, signature))| signature.as_instruction(instruction_index + i as u8, true))
+            .collect()
+    }
+
+    pub fn as_proof_of_identity(&self, verification_instruction_index: u8) -> IdentityCertificate {
+        IdentityCertificate::SuiMultisig {
+            multisig: self.multisig.clone(),
+            signers:  self
+                .signatures
+                .iter()
+                .enumerate()
+                .map(|(i, (member_index, _))| SuiMultisigSigner {
+                    member_index:                   *member_index,
+                    verification_instruction_index: verification_instruction_index + i as u8,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<SuiMultisigTestIdentityCertificate> for Identity {
+    fn from(val: SuiMultisigTestIdentityCertificate) -> Self {
+        Identity::Sui {
+            address: val.multisig.get_address(),
+        }
+    }
+}
+
+async fn setup(
+    simulator: &mut DispenserSimulator,
+    certificates: &[TestClaimCertificate],
+) -> MerkleTree<SolanaHasher> {
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Keypair::new().pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+    merkle_tree
+}
+
+fn sui_secp256_certificate(certificate: SuiSecp256TestIdentityCertificate) -> TestClaimCertificate {
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity: TestIdentityCertificate::SuiSecp256(certificate),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_with_sui_secp256k1_and_secp256r1() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let certificates = vec![
+        sui_secp256_certificate(SuiSecp256TestIdentityCertificate::random_secp256k1(
+            &claimant.pubkey(),
+        )),
+        sui_secp256_certificate(SuiSecp256TestIdentityCertificate::random_secp256r1(
+            &claimant.pubkey(),
+        )),
+    ];
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+/// The compute unit limit the SDK tells clients to request for a secp256r1 claim
+const SECP256R1_CLAIM_COMPUTE_UNIT_LIMIT: u64 = 400_000;
+
+#[tokio::test]
+pub async fn test_sui_secp256r1_claim_compute_units() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let certificate = sui_secp256_certificate(SuiSecp256TestIdentityCertificate::random_secp256r1(
+        &claimant.pubkey(),
+    ));
+    let merkle_tree = setup(&mut simulator, std::slice::from_ref(&certificate)).await;
+
+    let instructions = simulator
+        .claim_instructions(
+            &claimant.pubkey(),
+            &certificate,
+            &merkle_tree,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    let compute_units = simulator
+        .simulate_compute_units(&instructions, &vec![&claimant])
+        .await
+        .unwrap();
+    println!("secp256r1 claim: {} compute units", compute_units);
+    assert!(compute_units <= SECP256R1_CLAIM_COMPUTE_UNIT_LIMIT);
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_wrong_sui_secp256_signature() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let mut certificates_and_errors = vec![
+        (
+            sui_secp256_certificate(SuiSecp256TestIdentityCertificate::random_secp256k1(
+                &Pubkey::new_unique(),
+            )),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            sui_secp256_certificate(SuiSecp256TestIdentityCertificate::random_secp256r1(
+                &Pubkey::new_unique(),
+            )),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            sui_secp256_certificate(SuiSecp256TestIdentityCertificate::random_secp256k1(
+                &claimant.pubkey(),
+            )),
+            ErrorCode::SignatureVerificationWrongSigner,
+        ),
+        (
+            sui_secp256_certificate(SuiSecp256TestIdentityCertificate::random_secp256r1(
+                &claimant.pubkey(),
+            )),
+            ErrorCode::SignatureVerificationWrongSigner,
+        ),
+    ];
+    // the signatures are for another message
+    for (certificate, _) in certificates_and_errors[2..].iter_mut() {
+        match &mut certificate.off_chain_proof_of_identity {
+            TestIdentityCertificate::SuiSecp256(SuiSecp256TestIdentityCertificate::Secp256k1 {
+                message,
+                ..
+            })
+            | TestIdentityCertificate::SuiSecp256(SuiSecp256TestIdentityCertificate::Secp256r1 {
+                message,
+                ..
+            }) => *message = SuiMessage::from("another message"),
+            _ => unreachable!(),
+        }
+    }
+    let certificates: Vec<TestClaimCertificate> = certificates_and_errors
+        .iter()
+        .map(|(certificate, _)| certificate.clone())
+        .collect();
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for (certificate, error) in &certificates_and_errors {
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            (*error).into_transaction_error(0)
+        );
+    }
+}
+
+fn sui_multisig_certificate(
+    claimant: &Pubkey,
+    threshold: u16,
+    weights: &[u8],
+    signers: &[u8],
+) -> TestClaimCertificate {
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity: TestIdentityCertificate::SuiMultisig(
+            SuiMultisigTestIdentityCertificate::random(claimant, threshold, weights, signers),
+        ),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_with_sui_multisig() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let certificates = vec![
+        sui_multisig_certificate(&claimant.pubkey(), 2, &[1, 1, 1], &[0, 2]),
+        sui_multisig_certificate(&claimant.pubkey(), 3, &[3, 1, 1], &[0]),
+        sui_multisig_certificate(&claimant.pubkey(), 1, &[1], &[0]),
+    ];
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_invalid_sui_multisig() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let mut certificates_and_errors = vec![
+        (
+            sui_multisig_certificate(&claimant.pubkey(), 3, &[1, 1, 2], &[0, 1]),
+            ErrorCode::InvalidSuiMultisig,
+        ),
+        (
+            sui_multisig_certificate(&claimant.pubkey(), 2, &[1, 1, 1], &[1, 1]),
+            ErrorCode::InvalidSuiMultisig,
+        ),
+        (
+            sui_multisig_certificate(&claimant.pubkey(), 4, &[1, 1, 1], &[0, 1, 2]),
+            ErrorCode::InvalidSuiMultisig,
+        ),
+        (
+            sui_multisig_certificate(&claimant.pubkey(), 1, &[1, 0], &[0]),
+            ErrorCode::InvalidSuiMultisig,
+        ),
+        (
+            sui_multisig_certificate(&Pubkey::new_unique(), 2, &[1, 1, 1], &[0, 1]),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            sui_multisig_certificate(&claimant.pubkey(), 1, &[1, 1], &[1]),
+            ErrorCode::InvalidSuiMultisig,
+        ),
+    ];
+    // only Ed25519 members can sign
+    if let TestIdentityCertificate::SuiMultisig(multisig) =
+        &mut certificates_and_errors[5].0.off_chain_proof_of_identity
+    {
+        multisig.multisig.members[1].pubkey = SuiMultisigMemberPubkey::Secp256k1(
+            libsecp256k1::PublicKey::from_secret_key(&libsecp256k1::SecretKey::random(
+                &mut rand::thread_rng(),
+            ))
+            .serialize_compressed()
+            .into(),
+        );
+    }
+    let certificates: Vec<TestClaimCertificate> = certificates_and_errors
+        .iter()
+        .map(|(certificate, _)| certificate.clone())
+        .collect();
+    let merkle_tree = setup(&mut simulator, &certificates).await;
+
+    for (certificate, error) in &certificates_and_errors {
+        let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            (*error).into_transaction_error(ix_index_error)
+        );
+    }
+}
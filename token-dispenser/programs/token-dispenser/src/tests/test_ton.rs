@@ -0,0 +1,234 @@
+use {
+    super::{
+        dispenser_simulator::{
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_ed25519::Ed25519TestIdentityCertificate,
+        test_happy_path::{
+            TestClaimCertificate,
+            TestIdentityCertificate,
+        },
+    },
+    crate::{
+        ecosystems::{
+            ed25519::{
+                Ed25519Pubkey,
+                Ed25519TestMessage,
+            },
+            ton::{
+                TonAddress,
+                TonKeyLinkMessage,
+                TonTestMessage,
+            },
+            AuthorizationPayload,
+        },
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        Identity,
+        IdentityCertificate,
+        SolanaHasher,
+    },
+    anchor_lang::{
+        error,
+        prelude::Pubkey,
+        AnchorSerialize,
+    },
+    ed25519_dalek::{
+        Keypair,
+        Signer,
+    },
+    pythnet_sdk::accumulators::merkle::MerkleTree,
+    rand_compatible::rngs::OsRng,
+    solana_program_test::tokio,
+    solana_sdk::instruction::Instruction,
+};
+
+/**
+ * A TON wallet `address` whose key signs the `ton_proof`, and the attestation of the dispenser
+ * guard linking that key to `address`.
+ */
+#[derive(Clone)]
+pub struct TonTestIdentityCertificate {
+    pub address:     TonAddress,
+    pub signature:   Ed25519TestIdentityCertificate<TonTestMessage>,
+    pub attestation: Ed25519TestIdentityCertificate<TonKeyLinkMessage>,
+}
+
+impl TonTestIdentityCertificate {
+    pub fn random(claimant: &Pubkey, dispenser_guard: &Keypair) -> Self {
+        let signature = Ed25519TestIdentityCertificate::<TonTestMessage>::random(claimant);
+        let address = signature.message.address.clone();
+        let message = TonKeyLinkMessage::new(
+            address.clone(),
+            Ed25519Pubkey::from(signature.public_key.to_bytes()),
+        );
+        Self {
+            address,
+            signature,
+            attestation: Ed25519TestIdentityCertificate {
+                signature: dispenser_guard.sign(&message.get_message_with_metadata()),
+                public_key: dispenser_guard.public,
+                message,
+            },
+        }
+    }
+
+    pub fn as_instructions(&self, instruction_index: u8) -> Vec<Instruction> {
+        vec![
+            self.signature.as_instruction(instruction_index, true),
+            self.attestation.as_instruction(instruction_index + 1, true),
+        ]
+    }
+
+    pub fn as_proof_of_identity(&self, verification_instruction_index: u8) -> IdentityCertificate {
+        IdentityCertificate::Ton {
+            address: self.address.clone(),
+            pubkey: self.signature.public_key.to_bytes().into(),
+            message: self.signature.message.message.clone(),
+            verification_instruction_index,
+            attestation_instruction_index: verification_instruction_index + 1,
+        }
+    }
+}
+
+impl From<TonTestIdentityCertificate> for Identity {
+    fn from(val: TonTestIdentityCertificate) -> Self {
+        Identity::Ton {
+            address: val.address,
+        }
+    }
+}
+
+async fn setup(
+    simulator: &mut DispenserSimulator,
+    certificates: &[TestClaimCertificate],
+    dispenser_guard: &Keypair,
+) -> MerkleTree<SolanaHasher> {
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Pubkey::new_from_array(dispenser_guard.public.to_bytes()),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+    merkle_tree
+}
+
+fn ton_certificate(claimant: &Pubkey, dispenser_guard: &Keypair) -> TestClaimCertificate {
+    TestClaimCertificate {
+        amount:                      TestClaimCertificate::random_amount(),
+        off_chain_proof_of_identity: TestIdentityCertificate::Ton(
+            TonTestIdentityCertificate::random(claimant, dispenser_guard),
+        ),
+        authorization_payload:       AuthorizationPayload::V1,
+    }
+}
+
+#[test]
+pub fn test_ton_key_link_message() {
+    let address = TonAddress {
+        workchain: 0,
+        hash:      [1; 32],
+    };
+    let pubkey = Ed25519Pubkey::from([2; Ed25519Pubkey::LEN]);
+    let message = TonKeyLinkMessage::new(address.clone(), pubkey.clone());
+    assert!(TonKeyLinkMessage::parse_and_check_address_and_pubkey(
+        &message.get_message_with_metadata(),
+        &address,
+        &pubkey
+    )
+    .is_ok());
+
+    // Without the tag
+    assert_eq!(
+        TonKeyLinkMessage::parse_and_check_address_and_pubkey(
+            &message.try_to_vec().unwrap(),
+            &address,
+            &pubkey
+        )
+        .unwrap_err(),
+        error!(ErrorCode::SignatureVerificationWrongPayload)
+    );
+}
+
+#[tokio::test]
+pub async fn test_claim_with_ton() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = solana_sdk::signature::Keypair::new();
+    let claimant_pubkey = solana_sdk::signer::Signer::pubkey(&claimant);
+    let dispenser_guard = Keypair::generate(&mut OsRng {});
+
+    let certificates = vec![
+        ton_certificate(&claimant_pubkey, &dispenser_guard),
+        ton_certificate(&claimant_pubkey, &dispenser_guard),
+    ];
+    let merkle_tree = setup(&mut simulator, &certificates, &dispenser_guard).await;
+
+    for certificate in &certificates {
+        simulator
+            .claim(&claimant, certificate, &merkle_tree, None, None, None)
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+pub async fn test_claim_fails_with_invalid_ton_proof_or_attestation() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = solana_sdk::signature::Keypair::new();
+    let claimant_pubkey = solana_sdk::signer::Signer::pubkey(&claimant);
+    let dispenser_guard = Keypair::generate(&mut OsRng {});
+
+    let mut certificates_and_errors: Vec<(TestClaimCertificate, ErrorCode)> = vec![
+        (
+            ton_certificate(&Pubkey::new_unique(), &dispenser_guard),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+        (
+            ton_certificate(&claimant_pubkey, &Keypair::generate(&mut OsRng {})),
+            ErrorCode::SignatureVerificationWrongSigner,
+        ),
+        (
+            ton_certificate(&claimant_pubkey, &dispenser_guard),
+            ErrorCode::SignatureVerificationWrongPayload,
+        ),
+    ];
+    // the attestation is for another wallet
+    if let TestIdentityCertificate::Ton(ton) =
+        &mut certificates_and_errors[2].0.off_chain_proof_of_identity
+    {
+        ton.address.workchain = -1;
+    }
+    let certificates: Vec<TestClaimCertificate> = certificates_and_errors
+        .iter()
+        .map(|(certificate, _)| certificate.clone())
+        .collect();
+    let merkle_tree = setup(&mut simulator, &certificates, &dispenser_guard).await;
+
+    for (certificate, error) in &certificates_and_errors {
+        let ix_index_error = certificate.as_instruction_error_index(&merkle_tree);
+        assert_eq!(
+            simulator
+                .claim(&claimant, certificate, &merkle_tree, None, None, None)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            (*error).into_transaction_error(ix_index_error)
+        );
+    }
+}
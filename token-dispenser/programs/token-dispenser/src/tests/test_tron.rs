@@ -0,0 +1,67 @@
+use {
+    super::{
+        dispenser_simulator::{
+            DispenserSimulator,
+            IntoTransactionError,
+        },
+        test_happy_path::TestClaimCertificate,
+    },
+    crate::{
+        tests::merkleize,
+        ClaimInfo,
+        ErrorCode,
+        SolanaHasher,
+    },
+    anchor_lang::prelude::Pubkey,
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+#[tokio::test]
+pub async fn test_claim_with_tron() {
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let certificates = [
+        TestClaimCertificate::random_tron(&claimant.pubkey()),
+        TestClaimCertificate::random_tron(&Pubkey::new_unique()),
+    ];
+    let merkle_items: Vec<ClaimInfo> = certificates
+        .iter()
+        .map(|item: &TestClaimCertificate| item.clone().into())
+        .collect();
+    let total_claim_sum = merkle_items.iter().map(|item| item.amount).sum::<u64>();
+
+    let (merkle_tree, _) = merkleize::<SolanaHasher>(merkle_items);
+    let address_lookup_table = simulator.init_lookup_table().await.unwrap();
+    simulator
+        .initialize(
+            merkle_tree.root.clone(),
+            Keypair::new().pubkey(),
+            address_lookup_table,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+    simulator.setup_treasury(total_claim_sum).await.unwrap();
+
+    // Signed for another claimant
+    assert_eq!(
+        simulator
+            .claim(&claimant, &certificates[1], &merkle_tree, None, None, None)
+            .await
+            .unwrap_err()
+            .unwrap(),
+        ErrorCode::SignatureVerificationWrongPayload.into_transaction_error(1)
+    );
+
+    simulator
+        .claim(&claimant, &certificates[0], &merkle_tree, None, None, None)
+        .await
+        .unwrap();
+}
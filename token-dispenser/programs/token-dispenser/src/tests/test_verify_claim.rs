@@ -0,0 +1,229 @@
+use {
+    super::dispenser_simulator::DispenserSimulator,
+    crate::{
+        get_receipt_pda,
+        tests::{
+            dispenser_simulator::copy_keypair,
+            test_happy_path::TestIdentityCertificate,
+        },
+        ClaimFailure,
+        ClaimInfo,
+        ErrorCode,
+        HashAlgorithm,
+        SolanaHasher,
+    },
+    anchor_lang::{
+        prelude::{
+            Error,
+            ProgramError,
+        },
+        AnchorDeserialize,
+        AnchorSerialize,
+    },
+    pythnet_sdk::accumulators::{
+        merkle::MerkleTree,
+        Accumulator,
+    },
+    solana_program_test::tokio,
+    solana_sdk::{
+        signature::Keypair,
+        signer::Signer,
+    },
+};
+
+#[test]
+pub fn test_claim_failure_from_error() {
+    let cases = [
+        (ErrorCode::Forbidden.into(), ClaimFailure::Forbidden),
+        (
+            ErrorCode::InvalidInclusionProof.into(),
+            ClaimFailure::InvalidInclusionProof,
+        ),
+        (ErrorCode::WrongPda.into(), ClaimFailure::WrongPda),
+        (
+            ErrorCode::AlreadyClaimed.into(),
+            ClaimFailure::AlreadyClaimed,
+        ),
+        (
+            ErrorCode::TransferExceedsMax.into(),
+            ClaimFailure::TransferExceedsMax,
+        ),
+        (
+            ErrorCode::SignatureVerificationWrongPayload.into(),
+            ClaimFailure::InvalidProofOfIdentity {
+                error_code: ErrorCode::SignatureVerificationWrongPayload.into(),
+            },
+        ),
+        (
+            ErrorCode::AuthorizationExpired.into(),
+            ClaimFailure::InvalidProofOfIdentity {
+                error_code: ErrorCode::AuthorizationExpired.into(),
+            },
+        ),
+        (
+            Error::from(ProgramError::InvalidInstructionData),
+            ClaimFailure::Other {
+                program_error: u64::from(ProgramError::InvalidInstructionData),
+            },
+        ),
+    ];
+    for (error, expected_failure) in cases {
+        let failure = ClaimFailure::from(error);
+        assert_eq!(failure, expected_failure);
+        // the failure round-trips through the return data
+        assert_eq!(
+            ClaimFailure::try_from_slice(&failure.try_to_vec().unwrap()).unwrap(),
+            expected_failure
+        );
+    }
+}
+
+#[tokio::test]
+pub async fn test_verify_claim() {
+    let dispenser_guard: Keypair = Keypair::new();
+
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let (merkle_tree, mock_offchain_certificates_and_claimants) = simulator
+        .initialize_with_claimants(vec![copy_keypair(&claimant)], &dispenser_guard, None, false)
+        .await
+        .unwrap();
+
+    let fake_tree_leaf = b"This is a fake tree";
+    let fake_merkle_tree = MerkleTree::<SolanaHasher>::new(&[fake_tree_leaf]).unwrap();
+
+    let (_, offchain_claim_certificates, _) = &mock_offchain_certificates_and_claimants[0];
+    for offchain_claim_certificate in offchain_claim_certificates {
+        let claim_info: ClaimInfo = offchain_claim_certificate.clone().into();
+        let receipt_pda =
+            get_receipt_pda(&claim_info.try_to_vec().unwrap(), HashAlgorithm::Keccak160).0;
+
+        let verification = simulator
+            .verify_claim(
+                &claimant.pubkey(),
+                offchain_claim_certificate,
+                &merkle_tree,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            verification.claim_info.unwrap().try_to_vec().unwrap(),
+            claim_info.try_to_vec().unwrap()
+        );
+        assert_eq!(verification.receipt, Some(receipt_pda));
+        assert!(!verification.claimed);
+        assert_eq!(verification.failure_reason, None);
+
+        // wrong merkle proof
+        let verification = simulator
+            .verify_claim(
+                &claimant.pubkey(),
+                offchain_claim_certificate,
+                &merkle_tree,
+                Some(fake_merkle_tree.prove(fake_tree_leaf).unwrap()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(verification.receipt, Some(receipt_pda));
+        assert_eq!(
+            verification.failure_reason,
+            Some(ClaimFailure::InvalidInclusionProof)
+        );
+
+        // the identity didn't authorize this claimant
+        let verification = simulator
+            .verify_claim(
+                &Keypair::new().pubkey(),
+                offchain_claim_certificate,
+                &merkle_tree,
+                None,
+            )
+            .await
+            .unwrap();
+        match offchain_claim_certificate.off_chain_proof_of_identity {
+            // the identity is the claimant itself so it's just not in the tree
+            TestIdentityCertificate::Solana(_) => {
+                assert!(verification.claim_info.is_some());
+                assert_eq!(
+                    verification.failure_reason,
+                    Some(ClaimFailure::InvalidInclusionProof)
+                );
+            }
+            _ => {
+                assert!(verification.claim_info.is_none());
+                assert!(verification.receipt.is_none());
+                assert_eq!(
+                    verification.failure_reason,
+                    Some(ClaimFailure::InvalidProofOfIdentity {
+                        error_code: ErrorCode::SignatureVerificationWrongPayload.into(),
+                    })
+                );
+            }
+        }
+
+        simulator
+            .claim(
+                &claimant,
+                offchain_claim_certificate,
+                &merkle_tree,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let verification = simulator
+            .verify_claim(
+                &claimant.pubkey(),
+                offchain_claim_certificate,
+                &merkle_tree,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(verification.claimed);
+        assert_eq!(
+            verification.failure_reason,
+            Some(ClaimFailure::AlreadyClaimed)
+        );
+    }
+}
+
+#[tokio::test]
+pub async fn test_verify_claim_exceeds_max_transfer() {
+    let dispenser_guard: Keypair = Keypair::new();
+
+    let mut simulator = DispenserSimulator::new().await;
+    let claimant = Keypair::new();
+
+    let (merkle_tree, mock_offchain_certificates_and_claimants) = simulator
+        .initialize_with_claimants(
+            vec![copy_keypair(&claimant)],
+            &dispenser_guard,
+            Some(0),
+            false,
+        )
+        .await
+        .unwrap();
+
+    let (_, offchain_claim_certificates, _) = &mock_offchain_certificates_and_claimants[0];
+    for offchain_claim_certificate in offchain_claim_certificates {
+        let verification = simulator
+            .verify_claim(
+                &claimant.pubkey(),
+                offchain_claim_certificate,
+                &merkle_tree,
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(!verification.claimed);
+        assert_eq!(
+            verification.failure_reason,
+            Some(ClaimFailure::TransferExceedsMax)
+        );
+    }
+}
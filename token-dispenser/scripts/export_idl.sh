@@ -1,22 +1,33 @@
 #! /bin/bash
 
-# This script updates the IDL generated by anchor and copies it into the frontend
+# This script updates the IDL generated by anchor and copies it into the frontend, the backend and the scripts
 cat target/idl/token_dispenser.json |
 # ADD UNIT STRUCTS
-jq '(..|objects| select(.type? and .type.defined? and .type.defined == "EvmPubkey")).type |= {"array": ["u8", 20]}' |
-jq '(..|objects| select(.type? and .type.defined? and .type.defined == "Secp256k1Signature")).type |= {"array": ["u8", 64]}' |
-jq '(..|objects| select(.type? and .type.defined? and .type.defined == "UncompressedSecp256k1Pubkey")).type |= {"array": ["u8", 65]}' |
-jq '(..|objects| select(.type? and .type.defined? and .type.defined == "CosmosBech32Address")).type |= "string"' |
-jq '(..|objects| select(.type? and .type.defined? and .type.defined == "Ed25519Pubkey")).type |= {"array": ["u8", 32]}' |
-jq '(..|objects| select(.type? and .type.defined? and .type.defined == "Ed25519Signature")).type |= {"array": ["u8", 64]}' |
-jq '(..|objects| select(.type? and .type.defined? and .type.defined == "SuiAddress")).type |= {"array": ["u8", 32]}' |
-jq '(..|objects| select(.type? and .type.defined? and .type.defined == "AptosAddress")).type |= {"array": ["u8", 32]}' |
-jq '(..|objects| select(.type? and .type.defined? and .type.defined == "AlgorandAddress")).type |= {"array": ["u8", 36]}' |
+jq 'walk(if type == "object" and .defined? == "EvmPubkey" then {"array": ["u8", 20]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "Secp256k1Signature" then {"array": ["u8", 64]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "UncompressedSecp256k1Pubkey" then {"array": ["u8", 65]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "CosmosBech32Address" then "string" else . end)' |
+jq 'walk(if type == "object" and .defined? == "Ed25519Pubkey" then {"array": ["u8", 32]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "Ed25519Signature" then {"array": ["u8", 64]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "SuiAddress" then {"array": ["u8", 32]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "AptosAddress" then {"array": ["u8", 32]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "AlgorandAddress" then {"array": ["u8", 32]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "CompressedSecp256k1Pubkey" then {"array": ["u8", 33]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "Secp256r1Pubkey" then {"array": ["u8", 33]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "Secp256r1Signature" then {"array": ["u8", 64]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "CardanoKeyHash" then {"array": ["u8", 28]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "NearAccountId" then "string" else . end)' |
+jq 'walk(if type == "object" and .defined? == "SuiMessage" then "bytes" else . end)' |
+jq 'walk(if type == "object" and .defined? == "EcosystemsClaimStats" then {"array": [{"defined": "EcosystemClaimStats"}, 14]} else . end)' |
 # ADD EXTERNAL STRUCTS
-jq '(..|objects| select(.type? and .type.defined? and .type.defined == "MerklePath<SolanaHasher>")).type |= {"vec":{"array":["u8",20]}}' |
-jq '(..|objects| select(.type? and .type.defined? and .type.defined == "MerkleRoot<SolanaHasher>")).type |= {"array": ["u8", 20]}' |
+jq 'walk(if type == "object" and .defined? == "MerklePath<SolanaHasher>" then {"vec":{"array":["u8",20]}} else . end)' |
+jq 'walk(if type == "object" and .defined? == "MerklePath<Keccak256Hasher>" then {"vec":{"array":["u8",32]}} else . end)' |
+jq 'walk(if type == "object" and .defined? == "MerklePath<Sha256Hasher>" then {"vec":{"array":["u8",32]}} else . end)' |
+jq 'walk(if type == "object" and .defined? == "MerkleRoot<SolanaHasher>" then {"array": ["u8", 20]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "MerkleRoot<Keccak256Hasher>" then {"array": ["u8", 32]} else . end)' |
+jq 'walk(if type == "object" and .defined? == "MerkleRoot<Sha256Hasher>" then {"array": ["u8", 32]} else . end)' |
 # DELETE TEST STRUCTS
-jq '.types |= map(select(.name != "TestIdentityCertificate"))' > ../frontend/claim_sdk/idl/token_dispenser.json
+jq '.types |= map(select(.name | test("Test") | not))' > ../frontend/claim_sdk/idl/token_dispenser.json
 
 
 generate_declaration_file() {
@@ -42,3 +53,17 @@ generate_declaration_file() {
 }
 
 generate_declaration_file ../frontend/claim_sdk/idl/token_dispenser.json ../frontend/claim_sdk/idl
+cp ../frontend/claim_sdk/idl/token_dispenser.{json,ts} ../scripts/claim_sdk/idl
+
+generate_coder_file() {
+    DECLARATION_FILE=$1
+    OUT_PATH=$2
+
+    echo "import { BorshCoder } from '@coral-xyz/anchor'" >$OUT_PATH
+    echo "" >>$OUT_PATH
+    cat $DECLARATION_FILE >>$OUT_PATH
+    echo "" >>$OUT_PATH
+    echo "export const coder = new BorshCoder(IDL)" >>$OUT_PATH
+}
+
+generate_coder_file ../frontend/claim_sdk/idl/token_dispenser.ts ../backend/src/token-dispenser.ts
@@ -5,6 +5,7 @@ import {
   SYSVAR_INSTRUCTIONS_PUBKEY,
 } from "@solana/web3.js";
 
+import { utils } from "@coral-xyz/anchor";
 import { TokenDispenserSdk } from "../sdk";
 import { ledgerSignAndSend, ledgerSignAndSendV0 } from "./helpers";
 import { connection, getSigner, getEnv } from "./env";
@@ -21,6 +22,8 @@ type InitConfig = {
   dispenserGuard: string;
   merkleRoot: Buffer;
   maxTransfer: bigint;
+  authorizationTemplate: string;
+  minPayloadVersion: number;
 };
 
 (async () => {
@@ -30,6 +33,8 @@ type InitConfig = {
     dispenserGuard: getEnv("DISPENSER_GUARD"),
     merkleRoot: Buffer.from(getEnv("MERKLE_ROOT"), "hex"),
     maxTransfer: BigInt(getEnv("MAX_TRANSFER")),
+    authorizationTemplate: getEnv("AUTHORIZATION_TEMPLATE"),
+    minPayloadVersion: Number(process.env.MIN_PAYLOAD_VERSION ?? 1),
   };
 
   const signer = await getSigner();
@@ -94,6 +99,12 @@ type InitConfig = {
     addressLookupTable: lookupTable,
     merkleRoot: config.merkleRoot,
     maxTransfer: config.maxTransfer,
+    // Authorization payloads v2 are only valid on this cluster
+    genesisHash: Buffer.from(
+      utils.bytes.bs58.decode(await connection.getGenesisHash())
+    ),
+    minPayloadVersion: config.minPayloadVersion,
+    authorizationTemplate: config.authorizationTemplate,
   });
 
   const result = await ledgerSignAndSend([initializeIx], []);
@@ -0,0 +1,74 @@
+import { BN, utils } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+// NOTE: These must be kept in sync with `ecosystems/mod.rs` in the program
+export const PROGRAM_ID_PLACEHOLDER = "{program_id}";
+export const CLAIMANT_PLACEHOLDER = "{claimant}";
+export const AMOUNT_PLACEHOLDER = "{amount}";
+export const GENESIS_HASH_PLACEHOLDER = "{genesis_hash}";
+export const EXPIRY_PLACEHOLDER = "{expiry}";
+export const NONCE_PLACEHOLDER = "{nonce}";
+export const MAX_AUTHORIZATION_TEMPLATE_LEN = 256;
+
+/**
+ * The version of the authorization payload signed by an identity, as passed in
+ * a `ClaimCertificate`
+ */
+export type AuthorizationPayload =
+  | { v1: {} }
+  | { v2: { expiry: BN; nonce: BN } };
+
+/**
+ * Whether a template has the placeholders needed by the version 2 of the
+ * authorization payload
+ */
+export function supportsAuthorizationPayloadV2(template: string): boolean {
+  return [
+    AMOUNT_PLACEHOLDER,
+    GENESIS_HASH_PLACEHOLDER,
+    EXPIRY_PLACEHOLDER,
+    NONCE_PLACEHOLDER,
+  ].every((placeholder) => template.includes(placeholder));
+}
+
+/**
+ * The message an identity needs to sign to authorize `claimant` to claim its
+ * tokens, given the `authorizationTemplate` of the campaign
+ */
+export function getAuthorizationPayload(
+  template: string,
+  programId: PublicKey,
+  claimant: PublicKey
+): string {
+  return template
+    .split(PROGRAM_ID_PLACEHOLDER)
+    .join(programId.toBase58())
+    .split(CLAIMANT_PLACEHOLDER)
+    .join(claimant.toBase58());
+}
+
+/**
+ * The version 2 of the authorization payload, which also commits to the amount,
+ * the cluster, an expiry unix timestamp and a nonce
+ */
+export function getAuthorizationPayloadV2(
+  template: string,
+  programId: PublicKey,
+  claimant: PublicKey,
+  args: {
+    amount: BN;
+    genesisHash: Buffer;
+    expiry: BN;
+    nonce: BN;
+  }
+): string {
+  return getAuthorizationPayload(template, programId, claimant)
+    .split(AMOUNT_PLACEHOLDER)
+    .join(args.amount.toString())
+    .split(GENESIS_HASH_PLACEHOLDER)
+    .join(utils.bytes.bs58.encode(args.genesisHash))
+    .split(EXPIRY_PLACEHOLDER)
+    .join(args.expiry.toString())
+    .split(NONCE_PLACEHOLDER)
+    .join(args.nonce.toString());
+}
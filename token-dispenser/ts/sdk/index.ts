@@ -1 +1,3 @@
 export * from "./token-dispenser";
+export * from "./leaf-format";
+export * from "./authorization-payload";
@@ -0,0 +1,56 @@
+import { BorshCoder, BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { keccak_256 } from "@noble/hashes/sha3";
+import IDL from "./idl/token_dispenser.json";
+
+const LEAF_FORMAT_V2_VERSION = 2;
+const LEAF_PREFIX = Buffer.from([0]);
+// The size of a Keccak160 hash in bytes
+const KECCAK160_HASH_SIZE = 20;
+
+const coder = new BorshCoder(IDL as any);
+
+/** The `ClaimInfo` of the program, e.g. `{ identity: { discord: { username } }, amount }` */
+export type ClaimInfo = {
+  identity: Record<string, any>;
+  amount: BN;
+};
+
+export type LeafFormat = { v1: {} } | { v2: { campaignId: BN } };
+
+/**
+ * Encodes a `ClaimInfo` into a leaf of the merkle tree, like `LeafFormat::encode_leaf`.
+ * - `V1` is the borsh serialization of the `ClaimInfo`.
+ * - `V2` is `2 || program id || campaign id (u64 LE) || mint || V1 leaf`.
+ *
+ * NOTE: This must match the program and is checked against the same vectors as `test_merkle_tree`
+ */
+export function encodeLeaf(
+  leafFormat: LeafFormat,
+  claimInfo: ClaimInfo,
+  mint: PublicKey,
+  programId: PublicKey
+): Buffer {
+  const claimInfoBuffer = coder.types.encode("ClaimInfo", claimInfo);
+  if ("v1" in leafFormat) {
+    return claimInfoBuffer;
+  }
+
+  return Buffer.concat([
+    Buffer.from([LEAF_FORMAT_V2_VERSION]),
+    programId.toBuffer(),
+    leafFormat.v2.campaignId.toArrayLike(Buffer, "le", 8),
+    mint.toBuffer(),
+    claimInfoBuffer,
+  ]);
+}
+
+/** Hashes a leaf with the `Keccak160` hash algorithm of the program */
+export function hashLeafKeccak160(leaf: Buffer): Buffer {
+  return Buffer.from(
+    keccak_256(Buffer.concat([LEAF_PREFIX, leaf])).subarray(
+      0,
+      KECCAK160_HASH_SIZE
+    )
+  );
+}
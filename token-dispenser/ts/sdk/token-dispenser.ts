@@ -4,8 +4,22 @@ import IDL from "./idl/token_dispenser.json";
 import { PublicKey, Connection } from "@solana/web3.js";
 
 import { derivePda } from "./utils";
+import { LeafFormat } from "./leaf-format";
 
 const CONFIG_SEED_PREFIX = "config";
+const CLAIM_STATS_SEED_PREFIX = "claim_stats";
+const EVENT_AUTHORITY_SEED_PREFIX = "__event_authority";
+
+export type HashAlgorithm = "keccak160" | "keccak256" | "sha256";
+
+/**
+ * Compute unit limit to request with `ComputeBudgetProgram.setComputeUnitLimit`
+ * in a transaction claiming with a Sui secp256r1 signature. There is no
+ * secp256r1 precompile, so the program verifies the signature itself, which
+ * costs far more than the other proofs of identity. The program tests check
+ * that such a claim fits in this limit.
+ */
+export const SECP256R1_CLAIM_COMPUTE_UNIT_LIMIT = 400_000;
 
 export class TokenDispenserSdk {
   readonly program: Program<TokenDispenser>;
@@ -43,6 +57,14 @@ export class TokenDispenserSdk {
     return derivePda(CONFIG_SEED_PREFIX, this.program.programId);
   }
 
+  claimStatsAccountAddress(): Address {
+    return derivePda(CLAIM_STATS_SEED_PREFIX, this.program.programId);
+  }
+
+  eventAuthorityAddress(): Address {
+    return derivePda(EVENT_AUTHORITY_SEED_PREFIX, this.program.programId);
+  }
+
   // Instructions:
   async createInitializeInstruction(args: {
     payer: PublicKey;
@@ -50,20 +72,38 @@ export class TokenDispenserSdk {
     dispenserGuard: PublicKey;
     addressLookupTable: PublicKey;
     merkleRoot: Buffer;
+    // The hash algorithm of the tree, `keccak160` trees have 20-byte roots
+    hashAlgorithm?: HashAlgorithm;
     maxTransfer: bigint;
+    leafFormat?: LeafFormat;
+    genesisHash: Buffer;
+    // Authorization payloads with a lower version are rejected
+    minPayloadVersion?: number;
+    // See `authorization-payload.ts` for the placeholders
+    authorizationTemplate: string;
   }) {
+    const merkleRoot = {
+      [args.hashAlgorithm ?? "keccak160"]: { 0: Array.from(args.merkleRoot) },
+    };
     return this.program.methods
       .initialize(
-        Array.from(args.merkleRoot),
+        merkleRoot as any,
         args.dispenserGuard,
-        new BN(args.maxTransfer.toString())
+        new BN(args.maxTransfer.toString()),
+        args.leafFormat ?? { v1: {} },
+        Array.from(args.genesisHash),
+        args.minPayloadVersion ?? 1,
+        args.authorizationTemplate
       )
       .accounts({
         config: new PublicKey(this.configAccountAddress()),
+        claimStats: new PublicKey(this.claimStatsAccountAddress()),
         mint: args.mint,
         systemProgram: web3.SystemProgram.programId,
         addressLookupTable: args.addressLookupTable,
         payer: args.payer,
+        eventAuthority: new PublicKey(this.eventAuthorityAddress()),
+        program: this.program.programId,
       })
       .instruction();
   }
@@ -0,0 +1,55 @@
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  getAuthorizationPayload,
+  getAuthorizationPayloadV2,
+  supportsAuthorizationPayloadV2,
+} from "../sdk";
+
+const PROGRAM_ID = new PublicKey(
+  "Wapq3Hpv2aSKjWrh4pM8eweh8jVJB7D1nLBw9ikjVYx"
+);
+const CLAIMANT = new PublicKey("3kzAHeiucNConBwKQVHyLcG3soaMzSZkvs4y14fmMgKL");
+
+// Same templates as `DEFAULT_AUTHORIZATION_TEMPLATE` and
+// `DEFAULT_AUTHORIZATION_TEMPLATE_V2` in `ecosystems/mod.rs`
+const TEMPLATE =
+  "W Airdrop PID:\n{program_id}\nI authorize Solana wallet\n{claimant}\nto claim my W tokens.\n";
+const TEMPLATE_V2 =
+  "W Airdrop PID:\n{program_id}\nI authorize Solana wallet\n{claimant}\n" +
+  "to claim {amount} base units of W tokens.\n" +
+  "Cluster genesis hash:\n{genesis_hash}\n" +
+  "Expires at (unix timestamp):\n{expiry}\n" +
+  "Nonce:\n{nonce}\n";
+
+describe("authorization payload", () => {
+  it("fills the template of the campaign", () => {
+    expect(getAuthorizationPayload(TEMPLATE, PROGRAM_ID, CLAIMANT)).to.equal(
+      "W Airdrop PID:\nWapq3Hpv2aSKjWrh4pM8eweh8jVJB7D1nLBw9ikjVYx\n" +
+        "I authorize Solana wallet\n3kzAHeiucNConBwKQVHyLcG3soaMzSZkvs4y14fmMgKL\n" +
+        "to claim my W tokens.\n"
+    );
+  });
+
+  it("matches the V2 payload of the program", () => {
+    expect(supportsAuthorizationPayloadV2(TEMPLATE)).to.be.false;
+    expect(supportsAuthorizationPayloadV2(TEMPLATE_V2)).to.be.true;
+    expect(
+      getAuthorizationPayloadV2(TEMPLATE_V2, PROGRAM_ID, CLAIMANT, {
+        amount: new BN(1000),
+        genesisHash: Buffer.alloc(32, 1),
+        expiry: new BN(1_700_000_000),
+        nonce: new BN(42),
+      })
+    ).to.equal(
+      getAuthorizationPayload(TEMPLATE, PROGRAM_ID, CLAIMANT).replace(
+        "to claim my W tokens.\n",
+        "to claim 1000 base units of W tokens.\n" +
+          "Cluster genesis hash:\n4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi\n" +
+          "Expires at (unix timestamp):\n1700000000\n" +
+          "Nonce:\n42\n"
+      )
+    );
+  });
+});
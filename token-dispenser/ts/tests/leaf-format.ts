@@ -0,0 +1,54 @@
+import { BN } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import { encodeLeaf, hashLeafKeccak160, LeafFormat } from "../sdk";
+
+const PROGRAM_ID = new PublicKey(
+  "Wapq3Hpv2aSKjWrh4pM8eweh8jVJB7D1nLBw9ikjVYx"
+);
+const MINT = new PublicKey("3kzAHeiucNConBwKQVHyLcG3soaMzSZkvs4y14fmMgKL");
+
+// Same vectors as `test_leaf_format_v2_vectors` in `test_merkle_tree.rs`
+describe("leaf format", () => {
+  const leafFormat: LeafFormat = { v2: { campaignId: new BN(1) } };
+  const vectors = [
+    {
+      claimInfo: {
+        amount: new BN(1000),
+        identity: { discord: { username: "pepito" } },
+      },
+      leaf: "02079416ce881d9a7c80ac9c7758ff6548817e4a910e0cc3ebd9d28bc54ceeff0d010000000000000028fbfad41a14ea06b29beb7f24b3d71b14410ecd233c81e8848f045cea4a1d03000600000070657069746fe803000000000000",
+      leafHash: "3c9325e9b4f38a26e4f6cd0cf513bb99aab0492b",
+    },
+    {
+      claimInfo: {
+        amount: new BN(2000),
+        identity: {
+          evm: {
+            pubkey: Array.from(
+              Buffer.from("f3f9225A2166861e745742509CED164183a626d7", "hex")
+            ),
+          },
+        },
+      },
+      leaf: "02079416ce881d9a7c80ac9c7758ff6548817e4a910e0cc3ebd9d28bc54ceeff0d010000000000000028fbfad41a14ea06b29beb7f24b3d71b14410ecd233c81e8848f045cea4a1d0302f3f9225a2166861e745742509ced164183a626d7d007000000000000",
+      leafHash: "2256022e87b163d1788c1e408c9a2542f86004c4",
+    },
+  ];
+
+  it("matches the V2 vectors of the program", () => {
+    for (const { claimInfo, leaf, leafHash } of vectors) {
+      const encoded = encodeLeaf(leafFormat, claimInfo, MINT, PROGRAM_ID);
+      expect(encoded.toString("hex")).to.equal(leaf);
+      expect(hashLeafKeccak160(encoded).toString("hex")).to.equal(leafHash);
+    }
+  });
+
+  it("encodes V1 leaves as the borsh ClaimInfo", () => {
+    const { claimInfo, leaf } = vectors[0];
+    const encoded = encodeLeaf({ v1: {} }, claimInfo, MINT, PROGRAM_ID);
+    expect(encoded.toString("hex")).to.equal(
+      leaf.slice(2 * (1 + 32 + 8 + 32))
+    );
+  });
+});